@@ -8,6 +8,11 @@ pub(crate) struct Field {
     pub append: bool,
     #[darling(default)]
     pub ignore: bool,
+    /**
+     * Value type always written as `VALUE` parameter, for the optional properties.
+     */
+    #[darling(default)]
+    pub value: Option<String>,
 }
 
 pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
@@ -29,6 +34,8 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
     let mut new_body = Vec::new();
     let mut from_body = Vec::new();
     let mut ser_body = Vec::new();
+    let mut ser_components = Vec::new();
+    let mut properties_body = Vec::new();
 
     for field in fields {
         let name = &field.ident;
@@ -42,26 +49,99 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
             .to_uppercase()
             .replace('_', "-");
 
-        let ser_part = if crate::is_vec(ty) {
+        let ser_part = if crate::is_map(ty) {
+            quote::quote! {
+                for (k, v) in &self.#name {
+                    crate::ser::write_property(w, k, v)?;
+                }
+            }
+        } else if crate::is_vec(ty) && !field_params.append {
+            quote::quote! {
+                for v in &self.#name {
+                    crate::ser::write_property(w, #field_name, v)?;
+                }
+            }
+        } else if let (true, Some(value)) = (crate::is_option(ty), &field_params.value) {
+            quote::quote! {
+                if let Some(v) = &self.#name {
+                    crate::ser::write_property(w, #field_name, &crate::ser::Typed(v, #value))?;
+                }
+            }
+        } else if crate::is_option(ty) {
+            quote::quote! {
+                if let Some(v) = &self.#name {
+                    crate::ser::write_property(w, #field_name, v)?;
+                }
+            }
+        } else if crate::is_vec(ty) {
             quote::quote! {
                 if self.#name.len() == 1 {
-                    s.push_str(&crate::ser::field(#field_name, &self.#name[0]));
+                    crate::ser::write_property(w, #field_name, &self.#name[0])?;
                 }
-                else if self.#name.attr().is_none() {
-                    s.push_str(&crate::ser::field(#field_name, &self.#name));
+                else if crate::ser::same_attr(&self.#name) {
+                    crate::ser::write_field(w, #field_name, &self.#name)?;
                 } else {
                     for v in &self.#name {
-                        s.push_str(&crate::ser::field(#field_name, v));
+                        crate::ser::write_field(w, #field_name, v)?;
                     }
                 }
             }
         } else {
             quote::quote! {
-                s.push_str(&crate::ser::field(#field_name, &self.#name));
+                crate::ser::write_field(w, #field_name, &self.#name)?;
             }
         };
 
-        ser_body.push(ser_part);
+        let properties_part = if crate::is_map(ty) {
+            quote::quote! {
+                for (k, v) in &self.#name {
+                    properties.push(crate::ContentLine { key: k.clone(), ..v.clone() });
+                }
+            }
+        } else if crate::is_vec(ty) && !field_params.append {
+            quote::quote! {
+                for v in &self.#name {
+                    properties.push(crate::ser::content_line(#field_name, v));
+                }
+            }
+        } else if let (true, Some(value)) = (crate::is_option(ty), &field_params.value) {
+            quote::quote! {
+                if let Some(v) = &self.#name {
+                    properties.push(crate::ser::content_line(#field_name, &crate::ser::Typed(v, #value)));
+                }
+            }
+        } else if crate::is_option(ty) {
+            quote::quote! {
+                if let Some(v) = &self.#name {
+                    properties.push(crate::ser::content_line(#field_name, v));
+                }
+            }
+        } else if crate::is_vec(ty) {
+            quote::quote! {
+                if self.#name.len() == 1 {
+                    properties.push(crate::ser::content_line(#field_name, &self.#name[0]));
+                }
+                else if crate::ser::same_attr(&self.#name) {
+                    properties.extend(crate::ser::field_line(#field_name, &self.#name));
+                } else {
+                    for v in &self.#name {
+                        properties.extend(crate::ser::field_line(#field_name, v));
+                    }
+                }
+            }
+        } else {
+            quote::quote! {
+                properties.extend(crate::ser::field_line(#field_name, &self.#name));
+            }
+        };
+
+        // The sub-components follow all the properties, extensions included
+        if field_params.ignore && !crate::is_map(ty) {
+            ser_components.push(ser_part);
+        } else {
+            ser_body.push(ser_part);
+            properties_body.push(properties_part);
+        }
 
         if field_params.ignore {
             continue;
@@ -154,25 +234,48 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
             }
         }
 
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /**
+             * Writes the properties and sub-components, without the
+             * `BEGIN`/`END` lines.
+             */
+            #[doc(hidden)]
+            pub(crate) fn write_properties<W: ::std::fmt::Write + ?Sized>(&self, w: &mut W) -> ::std::fmt::Result {
+                use crate::ser::Serialize as _;
+
+                #(#ser_body)*
+                #(#ser_components)*
+
+                Ok(())
+            }
+
+            /**
+             * The properties as they are written, without the sub-components.
+             */
+            #[doc(hidden)]
+            #[cfg(feature = "caldav")]
+            pub(crate) fn properties(&self) -> Vec<crate::ContentLine> {
+                let mut properties = Vec::new();
+
+                #(#properties_body)*
+
+                properties
+            }
+        }
+
         #[automatically_derived]
         impl #impl_generics crate::ser::Serialize for #name #ty_generics #where_clause {
             fn component() -> Option<String> {
                 #name_str.to_uppercase().into()
             }
 
-            fn ical(&self) -> ::std::string::String {
-                let mut s = String::new();
-                let name = Self::component().unwrap();
-
-                s.push_str("BEGIN:");
-                s.push_str(&name);
-                s.push_str("\r\n");
-                #(#ser_body)*
-                s.push_str("END:");
-                s.push_str(&name);
-                s.push_str("\r\n");
+            fn write_ical<W: ::std::fmt::Write + ?Sized>(&self, w: &mut W) -> ::std::fmt::Result {
+                let name = #name_str.to_uppercase();
 
-                s
+                write!(w, "BEGIN:{name}\r\n")?;
+                self.write_properties(w)?;
+                write!(w, "END:{name}\r\n")
             }
         }
     };
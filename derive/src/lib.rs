@@ -35,8 +35,10 @@ macro_rules! component {
 }
 
 component!(audio, valarm::Audio);
+component!(custom, valarm::Custom);
 component!(display, valarm::Display);
 component!(email, valarm::Email);
+component!(none, valarm::NoAction);
 component!(tz_daylight, vtimezone::Daylight);
 component!(tz_standard, vtimezone::Standard);
 component!(vcalendar, VCalendar);
@@ -50,6 +52,10 @@ fn is_option(ty: &syn::Type) -> bool {
     tyname(ty) == "Option"
 }
 
+fn is_map(ty: &syn::Type) -> bool {
+    tyname(ty).ends_with("BTreeMap")
+}
+
 fn is_vec(ty: &syn::Type) -> bool {
     tyname(ty) == "Vec"
 }
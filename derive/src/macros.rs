@@ -175,12 +175,7 @@ impl quote::ToTokens for TypedValue {
                 };
 
                 let v = if Type::should_parsed(&self.0, &self.1) {
-                    if self.1 == "duration" {
-                        let ikal = ikal();
-                        quote::quote! { #ikal::parse_duration(#v)? }
-                    } else {
-                        quote::quote! { #v.parse()? }
-                    }
+                    quote::quote! { #v.parse()? }
                 } else if Type::should_convert(&self.0, &self.1) {
                     quote::quote! { #v.into() }
                 } else {
@@ -233,11 +228,17 @@ impl Type {
             || (ty != "valarm::Email" && field == "summary")
             || (ty == "VFreebusy" && field == "contact")
             || (ty == "VTodo" && field == "dtstart")
+            || (ty == "VCalendar" && field == "uid")
+            || (ty.starts_with("valarm::") && field == "uid")
+            || (ty == "valarm::Custom" && field == "description")
             || matches!(
                 field,
-                "class"
+                "acknowledged"
+                    | "class"
+                    | "color"
                     | "completed"
                     | "created"
+                    | "default_alarm"
                     | "dtend"
                     | "due"
                     | "duration"
@@ -247,9 +248,12 @@ impl Type {
                     | "location"
                     | "organizer"
                     | "priority"
+                    | "proximity"
                     | "recurid"
+                    | "refresh_interval"
                     | "rrule"
                     | "sequence"
+                    | "source"
                     | "status"
                     | "transp"
                     | "tzurl"
@@ -264,7 +268,8 @@ impl Type {
     fn should_parsed(_ty: &str, field: &str) -> bool {
         matches!(
             field,
-            "completed"
+            "acknowledged"
+                | "completed"
                 | "dtstamp"
                 | "dtstart"
                 | "duration"
@@ -281,6 +286,7 @@ impl Type {
                 | "rdate"
                 | "exdate"
                 | "recurid"
+                | "refresh_interval"
         )
     }
 
@@ -288,7 +294,10 @@ impl Type {
         let ikal = ikal();
         let ty = match field {
             "class" => "Class",
+            "color" => "Color",
             "freq" => "Freq",
+            "method" => "Method",
+            "proximity" => "Proximity",
             "status" => "Status",
             "transp" => "TimeTransparency",
             _ => return None,
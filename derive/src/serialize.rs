@@ -44,9 +44,11 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
         let field_name = field_params.name(name);
 
         let mut ser_part = quote::quote! {
-            let ical = self.#name.ical();
-            if !ical.is_empty() {
-                s.push_str(&format!("{}={ical};", #field_name));
+            let prefix = [separator, #field_name, "="];
+            let mut part = crate::ser::Prefixed::new(&mut *w, &prefix);
+            self.#name.write_ical(&mut part)?;
+            if part.is_written() {
+                separator = ";";
             }
         };
 
@@ -64,13 +66,12 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
     let serialize = quote::quote! {
         #[automatically_derived]
         impl #impl_generics crate::ser::Serialize for #name #ty_generics #where_clause {
-            fn ical(&self) -> ::std::string::String {
-                let mut s = String::new();
+            fn write_ical<W: ::std::fmt::Write + ?Sized>(&self, w: &mut W) -> ::std::fmt::Result {
+                let mut separator = "";
 
                 #(#body)*
-                s.pop();
 
-                s
+                Ok(())
             }
         }
     };
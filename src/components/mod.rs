@@ -65,3 +65,69 @@ impl From<Component> for Components {
         }
     }
 }
+
+/**
+ * Collects the date-times of a component, floating, in UTC or with a `TZID`, by shared or
+ * mutable reference.
+ *
+ * Dates without time are skipped, they are never bound to a time zone.
+ */
+pub(crate) struct DateTimes<T>(Vec<T>);
+
+impl<T> Default for DateTimes<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> From<DateTimes<T>> for Vec<T> {
+    fn from(value: DateTimes<T>) -> Self {
+        value.0
+    }
+}
+
+macro_rules! date_times {
+    ($($mut:tt)?) => {
+        impl<'a> DateTimes<&'a $($mut)? crate::DateTime> {
+            pub fn date(mut self, date: Option<&'a $($mut)? crate::Date>) -> Self {
+                if let Some(crate::Date::DateTime(date_time)) = date {
+                    self.0.push(date_time);
+                }
+
+                self
+            }
+
+            pub fn dates(self, dates: &'a $($mut)? [crate::Date]) -> Self {
+                dates
+                    .into_iter()
+                    .fold(self, |this, date| this.date(Some(date)))
+            }
+
+            pub fn rdates(self, rdates: &'a $($mut)? [crate::RDate]) -> Self {
+                rdates.into_iter().fold(self, |this, rdate| match rdate {
+                    crate::RDate::Date(dates) => this.dates(dates),
+                    crate::RDate::Period(periods) => this.periods(periods),
+                })
+            }
+
+            pub fn periods(mut self, periods: &'a $($mut)? [crate::Period]) -> Self {
+                for period in periods {
+                    match period {
+                        crate::Period::StartEnd(crate::period::StartEnd { start, end }) => {
+                            self.0.push(start);
+                            self.0.push(end);
+                        }
+                        crate::Period::StartDur(crate::period::StartDur { start, .. }) => {
+                            self.0.push(start)
+                        }
+                    }
+                }
+
+                self
+            }
+        }
+    };
+}
+
+date_times!();
+date_times!(mut);
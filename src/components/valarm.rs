@@ -2,24 +2,114 @@
  * See [3.6.6. Alarm Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.6)
  */
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum VAlarm {
     Audio(Audio),
     Display(Display),
     Email(Email),
+    #[cfg_attr(feature = "serde", serde(rename = "NONE"))]
+    NoAction(NoAction),
+    Custom(Custom),
+}
+
+macro_rules! common {
+    ($self:ident, $alarm:ident => $expr:expr) => {
+        match $self {
+            Self::Audio($alarm) => $expr,
+            Self::Display($alarm) => $expr,
+            Self::Email($alarm) => $expr,
+            Self::NoAction($alarm) => $expr,
+            Self::Custom($alarm) => $expr,
+        }
+    };
+}
+
+impl VAlarm {
+    #[must_use]
+    pub fn trigger(&self) -> &crate::Trigger {
+        common!(self, alarm => &alarm.trigger)
+    }
+
+    pub fn trigger_mut(&mut self) -> &mut crate::Trigger {
+        common!(self, alarm => &mut alarm.trigger)
+    }
+
+    #[must_use]
+    pub fn uid(&self) -> Option<&crate::Text> {
+        common!(self, alarm => alarm.uid.as_ref())
+    }
+
+    pub fn uid_mut(&mut self) -> &mut Option<crate::Text> {
+        common!(self, alarm => &mut alarm.uid)
+    }
+
+    #[must_use]
+    pub fn related_to(&self) -> &[crate::Text] {
+        common!(self, alarm => &alarm.related_to)
+    }
+
+    pub fn related_to_mut(&mut self) -> &mut Vec<crate::Text> {
+        common!(self, alarm => &mut alarm.related_to)
+    }
+
+    #[must_use]
+    pub fn acknowledged(&self) -> Option<&crate::DateTime> {
+        common!(self, alarm => alarm.acknowledged.as_ref())
+    }
+
+    pub fn acknowledged_mut(&mut self) -> &mut Option<crate::DateTime> {
+        common!(self, alarm => &mut alarm.acknowledged)
+    }
+
+    #[must_use]
+    pub fn duration(&self) -> Option<crate::Duration> {
+        match self {
+            Self::Audio(audio) => audio.duration,
+            Self::Display(display) => display.duration,
+            Self::Email(email) => email.duration,
+            Self::NoAction(_) => None,
+            Self::Custom(custom) => custom.duration,
+        }
+    }
+
+    #[must_use]
+    pub fn repeat(&self) -> Option<u32> {
+        match self {
+            Self::Audio(audio) => audio.repeat,
+            Self::Display(display) => display.repeat,
+            Self::Email(email) => email.repeat,
+            Self::NoAction(_) => None,
+            Self::Custom(custom) => custom.repeat,
+        }
+    }
+
+    /**
+     * Checks the constraints of RFC 5545 the parser doesn’t enforce, see [`crate::validate`].
+     *
+     * The findings have no id, the UID belongs to the enclosing component.
+     */
+    #[must_use]
+    pub fn validate(&self) -> Vec<crate::validate::Finding> {
+        crate::validate::valarm(self, "")
+    }
 }
 
 impl TryFrom<Vec<crate::ContentLine>> for VAlarm {
     type Error = crate::Error;
 
     fn try_from(properties: Vec<crate::ContentLine>) -> crate::Result<Self> {
-        let action = properties.iter().find(|x| x.key == "ACTION").unwrap();
+        let action = properties
+            .iter()
+            .find(|x| x.key == "ACTION")
+            .ok_or_else(|| crate::Error::Parser("Missing field ACTION".to_string()))?;
 
         let component = match action.value.as_str() {
             "AUDIO" => Self::Audio(Audio::try_from(properties)?),
             "DISPLAY" => Self::Display(Display::try_from(properties)?),
             "EMAIL" => Self::Email(Email::try_from(properties)?),
-
-            action => return Err(crate::Error::Alarm(action.to_string())),
+            "NONE" => Self::NoAction(NoAction::try_from(properties)?),
+            _ => Self::Custom(Custom::try_from(properties)?),
         };
 
         Ok(component)
@@ -53,30 +143,39 @@ impl std::str::FromStr for VAlarm {
 }
 
 impl crate::ser::Serialize for VAlarm {
-    fn ical(&self) -> String {
-        let s = match self {
-            Self::Audio(audio) => audio.ical(),
-            Self::Display(display) => display.ical(),
-            Self::Email(email) => email.ical(),
-        };
+    fn component() -> Option<String> {
+        Some("VALARM".to_string())
+    }
 
-        let mut lines = s.split('\n').collect::<Vec<_>>();
-        lines[0] = "BEGIN:VALARM\r";
-        lines.pop();
-        lines.pop();
-        lines.push("END:VALARM\r\n");
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str("BEGIN:VALARM\r\n")?;
 
-        lines.join("\n")
+        match self {
+            Self::Audio(audio) => audio.write_properties(w)?,
+            Self::Display(display) => display.write_properties(w)?,
+            Self::Email(email) => email.write_properties(w)?,
+            Self::NoAction(none) => none.write_properties(w)?,
+            Self::Custom(custom) => custom.write_properties(w)?,
+        }
+
+        w.write_str("END:VALARM\r\n")
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Audio {
     pub action: crate::Text,
     pub trigger: crate::Trigger,
-    pub duration: Option<chrono::Duration>,
+    pub duration: Option<crate::Duration>,
     pub repeat: Option<u32>,
     pub attach: Vec<crate::Text>,
+    pub uid: Option<crate::Text>,
+    pub related_to: Vec<crate::Text>,
+    pub acknowledged: Option<crate::DateTime>,
+    pub proximity: Option<crate::Proximity>,
+    pub default_alarm: Option<bool>,
     #[component(ignore)]
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
@@ -99,12 +198,19 @@ impl From<Audio> for VAlarm {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Display {
     pub action: crate::Text,
     pub trigger: crate::Trigger,
     pub description: crate::Text,
-    pub duration: Option<chrono::Duration>,
+    pub duration: Option<crate::Duration>,
     pub repeat: Option<u32>,
+    pub uid: Option<crate::Text>,
+    pub related_to: Vec<crate::Text>,
+    pub acknowledged: Option<crate::DateTime>,
+    pub proximity: Option<crate::Proximity>,
+    pub default_alarm: Option<bool>,
     #[component(ignore)]
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
@@ -127,15 +233,22 @@ impl From<Display> for VAlarm {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Email {
     pub action: crate::Text,
     pub trigger: crate::Trigger,
     pub description: crate::Text,
     pub summary: crate::Text,
     pub attendee: Vec<crate::Uri>,
-    pub duration: Option<chrono::Duration>,
+    pub duration: Option<crate::Duration>,
     pub repeat: Option<u32>,
     pub attach: Vec<crate::Text>,
+    pub uid: Option<crate::Text>,
+    pub related_to: Vec<crate::Text>,
+    pub acknowledged: Option<crate::DateTime>,
+    pub proximity: Option<crate::Proximity>,
+    pub default_alarm: Option<bool>,
     #[component(ignore)]
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
@@ -157,6 +270,82 @@ impl From<Email> for VAlarm {
     }
 }
 
+/**
+ * An alarm without action, used to override a default alarm.
+ *
+ * See [RFC 9074](https://datatracker.ietf.org/doc/html/rfc9074)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct NoAction {
+    pub action: crate::Text,
+    pub trigger: crate::Trigger,
+    pub uid: Option<crate::Text>,
+    pub related_to: Vec<crate::Text>,
+    pub acknowledged: Option<crate::DateTime>,
+    pub proximity: Option<crate::Proximity>,
+    pub default_alarm: Option<bool>,
+    #[component(ignore)]
+    pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+}
+
+impl NoAction {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<NoAction> for VAlarm {
+    fn from(value: NoAction) -> Self {
+        let mut value = value.clone();
+        value.action = "NONE".into();
+        Self::NoAction(value)
+    }
+}
+
+/**
+ * An alarm with an unknown action (like the RFC 2445 `PROCEDURE`), kept as is.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Custom {
+    pub action: crate::Text,
+    pub trigger: crate::Trigger,
+    pub description: Option<crate::Text>,
+    pub summary: Option<crate::Text>,
+    pub attendee: Vec<crate::Uri>,
+    pub duration: Option<crate::Duration>,
+    pub repeat: Option<u32>,
+    pub attach: Vec<crate::Text>,
+    pub uid: Option<crate::Text>,
+    pub related_to: Vec<crate::Text>,
+    pub acknowledged: Option<crate::DateTime>,
+    pub proximity: Option<crate::Proximity>,
+    pub default_alarm: Option<bool>,
+    #[component(ignore)]
+    pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+}
+
+impl Custom {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<Custom> for VAlarm {
+    fn from(value: Custom) -> Self {
+        Self::Custom(value)
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -186,7 +375,7 @@ mod test {
             "BEGIN:VALARM\r
 ACTION:AUDIO\r
 TRIGGER;VALUE=DATE-TIME:19970317T133000Z\r
-DURATION:PT1296000S\r
+DURATION:P15D\r
 REPEAT:4\r
 ATTACH;FMTTYPE=audio/basic:ftp://example.com/pub/sounds/bell-01.aud\r
 END:VALARM\r
@@ -196,9 +385,43 @@ END:VALARM\r
         Ok(())
     }
 
+    #[test]
+    fn ser_rfc9074() -> crate::Result {
+        let mut related_to = crate::Text::from("D2B9CD8E-F7F4-4C22-9A4C-0C1B4A2A8B0A");
+        related_to
+            .params
+            .insert("RELTYPE".to_string(), "SNOOZE".to_string());
+
+        let valarm = crate::valarm! {
+            @none,
+            trigger: "19760401T005545Z",
+            uid: "8297C37D-BA2D-4476-91AE-C1EAA364F8E1",
+            related_to: [related_to],
+            acknowledged: "20210618T142500Z",
+            default_alarm: true,
+        }?;
+
+        let ical = crate::ser::ical(&valarm);
+
+        similar_asserts::assert_eq!(
+            ical,
+            "BEGIN:VALARM\r
+ACTION:NONE\r
+TRIGGER;VALUE=DATE-TIME:19760401T005545Z\r
+UID:8297C37D-BA2D-4476-91AE-C1EAA364F8E1\r
+RELATED-TO;RELTYPE=SNOOZE:D2B9CD8E-F7F4-4C22-9A4C-0C1B4A2A8B0A\r
+ACKNOWLEDGED:20210618T142500Z\r
+DEFAULT-ALARM:TRUE\r
+END:VALARM\r
+"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn macros() -> crate::Result {
-        let duration = Some(chrono::TimeDelta::zero());
+        let duration = Some(crate::Duration::zero());
 
         let _audio = crate::valarm! {
             @audio,
@@ -229,6 +452,15 @@ pointer the document template for the agenda file.",
             attach: ["http://example.com/templates/agenda.doc"],
         }?;
 
+        let _custom = crate::valarm! {
+            @custom,
+            action: "PROCEDURE",
+            trigger: "-PT5M",
+            description: "Run the notifier",
+            proximity: Arrive,
+            attach: ["ftp://example.com/pub/bin/notify.exe"],
+        }?;
+
         Ok(())
     }
 }
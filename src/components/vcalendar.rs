@@ -2,11 +2,25 @@
  * See [3.6. Calendar Components](https://datatracker.ietf.org/doc/html/rfc5545#section-3.4)
  */
 #[derive(Clone, Debug, Default, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct VCalendar {
     pub prodid: crate::Text,
     pub version: crate::Text,
     pub calscale: Option<crate::Text>,
-    pub method: Option<crate::Text>,
+    pub method: Option<crate::Method>,
+    pub uid: Option<crate::Text>,
+    pub last_modified: Option<crate::DateTime>,
+    pub url: Option<crate::Uri>,
+    #[component(value = "DURATION")]
+    pub refresh_interval: Option<crate::Duration>,
+    pub source: Option<crate::Uri>,
+    pub color: Option<crate::Color>,
+    pub name: Vec<crate::Text>,
+    pub description: Vec<crate::Text>,
+    #[component(append)]
+    pub categories: Vec<crate::Text>,
+    pub image: Vec<crate::Image>,
     #[component(ignore)]
     pub alarms: Vec<crate::VAlarm>,
     #[component(ignore)]
@@ -30,6 +44,64 @@ impl VCalendar {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /**
+     * Checks the constraints of RFC 5545 the parser doesn’t enforce, see [`crate::validate`].
+     */
+    #[must_use]
+    pub fn validate(&self) -> Vec<crate::validate::Finding> {
+        crate::validate::vcalendar(self)
+    }
+
+    /**
+     * Rewrites the extensions of Outlook, Google and Apple into standard properties, see
+     * [`crate::normalize`].
+     */
+    pub fn normalize(&mut self) -> Vec<crate::normalize::Change> {
+        crate::normalize::vcalendar(self)
+    }
+
+    /**
+     * Components overlapping the time range from `start` to `end`, see
+     * [`crate::time_range`].
+     */
+    #[must_use]
+    pub fn slice<D: Into<crate::DateTime>>(&self, start: D, end: D) -> Self {
+        crate::time_range::slice(self, &crate::time_range::TimeRange::new(start, end), false)
+    }
+
+    /**
+     * Like [`Self::slice`], with the recurrences restricted to the time range.
+     */
+    #[must_use]
+    pub fn slice_trimmed<D: Into<crate::DateTime>>(&self, start: D, end: D) -> Self {
+        crate::time_range::slice(self, &crate::time_range::TimeRange::new(start, end), true)
+    }
+
+    /**
+     * `TZID` referenced by the components.
+     */
+    pub(crate) fn tzids(&self) -> std::collections::BTreeSet<String> {
+        let date_times = self
+            .events
+            .iter()
+            .flat_map(crate::VEvent::date_times)
+            .chain(self.freebusy.iter().flat_map(crate::VFreebusy::date_times))
+            .chain(self.journals.iter().flat_map(crate::VJournal::date_times))
+            .chain(self.todo.iter().flat_map(crate::VTodo::date_times));
+
+        date_times
+            .filter_map(|date_time| date_time.tzid().map(str::to_string))
+            .collect()
+    }
+
+    /**
+     * Converts the date-times to `target` and updates the time zones accordingly, see
+     * [`crate::convert`].
+     */
+    pub fn convert(&mut self, target: crate::convert::Target) -> crate::Result {
+        crate::convert::vcalendar(self, target)
+    }
 }
 
 #[cfg(test)]
@@ -75,4 +147,37 @@ END:VCALENDAR\r
 
         Ok(())
     }
+
+    #[test]
+    fn ser_rfc7986() -> crate::Result {
+        let vcalendar = crate::vcalendar! {
+            version: "2.0",
+            prodid: "-//Example Corp.//CalDAV Client//EN",
+            uid: "5FC53010-1267-4F8E-BC28-1D7AE55A7C99",
+            refresh_interval: "P1W",
+            source: "http://example.com/holidays.ics",
+            color: Turquoise,
+            name: ["Company Vacation Days"],
+            image: ["http://example.com/images/cal.png"],
+        }?;
+
+        let ical = crate::ser::ical(&vcalendar);
+
+        similar_asserts::assert_eq!(
+            ical,
+            "BEGIN:VCALENDAR\r
+PRODID:-//Example Corp.//CalDAV Client//EN\r
+VERSION:2.0\r
+UID:5FC53010-1267-4F8E-BC28-1D7AE55A7C99\r
+REFRESH-INTERVAL;VALUE=DURATION:P1W\r
+SOURCE:http://example.com/holidays.ics\r
+COLOR:turquoise\r
+NAME:Company Vacation Days\r
+IMAGE;VALUE=URI:http://example.com/images/cal.png\r
+END:VCALENDAR\r
+"
+        );
+
+        Ok(())
+    }
 }
@@ -2,6 +2,8 @@
  * See [3.6.1. Event Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.1)
  */
 #[derive(Clone, Debug, Default, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct VEvent {
     pub dtstamp: crate::DateTime,
     pub uid: crate::Text,
@@ -19,10 +21,11 @@ pub struct VEvent {
     pub summary: Option<crate::Text>,
     pub transp: Option<crate::TimeTransparency>,
     pub url: Option<crate::Uri>,
+    pub color: Option<crate::Color>,
     pub recurid: Option<crate::Date>,
     pub rrule: Option<crate::Recur>,
     pub dtend: Option<crate::Date>,
-    pub duration: Option<chrono::Duration>,
+    pub duration: Option<crate::Duration>,
     pub attach: Vec<crate::Text>,
     pub attendee: Vec<crate::Uri>,
     #[component(append)]
@@ -36,6 +39,8 @@ pub struct VEvent {
     #[component(append)]
     pub resources: Vec<crate::Text>,
     pub rdate: Vec<crate::RDate>,
+    pub conference: Vec<crate::Conference>,
+    pub image: Vec<crate::Image>,
     #[component(ignore)]
     pub alarms: Vec<crate::VAlarm>,
     #[component(ignore)]
@@ -54,6 +59,70 @@ impl VEvent {
     pub fn recurrent(&self) -> crate::iter::Recur<Self> {
         crate::iter::Recur::from(self)
     }
+
+    #[must_use]
+    pub fn alarm_instants(&self) -> crate::iter::Alarms<'_, Self> {
+        crate::iter::Alarms::from(self)
+    }
+
+    /**
+     * Checks the constraints of RFC 5545 the parser doesn’t enforce, see [`crate::validate`].
+     */
+    #[must_use]
+    pub fn validate(&self) -> Vec<crate::validate::Finding> {
+        crate::validate::vevent(self)
+    }
+
+    /**
+     * Converts the date-times to `target`, the `TZID` are resolved from `timezones`, see
+     * [`crate::convert`].
+     */
+    pub fn convert(
+        &mut self,
+        timezones: &[crate::VTimezone],
+        target: crate::convert::Target,
+    ) -> crate::Result {
+        crate::convert::vevent(self, timezones, target)
+    }
+
+    /**
+     * Whether an instance overlaps `range`, the `TZID` are resolved from `timezones`, see
+     * [`crate::time_range`].
+     */
+    #[must_use]
+    pub fn overlaps(
+        &self,
+        range: &crate::time_range::TimeRange,
+        timezones: &[crate::VTimezone],
+    ) -> bool {
+        crate::time_range::Overlap::new(range, timezones).vevent(self, &[])
+    }
+
+    /**
+     * Date-times which may refer to a time zone.
+     */
+    pub(crate) fn date_times(&self) -> Vec<&crate::DateTime> {
+        crate::components::DateTimes::<&_>::default()
+            .date(Some(&self.dtstart))
+            .date(self.dtend.as_ref())
+            .date(self.recurid.as_ref())
+            .dates(&self.exdate)
+            .rdates(&self.rdate)
+            .into()
+    }
+
+    /**
+     * Mutable [`Self::date_times`].
+     */
+    pub(crate) fn date_times_mut(&mut self) -> Vec<&mut crate::DateTime> {
+        crate::components::DateTimes::<&mut _>::default()
+            .date(Some(&mut self.dtstart))
+            .date(self.dtend.as_mut())
+            .date(self.recurid.as_mut())
+            .dates(&mut self.exdate)
+            .rdates(&mut self.rdate)
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -98,6 +167,64 @@ END:VEVENT\r
         Ok(())
     }
 
+    #[test]
+    fn ser_tzid() -> crate::Result {
+        let input =
+            std::fs::read_to_string("tests/events/issue_112_missing_tzinfo_on_exdate.ics").unwrap();
+        let vevent = crate::VEvent::try_from(input)?;
+
+        assert_eq!(vevent.dtstart.tzid(), Some("America/New_York"));
+
+        let ical = crate::ser::ical(&vevent);
+
+        assert!(ical.contains("DTSTART;TZID=America/New_York:20130907T120000\r\n"));
+        assert!(ical.contains("EXDATE;TZID=America/New_York:20131012T120000,20131011T120000\r\n"));
+        assert_eq!(crate::VEvent::try_from(ical)?, vevent);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ser_alarms() -> crate::Result {
+        let mut vevent = crate::vevent! {
+            dtstamp: "20170209T192358",
+            uid: "5UILHLI7RI6K2IDRAQX7O",
+            dtstart: "20170209T100000",
+        }?;
+        vevent.alarms.push(crate::valarm! {
+            @display,
+            trigger: "-PT15M",
+            description: "Soon",
+        }?);
+        vevent.alarms.push(crate::valarm! {
+            @audio,
+            trigger: "-PT5M",
+        }?);
+
+        let ical = crate::ser::ical(&vevent);
+
+        similar_asserts::assert_eq!(
+            ical,
+            "BEGIN:VEVENT\r
+DTSTAMP:20170209T192358\r
+UID:5UILHLI7RI6K2IDRAQX7O\r
+DTSTART:20170209T100000\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+TRIGGER;VALUE=DURATION:-PT15M\r
+DESCRIPTION:Soon\r
+END:VALARM\r
+BEGIN:VALARM\r
+ACTION:AUDIO\r
+TRIGGER;VALUE=DURATION:-PT5M\r
+END:VALARM\r
+END:VEVENT\r
+"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn macros() -> crate::Result {
         let _vevent = crate::vevent! {
@@ -119,13 +246,14 @@ END:VEVENT\r
             status: Confirmed,
             summary: "Vers",
             transp: Transparent,
+            color: Turquoise,
             recurid: "20170210",
             rrule: {
                 freq: Yearly,
                 interval: 1,
             },
             dtend: "20170210",
-            duration: "P1Y",
+            duration: "P1W",
             attach: [""],
             attendee: [""],
             categories: [""],
@@ -141,6 +269,8 @@ END:VEVENT\r
             related_to: [""],
             resources: [""],
             //rdate: [],
+            conference: ["tel:+1-412-555-0123,,,654321"],
+            image: ["http://example.com/images/party.png"],
             alarms: [
                 //crate::valarm! {
                 //    @email,
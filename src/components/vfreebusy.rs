@@ -2,6 +2,8 @@
  * See [3.6.4. Free/Busy Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.4)
  */
 #[derive(Clone, Debug, Default, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct VFreebusy {
     pub dtstamp: crate::DateTime,
     pub uid: crate::Text,
@@ -25,6 +27,61 @@ impl VFreebusy {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /**
+     * Checks the constraints of RFC 5545 the parser doesn’t enforce, see [`crate::validate`].
+     */
+    #[must_use]
+    pub fn validate(&self) -> Vec<crate::validate::Finding> {
+        crate::validate::vfreebusy(self)
+    }
+
+    /**
+     * Converts the date-times to `target`, the `TZID` are resolved from `timezones`, see
+     * [`crate::convert`].
+     */
+    pub fn convert(
+        &mut self,
+        timezones: &[crate::VTimezone],
+        target: crate::convert::Target,
+    ) -> crate::Result {
+        crate::convert::vfreebusy(self, timezones, target)
+    }
+
+    /**
+     * Whether an instance overlaps `range`, the `TZID` are resolved from `timezones`, see
+     * [`crate::time_range`].
+     */
+    #[must_use]
+    pub fn overlaps(
+        &self,
+        range: &crate::time_range::TimeRange,
+        timezones: &[crate::VTimezone],
+    ) -> bool {
+        crate::time_range::Overlap::new(range, timezones).vfreebusy(self)
+    }
+
+    /**
+     * Date-times which may refer to a time zone.
+     */
+    pub(crate) fn date_times(&self) -> Vec<&crate::DateTime> {
+        crate::components::DateTimes::<&_>::default()
+            .date(self.dtstart.as_ref())
+            .date(self.dtend.as_ref())
+            .periods(&self.freebusy)
+            .into()
+    }
+
+    /**
+     * Mutable [`Self::date_times`].
+     */
+    pub(crate) fn date_times_mut(&mut self) -> Vec<&mut crate::DateTime> {
+        crate::components::DateTimes::<&mut _>::default()
+            .date(self.dtstart.as_mut())
+            .date(self.dtend.as_mut())
+            .periods(&mut self.freebusy)
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -2,6 +2,8 @@
  * See [3.6.3. Journal Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.3)
  */
 #[derive(Clone, Debug, Default, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct VJournal {
     pub dtstamp: crate::DateTime,
     pub uid: crate::Text,
@@ -15,6 +17,7 @@ pub struct VJournal {
     pub status: Option<crate::Status>,
     pub summary: Option<crate::Text>,
     pub url: Option<crate::Uri>,
+    pub color: Option<crate::Color>,
     pub rrule: Option<crate::Recur>,
     pub attach: Vec<crate::Text>,
     pub attendee: Vec<crate::Uri>,
@@ -28,6 +31,7 @@ pub struct VJournal {
     pub related_to: Vec<crate::Text>,
     pub rdate: Vec<crate::RDate>,
     pub rstatus: Vec<crate::RequestStatus>,
+    pub image: Vec<crate::Image>,
     #[component(ignore)]
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
@@ -44,6 +48,63 @@ impl VJournal {
     pub fn recurrent(&self) -> crate::iter::Recur<Self> {
         crate::iter::Recur::from(self)
     }
+
+    /**
+     * Checks the constraints of RFC 5545 the parser doesn’t enforce, see [`crate::validate`].
+     */
+    #[must_use]
+    pub fn validate(&self) -> Vec<crate::validate::Finding> {
+        crate::validate::vjournal(self)
+    }
+
+    /**
+     * Converts the date-times to `target`, the `TZID` are resolved from `timezones`, see
+     * [`crate::convert`].
+     */
+    pub fn convert(
+        &mut self,
+        timezones: &[crate::VTimezone],
+        target: crate::convert::Target,
+    ) -> crate::Result {
+        crate::convert::vjournal(self, timezones, target)
+    }
+
+    /**
+     * Whether an instance overlaps `range`, the `TZID` are resolved from `timezones`, see
+     * [`crate::time_range`].
+     */
+    #[must_use]
+    pub fn overlaps(
+        &self,
+        range: &crate::time_range::TimeRange,
+        timezones: &[crate::VTimezone],
+    ) -> bool {
+        crate::time_range::Overlap::new(range, timezones).vjournal(self, &[])
+    }
+
+    /**
+     * Date-times which may refer to a time zone.
+     */
+    pub(crate) fn date_times(&self) -> Vec<&crate::DateTime> {
+        crate::components::DateTimes::<&_>::default()
+            .date(Some(&self.dtstart))
+            .date(self.recurid.as_ref())
+            .dates(&self.exdate)
+            .rdates(&self.rdate)
+            .into()
+    }
+
+    /**
+     * Mutable [`Self::date_times`].
+     */
+    pub(crate) fn date_times_mut(&mut self) -> Vec<&mut crate::DateTime> {
+        crate::components::DateTimes::<&mut _>::default()
+            .date(Some(&mut self.dtstart))
+            .date(self.recurid.as_mut())
+            .dates(&mut self.exdate)
+            .rdates(&mut self.rdate)
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -75,12 +136,12 @@ UID:19970901T130000Z-123405@example.com\r
 DTSTART;VALUE=DATE:19970317\r
 SUMMARY:Staff meeting minutes\r
 DESCRIPTION:1. Staff meeting: Participants include Joe\\, Lisa\\, and Bob. Au\r
- rora project plans were reviewed. There is currently no budget reserves for\r
-  this project. Lisa will escalate to management. Next meeting on Tuesday.\\n\r
- 2. Telephone Conference: ABC Corp. sales representative called to discuss n\r
- ew printer. Promised to get us a demo by Friday.\\n3. Henry Miller (Handsoff\r
-  Insurance): Car was totaled by tree. Is looking into a loaner car. 555-232\r
- 3 (tel).\r
+ rora project plans were reviewed. There is currently no budget reserves fo\r
+ r this project. Lisa will escalate to management. Next meeting on Tuesday.\r
+ \\n2. Telephone Conference: ABC Corp. sales representative called to discus\r
+ s new printer. Promised to get us a demo by Friday.\\n3. Henry Miller (Hand\r
+ soff Insurance): Car was totaled by tree. Is looking into a loaner car. 55\r
+ 5-2323 (tel).\r
 END:VJOURNAL\r
 "
         );
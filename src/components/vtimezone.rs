@@ -1,9 +1,12 @@
+use chrono::Datelike as _;
 use std::collections::BTreeMap;
 
 /**
  * See [3.6.5. Time Zone Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.5)
  */
 #[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct VTimezone {
     pub tzid: crate::Text,
     pub last_modified: Option<crate::DateTime>,
@@ -23,6 +26,218 @@ impl VTimezone {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /**
+     * Checks the constraints of RFC 5545 the parser doesn’t enforce, see [`crate::validate`].
+     */
+    #[must_use]
+    pub fn validate(&self) -> Vec<crate::validate::Finding> {
+        crate::validate::vtimezone(self)
+    }
+
+    /**
+     * UTC offset in effect at the UTC date-time `utc`, from the `STANDARD` and `DAYLIGHT`
+     * observances.
+     *
+     * Only yearly recurrence rules are expanded, which covers the time zones generated from
+     * the tz database.
+     */
+    #[must_use]
+    pub fn offset_at_utc(&self, utc: chrono::NaiveDateTime) -> Option<chrono::FixedOffset> {
+        self.offset(utc.year(), |transition| {
+            transition.onset - chrono::TimeDelta::seconds(transition.from.local_minus_utc().into())
+                <= utc
+        })
+    }
+
+    /**
+     * UTC offset of the local date-time `local`.
+     *
+     * A local time skipped or repeated by a transition uses the offset of the observance in
+     * effect before it.
+     */
+    #[must_use]
+    pub fn offset_at_local(&self, local: chrono::NaiveDateTime) -> Option<chrono::FixedOffset> {
+        self.offset(local.year(), |transition| {
+            let shift = transition.to.local_minus_utc() - transition.from.local_minus_utc();
+
+            transition.onset + chrono::TimeDelta::seconds(shift.max(0).into()) <= local
+        })
+    }
+
+    fn offset(
+        &self,
+        year: i32,
+        started: impl Fn(&Transition) -> bool,
+    ) -> Option<chrono::FixedOffset> {
+        let transitions = self.transitions(year);
+
+        match transitions
+            .iter()
+            .rev()
+            .find(|transition| started(transition))
+        {
+            Some(transition) => Some(transition.to),
+            None => transitions.first().map(|transition| transition.from),
+        }
+    }
+
+    /**
+     * Onsets of the observances until the end of the year following `year`, sorted.
+     */
+    fn transitions(&self, year: i32) -> Vec<Transition> {
+        let standard = self.standard.iter().map(|standard| {
+            (
+                &standard.dtstart,
+                standard.rrule.as_ref(),
+                standard.rdate.as_slice(),
+                standard.tzoffsetfrom,
+                standard.tzoffsetto,
+            )
+        });
+        let daylight = self.daylight.iter().map(|daylight| {
+            (
+                &daylight.dtstart,
+                daylight.rrule.as_ref(),
+                daylight.rdate.as_slice(),
+                daylight.tzoffsetfrom,
+                daylight.tzoffsetto,
+            )
+        });
+
+        let mut transitions = standard
+            .chain(daylight)
+            .flat_map(|(dtstart, rrule, rdate, from, to)| {
+                onsets(dtstart, rrule, rdate, from, year + 1)
+                    .into_iter()
+                    .map(move |onset| Transition { onset, from, to })
+            })
+            .collect::<Vec<_>>();
+
+        transitions.sort_by_key(|transition| transition.onset);
+
+        transitions
+    }
+}
+
+struct Transition {
+    /** Local time of the onset, in the `from` offset */
+    onset: chrono::NaiveDateTime,
+    from: chrono::FixedOffset,
+    to: chrono::FixedOffset,
+}
+
+fn onsets(
+    dtstart: &crate::Date,
+    rrule: Option<&crate::Recur>,
+    rdate: &[crate::RDate],
+    from: chrono::FixedOffset,
+    last_year: i32,
+) -> Vec<chrono::NaiveDateTime> {
+    let dtstart = crate::DateTime::from(dtstart.clone()).naive();
+    let mut onsets = vec![dtstart];
+
+    for rdate in rdate {
+        match rdate {
+            crate::RDate::Date(dates) => onsets.extend(
+                dates
+                    .iter()
+                    .map(|date| crate::DateTime::from(date.clone()).naive()),
+            ),
+            crate::RDate::Period(periods) => {
+                onsets.extend(periods.iter().map(|period| match period {
+                    crate::Period::StartEnd(crate::period::StartEnd { start, .. })
+                    | crate::Period::StartDur(crate::period::StartDur { start, .. }) => {
+                        start.naive()
+                    }
+                }))
+            }
+        }
+    }
+
+    let Some(rrule) = rrule.filter(|rrule| rrule.freq == crate::Freq::Yearly) else {
+        return onsets;
+    };
+
+    // `UNTIL` is in UTC, the onsets are local times in the `from` offset
+    let until = rrule.until.as_ref().map(|until| match until {
+        crate::Date::DateTime(crate::DateTime::Local(utc)) => {
+            utc.naive_utc() + chrono::TimeDelta::seconds(from.local_minus_utc().into())
+        }
+        until => crate::DateTime::from(until.clone()).naive(),
+    });
+    let count = rrule.count.map_or(u32::MAX, u32::from);
+    let interval = i32::from(rrule.interval.max(1));
+
+    let years = (dtstart.year() + interval..=last_year)
+        .step_by(interval as usize)
+        .take(count.saturating_sub(1) as usize);
+
+    for year in years {
+        let Some(onset) = onset(rrule, dtstart, year) else {
+            continue;
+        };
+
+        if until.is_some_and(|until| onset > until) {
+            break;
+        }
+
+        onsets.push(onset);
+    }
+
+    onsets
+}
+
+/**
+ * Occurrence of a yearly rule like `BYMONTH=3;BYDAY=-1SU` or `BYMONTH=4;BYMONTHDAY=1,...,7;BYDAY=SU`.
+ */
+fn onset(
+    rrule: &crate::Recur,
+    dtstart: chrono::NaiveDateTime,
+    year: i32,
+) -> Option<chrono::NaiveDateTime> {
+    let month = rrule
+        .by_month
+        .first()
+        .map_or(dtstart.month(), |month| *month as u32);
+
+    let date = match rrule.by_day.first() {
+        Some(day) if !rrule.by_monthday.is_empty() => rrule
+            .by_monthday
+            .iter()
+            .filter_map(|monthday| chrono::NaiveDate::from_ymd_opt(year, month, *monthday as u32))
+            .find(|date| date.weekday() == chrono::Weekday::from(&day.weekday))?,
+        Some(day) => {
+            let weekday = chrono::Weekday::from(&day.weekday);
+
+            match day.ord.unwrap_or(1) {
+                ord @ 1.. => {
+                    chrono::NaiveDate::from_weekday_of_month_opt(year, month, weekday, ord as u8)?
+                }
+                ord => {
+                    let last = chrono::NaiveDate::from_ymd_opt(year, month, 1)?
+                        .checked_add_months(chrono::Months::new(1))?
+                        .pred_opt()?;
+                    let back = (last.weekday().num_days_from_monday() + 7
+                        - weekday.num_days_from_monday())
+                        % 7;
+
+                    last - chrono::TimeDelta::days(i64::from(back))
+                        + chrono::TimeDelta::weeks(i64::from(ord + 1))
+                }
+            }
+        }
+        None => chrono::NaiveDate::from_ymd_opt(
+            year,
+            month,
+            rrule
+                .by_monthday
+                .first()
+                .map_or(dtstart.day(), |monthday| *monthday as u32),
+        )?,
+    };
+
+    Some(date.and_time(dtstart.time()))
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -34,9 +249,13 @@ pub(crate) enum Component {
 macro_rules! prop {
     ($name:ident) => {
         #[derive(Clone, Debug, Eq, PartialEq, crate::Component)]
+        #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+        #[cfg_attr(feature = "serde", serde(default))]
         pub struct $name {
             pub dtstart: crate::Date,
+            #[cfg_attr(feature = "serde", serde(with = "crate::serde::utc_offset"))]
             pub tzoffsetto: chrono::offset::FixedOffset,
+            #[cfg_attr(feature = "serde", serde(with = "crate::serde::utc_offset"))]
             pub tzoffsetfrom: chrono::offset::FixedOffset,
             pub rrule: Option<crate::Recur>,
             pub comment: Vec<crate::Text>,
@@ -78,6 +297,56 @@ prop!(Daylight);
 
 #[cfg(test)]
 mod test {
+    fn new_york() -> crate::VTimezone {
+        std::fs::read_to_string("tests/timezones/issue_53_tzid_parsed_properly.ics")
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn offset_at_utc() {
+        let vtimezone = new_york();
+        let tests = [
+            ("2021-01-15T12:00:00", -5),
+            ("2021-03-14T06:59:59", -5),
+            ("2021-03-14T07:00:00", -4),
+            ("2021-11-07T05:59:59", -4),
+            ("2021-11-07T06:00:00", -5),
+            ("1960-06-01T00:00:00", -5),
+        ];
+
+        for (utc, hours) in tests {
+            assert_eq!(
+                vtimezone.offset_at_utc(utc.parse().unwrap()),
+                chrono::FixedOffset::east_opt(hours * 3_600),
+                "{utc}"
+            );
+        }
+    }
+
+    #[test]
+    fn offset_at_local() {
+        let vtimezone = new_york();
+        let tests = [
+            ("2021-03-14T01:59:59", -5),
+            // Skipped
+            ("2021-03-14T02:30:00", -5),
+            ("2021-03-14T03:00:00", -4),
+            // Repeated
+            ("2021-11-07T01:30:00", -4),
+            ("2021-11-07T02:00:00", -5),
+        ];
+
+        for (local, hours) in tests {
+            assert_eq!(
+                vtimezone.offset_at_local(local.parse().unwrap()),
+                chrono::FixedOffset::east_opt(hours * 3_600),
+                "{local}"
+            );
+        }
+    }
+
     #[test]
     fn parse() {
         crate::test::test_files::<crate::VTimezone>("timezones");
@@ -2,6 +2,8 @@
  * See [3.6.2. To-Do Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.2)
  */
 #[derive(Clone, Default, Debug, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct VTodo {
     pub dtstamp: crate::DateTime,
     pub uid: crate::Text,
@@ -20,9 +22,10 @@ pub struct VTodo {
     pub status: Option<crate::Status>,
     pub summary: Option<crate::Text>,
     pub url: Option<crate::Uri>,
+    pub color: Option<crate::Color>,
     pub rrule: Option<crate::Recur>,
     pub due: Option<crate::Date>,
-    pub duration: Option<chrono::Duration>,
+    pub duration: Option<crate::Duration>,
     pub attach: Vec<crate::Text>,
     pub attendee: Vec<crate::Uri>,
     #[component(append)]
@@ -36,6 +39,10 @@ pub struct VTodo {
     #[component(append)]
     pub resources: Vec<crate::Text>,
     pub rdate: Vec<crate::RDate>,
+    pub conference: Vec<crate::Conference>,
+    pub image: Vec<crate::Image>,
+    #[component(ignore)]
+    pub alarms: Vec<crate::VAlarm>,
     #[component(ignore)]
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
@@ -52,6 +59,70 @@ impl VTodo {
     pub fn recurrent(&self) -> crate::iter::Recur<Self> {
         crate::iter::Recur::from(self)
     }
+
+    #[must_use]
+    pub fn alarm_instants(&self) -> crate::iter::Alarms<'_, Self> {
+        crate::iter::Alarms::from(self)
+    }
+
+    /**
+     * Checks the constraints of RFC 5545 the parser doesn’t enforce, see [`crate::validate`].
+     */
+    #[must_use]
+    pub fn validate(&self) -> Vec<crate::validate::Finding> {
+        crate::validate::vtodo(self)
+    }
+
+    /**
+     * Converts the date-times to `target`, the `TZID` are resolved from `timezones`, see
+     * [`crate::convert`].
+     */
+    pub fn convert(
+        &mut self,
+        timezones: &[crate::VTimezone],
+        target: crate::convert::Target,
+    ) -> crate::Result {
+        crate::convert::vtodo(self, timezones, target)
+    }
+
+    /**
+     * Whether an instance overlaps `range`, the `TZID` are resolved from `timezones`, see
+     * [`crate::time_range`].
+     */
+    #[must_use]
+    pub fn overlaps(
+        &self,
+        range: &crate::time_range::TimeRange,
+        timezones: &[crate::VTimezone],
+    ) -> bool {
+        crate::time_range::Overlap::new(range, timezones).vtodo(self, &[])
+    }
+
+    /**
+     * Date-times which may refer to a time zone.
+     */
+    pub(crate) fn date_times(&self) -> Vec<&crate::DateTime> {
+        crate::components::DateTimes::<&_>::default()
+            .date(self.dtstart.as_ref())
+            .date(self.due.as_ref())
+            .date(self.recurid.as_ref())
+            .dates(&self.exdate)
+            .rdates(&self.rdate)
+            .into()
+    }
+
+    /**
+     * Mutable [`Self::date_times`].
+     */
+    pub(crate) fn date_times_mut(&mut self) -> Vec<&mut crate::DateTime> {
+        crate::components::DateTimes::<&mut _>::default()
+            .date(self.dtstart.as_mut())
+            .date(self.due.as_mut())
+            .date(self.recurid.as_mut())
+            .dates(&mut self.exdate)
+            .rdates(&mut self.rdate)
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -5,6 +5,7 @@ use std::collections::BTreeMap;
  */
 
 #[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ContentLine {
     pub key: String,
     pub params: BTreeMap<String, String>,
@@ -54,15 +55,15 @@ impl std::fmt::Display for ContentLine {
 }
 
 impl crate::ser::Serialize for ContentLine {
-    fn ical(&self) -> String {
-        self.to_string().ical()
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(&self.value)
     }
 
     fn attr(&self) -> Option<String> {
         if self.params.is_empty() {
             None
         } else {
-            Some(self.params.ical())
+            Some(crate::ser::params(&self.params))
         }
     }
 }
@@ -0,0 +1,571 @@
+/*!
+ * Conversion of date-times to another time zone.
+ *
+ * [`crate::VCalendar::convert`] rewrites `DTSTART`, `DTEND`, `DUE`, `RECURRENCE-ID`, `EXDATE`
+ * and `RDATE` into the [`Target`] time zone. The offsets come from the `VTIMEZONE` of the
+ * calendar, the ones no longer referenced are removed and the target one is added.
+ *
+ * Dates without time and floating date-times are bound to no time zone and stay unchanged.
+ * `FREEBUSY`, the free/busy `DTSTART` and `DTEND`, absolute `TRIGGER` and `UNTIL` must be in
+ * UTC, they are converted to UTC whatever the target.
+ *
+ * A recurrence rule expands in the local time of its `DTSTART`: a weekly event at 10:00 in Paris
+ * occurs at 09:00Z in winter and at 08:00Z in summer, which no other time zone can express. The
+ * recurring components, with a `RRULE` or `RDATE`, and their overridden instances keep their
+ * date-times and time zone, only their `UNTIL` and absolute `TRIGGER` are converted to UTC.
+ *
+ * ```
+ * let mut vcalendar: ikal::VCalendar = "BEGIN:VCALENDAR\r
+ * VERSION:2.0\r
+ * PRODID:-//ikal//EN\r
+ * BEGIN:VTIMEZONE\r
+ * TZID:Europe/Paris\r
+ * BEGIN:STANDARD\r
+ * DTSTART:19701025T030000\r
+ * TZOFFSETFROM:+0200\r
+ * TZOFFSETTO:+0100\r
+ * RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+ * END:STANDARD\r
+ * BEGIN:DAYLIGHT\r
+ * DTSTART:19700329T020000\r
+ * TZOFFSETFROM:+0100\r
+ * TZOFFSETTO:+0200\r
+ * RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+ * END:DAYLIGHT\r
+ * END:VTIMEZONE\r
+ * BEGIN:VEVENT\r
+ * UID:a8df6573-0474-496d-8496-033ad45d7fea\r
+ * DTSTAMP:20210517T051025Z\r
+ * DTSTART;TZID=Europe/Paris:20210517T090000\r
+ * END:VEVENT\r
+ * END:VCALENDAR\r
+ * ".parse()?;
+ *
+ * vcalendar.convert(ikal::convert::Target::Utc)?;
+ *
+ * assert_eq!(vcalendar.events[0].dtstart.to_string(), "20210517T070000Z");
+ * assert!(vcalendar.timezones.is_empty());
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+
+#[derive(Clone, Copy, Debug)]
+pub enum Target<'a> {
+    Utc,
+    /** Time zone with at least one observance */
+    Timezone(&'a crate::VTimezone),
+}
+
+struct Converter<'a> {
+    timezones: &'a [crate::VTimezone],
+    target: Target<'a>,
+}
+
+impl<'a> Converter<'a> {
+    fn new(timezones: &'a [crate::VTimezone], target: Target<'a>) -> Self {
+        Self { timezones, target }
+    }
+
+    /**
+     * Definition of a `TZID`, a Windows or legacy name matches its IANA time zone.
+     */
+    fn timezone(&self, tzid: &str) -> crate::Result<&'a crate::VTimezone> {
+        let target = match self.target {
+            Target::Utc => None,
+            Target::Timezone(vtimezone) => Some(vtimezone),
+        };
+        let mut timezones = self.timezones.iter().chain(target);
+        let iana = crate::tzid::iana(tzid);
+
+        timezones
+            .clone()
+            .find(|vtimezone| vtimezone.tzid.text.as_str() == tzid)
+            .or_else(|| {
+                timezones
+                    .find(|vtimezone| iana.is_some() && crate::tzid::iana(&vtimezone.tzid) == iana)
+            })
+            .ok_or_else(|| crate::Error::Timezone(tzid.to_string()))
+    }
+
+    fn date_times(&self, date_times: Vec<&mut crate::DateTime>) -> crate::Result {
+        for date_time in date_times {
+            self.convert(date_time, self.target)?;
+        }
+
+        Ok(())
+    }
+
+    fn all_utc(&self, date_times: Vec<&mut crate::DateTime>) -> crate::Result {
+        for date_time in date_times {
+            self.convert(date_time, Target::Utc)?;
+        }
+
+        Ok(())
+    }
+
+    fn alarms(&self, alarms: &mut [crate::VAlarm]) -> crate::Result {
+        for alarm in alarms {
+            if let crate::Trigger::DateTime(date_time) = alarm.trigger_mut() {
+                self.convert(date_time, Target::Utc)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Converts `UNTIL` to UTC, a floating one is in the time zone of `DTSTART`.
+     */
+    fn until(
+        &self,
+        rrule: Option<&mut crate::Recur>,
+        dtstart: Option<&crate::Date>,
+    ) -> crate::Result {
+        let Some(crate::Date::DateTime(until)) = rrule.and_then(|rrule| rrule.until.as_mut())
+        else {
+            return Ok(());
+        };
+
+        if let Some(tzid) = dtstart.and_then(crate::Date::tzid) {
+            *until = std::mem::take(until).with_tzid(tzid);
+        }
+
+        self.convert(until, Target::Utc)
+    }
+
+    /**
+     * UTC time of a date-time, `None` for a floating one.
+     */
+    fn utc(&self, date_time: &crate::DateTime) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        let utc = match date_time {
+            crate::DateTime::Naive(_) => return Ok(None),
+            crate::DateTime::Local(local) => local.naive_utc(),
+            crate::DateTime::Zoned(naive, tzid) => {
+                let offset = self
+                    .timezone(tzid)?
+                    .offset_at_local(*naive)
+                    .ok_or_else(|| crate::Error::Timezone(tzid.to_string()))?;
+
+                date_time - chrono::TimeDelta::seconds(offset.local_minus_utc().into())
+            }
+        };
+
+        Ok(Some(utc))
+    }
+
+    fn convert(&self, date_time: &mut crate::DateTime, target: Target) -> crate::Result {
+        if let (Target::Timezone(vtimezone), Some(tzid)) = (target, date_time.tzid())
+            && vtimezone.tzid.text.as_str() == tzid
+        {
+            return Ok(());
+        }
+
+        let Some(utc) = self.utc(date_time)? else {
+            return Ok(());
+        };
+
+        *date_time = match target {
+            Target::Utc => crate::DateTime::Local(utc.and_utc().with_timezone(&chrono::Local)),
+            Target::Timezone(vtimezone) => {
+                let offset = vtimezone
+                    .offset_at_utc(utc)
+                    .ok_or_else(|| crate::Error::Timezone(vtimezone.tzid.to_string()))?;
+
+                crate::DateTime::Zoned(utc, vtimezone.tzid.text.as_str().into())
+                    + chrono::TimeDelta::seconds(offset.local_minus_utc().into())
+            }
+        };
+
+        Ok(())
+    }
+}
+
+/**
+ * UTC time of a date-time with its `TZID` resolved from `timezones`, `None` for a floating one.
+ */
+pub(crate) fn utc(
+    date_time: &crate::DateTime,
+    timezones: &[crate::VTimezone],
+) -> crate::Result<Option<chrono::NaiveDateTime>> {
+    Converter::new(timezones, Target::Utc).utc(date_time)
+}
+
+/**
+ * UTC time of a date-time, a floating one or one with a `TZID` not in `timezones` is in the
+ * local time zone.
+ */
+pub(crate) fn instant(
+    date_time: &crate::DateTime,
+    timezones: &[crate::VTimezone],
+) -> chrono::NaiveDateTime {
+    if let Ok(Some(utc)) = utc(date_time, timezones) {
+        return utc;
+    }
+
+    let naive = date_time.naive();
+
+    naive
+        .and_local_timezone(chrono::Local)
+        .earliest()
+        .map_or(naive, |local| local.naive_utc())
+}
+
+/**
+ * Adds `duration` to a date-time, a zoned one moves its wall clock by the nominal days then
+ * the UTC time by the exact part, with the offsets of its `TZID` resolved from `timezones`.
+ */
+pub(crate) fn add(
+    date_time: &crate::DateTime,
+    duration: crate::Duration,
+    timezones: &[crate::VTimezone],
+) -> crate::Result<crate::DateTime> {
+    let crate::DateTime::Zoned(naive, tzid) = date_time else {
+        return Ok(date_time.clone() + duration);
+    };
+
+    let converter = Converter::new(timezones, Target::Utc);
+    let vtimezone = converter.timezone(tzid)?;
+    let wall = crate::DateTime::Zoned(*naive, tzid.clone())
+        + chrono::TimeDelta::days(duration.nominal_days());
+    let Some(utc) = converter.utc(&wall)? else {
+        return Ok(wall);
+    };
+    let end = crate::DateTime::Zoned(utc, tzid.clone()) + duration.exact();
+    let offset = vtimezone
+        .offset_at_utc(end.naive())
+        .ok_or_else(|| crate::Error::Timezone(tzid.to_string()))?;
+
+    Ok(end + chrono::TimeDelta::seconds(offset.local_minus_utc().into()))
+}
+
+pub(crate) fn vcalendar(vcalendar: &mut crate::VCalendar, target: Target) -> crate::Result {
+    let mut converted = vcalendar.clone();
+    let timezones = &vcalendar.timezones;
+
+    for vevent in &mut converted.events {
+        self::vevent(vevent, timezones, target)?;
+    }
+
+    for vfreebusy in &mut converted.freebusy {
+        self::vfreebusy(vfreebusy, timezones, target)?;
+    }
+
+    for vjournal in &mut converted.journals {
+        self::vjournal(vjournal, timezones, target)?;
+    }
+
+    for vtodo in &mut converted.todo {
+        self::vtodo(vtodo, timezones, target)?;
+    }
+
+    Converter::new(timezones, target).alarms(&mut converted.alarms)?;
+
+    let tzids = converted.tzids();
+
+    converted
+        .timezones
+        .retain(|vtimezone| tzids.contains(vtimezone.tzid.text.as_str()));
+
+    if let Target::Timezone(vtimezone) = target {
+        let defined = converted
+            .timezones
+            .iter()
+            .any(|defined| defined.tzid == vtimezone.tzid);
+
+        if tzids.contains(vtimezone.tzid.text.as_str()) && !defined {
+            converted.timezones.push(vtimezone.clone());
+        }
+    }
+
+    *vcalendar = converted;
+
+    Ok(())
+}
+
+pub(crate) fn vevent(
+    vevent: &mut crate::VEvent,
+    timezones: &[crate::VTimezone],
+    target: Target,
+) -> crate::Result {
+    let converter = Converter::new(timezones, target);
+    let mut converted = vevent.clone();
+
+    converter.until(converted.rrule.as_mut(), Some(&converted.dtstart))?;
+    if !recurring(vevent) {
+        converter.date_times(converted.date_times_mut())?;
+    }
+    converter.alarms(&mut converted.alarms)?;
+
+    *vevent = converted;
+
+    Ok(())
+}
+
+pub(crate) fn vfreebusy(
+    vfreebusy: &mut crate::VFreebusy,
+    timezones: &[crate::VTimezone],
+    target: Target,
+) -> crate::Result {
+    let converter = Converter::new(timezones, target);
+    let mut converted = vfreebusy.clone();
+
+    converter.all_utc(converted.date_times_mut())?;
+
+    *vfreebusy = converted;
+
+    Ok(())
+}
+
+pub(crate) fn vjournal(
+    vjournal: &mut crate::VJournal,
+    timezones: &[crate::VTimezone],
+    target: Target,
+) -> crate::Result {
+    let converter = Converter::new(timezones, target);
+    let mut converted = vjournal.clone();
+
+    converter.until(converted.rrule.as_mut(), Some(&converted.dtstart))?;
+    if !recurring(vjournal) {
+        converter.date_times(converted.date_times_mut())?;
+    }
+
+    *vjournal = converted;
+
+    Ok(())
+}
+
+pub(crate) fn vtodo(
+    vtodo: &mut crate::VTodo,
+    timezones: &[crate::VTimezone],
+    target: Target,
+) -> crate::Result {
+    let converter = Converter::new(timezones, target);
+    let mut converted = vtodo.clone();
+
+    converter.until(converted.rrule.as_mut(), converted.dtstart.as_ref())?;
+    if !recurring(vtodo) {
+        converter.date_times(converted.date_times_mut())?;
+    }
+    converter.alarms(&mut converted.alarms)?;
+
+    *vtodo = converted;
+
+    Ok(())
+}
+
+/**
+ * Whether `item` belongs to a recurrence set, as its master or an overridden instance.
+ */
+fn recurring<T: crate::iter::Override>(item: &T) -> bool {
+    item.rrule().is_some() || !item.rdate().is_empty() || item.recurid().is_some()
+}
+
+#[cfg(test)]
+mod test {
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//EN\r
+BEGIN:VTIMEZONE\r
+TZID:Europe/Paris\r
+BEGIN:STANDARD\r
+DTSTART:19701025T030000\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:19700329T020000\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20210517T051025Z\r
+DTSTART;TZID=Europe/Paris:20210301T100000\r
+DTEND;TZID=Europe/Paris:20210301T110000\r
+RRULE:FREQ=WEEKLY;UNTIL=20210405T100000\r
+EXDATE;TZID=Europe/Paris:20210308T100000\r
+RDATE;TZID=Europe/Paris:20210407T100000\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+DESCRIPTION:Reminder\r
+TRIGGER;VALUE=DATE-TIME:20210301T080000Z\r
+END:VALARM\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:2\r
+DTSTAMP:20210517T051025Z\r
+DTSTART;VALUE=DATE:20210601\r
+DTEND;VALUE=DATE:20210602\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:3\r
+DTSTAMP:20210517T051025Z\r
+DTSTART:20210601T090000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:4\r
+DTSTAMP:20210517T051025Z\r
+DTSTART:20210601T090000Z\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:5\r
+DTSTAMP:20210517T051025Z\r
+DTSTART;TZID=Europe/Paris:20210701T100000\r
+DTEND;TZID=Europe/Paris:20210701T110000\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    #[test]
+    fn utc() -> crate::Result {
+        let mut vcalendar: crate::VCalendar = CALENDAR.parse()?;
+        let recurring = vcalendar.events[0].clone();
+        vcalendar.convert(super::Target::Utc)?;
+
+        let vevent = &vcalendar.events[0];
+        assert_eq!(vevent.dtstart, recurring.dtstart);
+        assert_eq!(vevent.dtend, recurring.dtend);
+        assert_eq!(vevent.exdate, recurring.exdate);
+        assert_eq!(vevent.rdate, recurring.rdate);
+        assert_eq!(
+            vevent
+                .rrule
+                .as_ref()
+                .unwrap()
+                .until
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            "20210405T080000Z"
+        );
+        assert_eq!(
+            vevent.alarms[0].trigger(),
+            &crate::Trigger::DateTime("20210301T080000Z".parse()?)
+        );
+
+        assert_eq!(vcalendar.events[1].dtstart, "20210601".parse()?);
+        assert_eq!(vcalendar.events[2].dtstart, "20210601T090000".parse()?);
+        assert_eq!(vcalendar.events[3].dtstart, "20210601T090000Z".parse()?);
+
+        let vevent = &vcalendar.events[4];
+        assert_eq!(vevent.dtstart.to_string(), "20210701T080000Z");
+        assert_eq!(
+            vevent.dtend.as_ref().unwrap().to_string(),
+            "20210701T090000Z"
+        );
+
+        assert_eq!(vcalendar.timezones.len(), 1);
+        assert_eq!(vcalendar.timezones[0].tzid.text, "Europe/Paris");
+
+        Ok(())
+    }
+
+    #[test]
+    fn utc_single() -> crate::Result {
+        let mut vcalendar: crate::VCalendar = CALENDAR.parse()?;
+        vcalendar.events.remove(0);
+        vcalendar.convert(super::Target::Utc)?;
+
+        assert_eq!(vcalendar.events[3].dtstart.to_string(), "20210701T080000Z");
+        assert!(vcalendar.timezones.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn timezone() -> crate::Result {
+        let new_york: crate::VTimezone =
+            std::fs::read_to_string("tests/timezones/issue_53_tzid_parsed_properly.ics")
+                .unwrap()
+                .parse()?;
+
+        let mut vcalendar: crate::VCalendar = CALENDAR.parse()?;
+        let recurring = vcalendar.events[0].clone();
+        let paris = vcalendar.timezones[0].clone();
+        vcalendar.convert(super::Target::Timezone(&new_york))?;
+
+        let vevent = &vcalendar.events[0];
+        assert_eq!(vevent.dtstart, recurring.dtstart);
+        assert_eq!(vevent.exdate, recurring.exdate);
+        assert_eq!(
+            vevent
+                .rrule
+                .as_ref()
+                .unwrap()
+                .until
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            "20210405T080000Z"
+        );
+        assert_eq!(
+            vevent.alarms[0].trigger(),
+            &crate::Trigger::DateTime("20210301T080000Z".parse()?)
+        );
+        assert_eq!(vcalendar.events[3].dtstart.to_string(), "20210601T050000");
+        assert_eq!(
+            vcalendar.events[4].dtstart,
+            crate::Date::DateTime(
+                "20210701T040000"
+                    .parse::<crate::DateTime>()?
+                    .with_tzid("America/New_York")
+            )
+        );
+        assert_eq!(vcalendar.timezones, [paris, new_york]);
+        assert!(vcalendar.validate().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn add() -> crate::Result {
+        let vcalendar: crate::VCalendar = CALENDAR.parse()?;
+        let timezones = &vcalendar.timezones;
+        let zoned = "20240330T120000"
+            .parse::<crate::DateTime>()?
+            .with_tzid("Europe/Paris");
+
+        // The day across the DST transition lasts 23 hours
+        let day = super::add(&zoned, crate::Duration::days(1), timezones)?;
+        assert_eq!(crate::ser::ical(&day), "TZID=Europe/Paris:20240331T120000");
+        assert_eq!(
+            super::utc(&day, timezones)?.zip(super::utc(&zoned, timezones)?),
+            Some((
+                "20240331T100000".parse::<crate::DateTime>()?.naive(),
+                "20240330T110000".parse::<crate::DateTime>()?.naive()
+            ))
+        );
+
+        let hours = super::add(&zoned, crate::Duration::hours(24), timezones)?;
+        assert_eq!(
+            crate::ser::ical(&hours),
+            "TZID=Europe/Paris:20240331T130000"
+        );
+
+        let utc: crate::DateTime = "20240330T110000Z".parse()?;
+        assert_eq!(
+            super::add(&utc, crate::Duration::days(1), timezones)?,
+            utc + crate::Duration::hours(24)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_timezone() -> crate::Result {
+        let mut vcalendar: crate::VCalendar = CALENDAR.parse()?;
+        vcalendar.timezones.clear();
+        let expected = vcalendar.clone();
+
+        assert_eq!(
+            vcalendar.convert(super::Target::Utc),
+            Err(crate::Error::Timezone("Europe/Paris".to_string()))
+        );
+        assert_eq!(vcalendar, expected);
+
+        Ok(())
+    }
+}
@@ -2,16 +2,38 @@ pub type Result<T = ()> = std::result::Result<T, Error>;
 
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum Error {
-    #[error("Invalid alarm action: {0}")]
-    Alarm(String),
+    #[error("Alarm not found in {0}")]
+    AlarmNotFound(String),
+    #[error("Invalid boolean {0}")]
+    Boolean(String),
+    #[error("Invalid color {0}")]
+    Color(String),
+    #[error("{0} was modified")]
+    Conflict(String),
     #[error("{0}")]
     Date(#[from] chrono::ParseError),
+    #[error("{0} already exists")]
+    Exists(String),
+    #[error("Invalid CalDAV filter: {0}")]
+    Filter(String),
     #[error("Invalid freq {0}")]
     Freq(String),
+    #[error("Invalid iMIP message: {0}")]
+    Imip(String),
+    #[error("{0}")]
+    Io(String),
+    #[error("Invalid iTIP message: {0}")]
+    Itip(String),
+    #[error("Invalid jCal: {0}")]
+    Jcal(String),
+    #[error("Invalid JSCalendar: {0}")]
+    Jscalendar(String),
     #[error("Unknow key {0}")]
     Key(String),
     #[error("Invalid date in local timezone: {0:?}")]
     Local(crate::properties::DateTime),
+    #[error("{0} not found")]
+    NotFound(String),
     #[error("{0}")]
     ParseDuration(String),
     #[error("{0}")]
@@ -20,14 +42,32 @@ pub enum Error {
     Parser(String),
     #[error("Invalid priority: {0}")]
     Priority(u8),
+    #[error("Invalid trigger relationship {0}")]
+    Related(String),
+    #[error("{0}")]
+    Serde(String),
     #[error("{0}")]
     Serialize(String),
+    #[error("CalDAV server error: {0}")]
+    Server(String),
     #[error("Unknow status {0}")]
     Status(String),
+    #[error("Invalid sync status: {0}")]
+    Sync(String),
     #[error("Unknow time transparency {0}")]
     TimeTransparency(String),
+    #[error("Unknown time zone {0}")]
+    Timezone(String),
     #[error("Invalid weekday {0}")]
     Weekday(String),
+    #[error("Invalid xCal: {0}")]
+    Xcal(String),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value.to_string())
+    }
 }
 
 impl<I: std::fmt::Debug> From<nom::Err<nom_language::error::VerboseError<I>>> for Error {
@@ -35,3 +75,17 @@ impl<I: std::fmt::Debug> From<nom::Err<nom_language::error::VerboseError<I>>> fo
         Self::Parser(format!("{value:#?}"))
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::Serde(msg.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::ser::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::Serde(msg.to_string())
+    }
+}
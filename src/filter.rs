@@ -0,0 +1,826 @@
+/*!
+ * CalDAV `calendar-query` filters, see
+ * [RFC 4791 section 9.7](https://datatracker.ietf.org/doc/html/rfc4791#section-9.7)
+ *
+ * The time ranges match every instance of the recurring components, see [`crate::time_range`].
+ *
+ * ```
+ * let vcalendar: ikal::VCalendar = "BEGIN:VCALENDAR\r
+ * VERSION:2.0\r
+ * PRODID:-//ikal//EN\r
+ * BEGIN:VEVENT\r
+ * UID:1\r
+ * DTSTAMP:20240101T000000Z\r
+ * DTSTART:20240101T100000Z\r
+ * DTEND:20240101T110000Z\r
+ * SUMMARY:Weekly meeting\r
+ * RRULE:FREQ=WEEKLY\r
+ * END:VEVENT\r
+ * END:VCALENDAR\r
+ * ".parse()?;
+ *
+ * let filter = ikal::filter::Filter::from_xml(r#"<C:filter xmlns:C="urn:ietf:params:xml:ns:caldav">
+ *   <C:comp-filter name="VCALENDAR">
+ *     <C:comp-filter name="VEVENT">
+ *       <C:time-range start="20240301T000000Z" end="20240308T000000Z"/>
+ *       <C:prop-filter name="SUMMARY">
+ *         <C:text-match>MEETING</C:text-match>
+ *       </C:prop-filter>
+ *     </C:comp-filter>
+ *   </C:comp-filter>
+ * </C:filter>"#)?;
+ *
+ * assert!(filter.matches(&vcalendar, None));
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+
+use crate::time_range::TimeRange;
+use crate::xml::Element;
+
+/**
+ * See [9.7. CALDAV:filter XML Element](https://datatracker.ietf.org/doc/html/rfc4791#section-9.7)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Filter {
+    pub comp_filter: CompFilter,
+}
+
+impl Filter {
+    #[must_use]
+    pub fn new(comp_filter: CompFilter) -> Self {
+        Self { comp_filter }
+    }
+
+    /**
+     * Parses a `filter` element, or the one of a `calendar-query` report.
+     */
+    pub fn from_xml(s: &str) -> crate::Result<Self> {
+        let root = Element::parse(s).map_err(crate::Error::Filter)?;
+
+        Self::from_element(&root)
+    }
+
+    pub(crate) fn from_element(element: &Element) -> crate::Result<Self> {
+        let filter = match element.name.as_str() {
+            "filter" => element,
+            _ => element
+                .child("filter")
+                .ok_or_else(|| error(format!("expected filter, found {}", element.name)))?,
+        };
+
+        let [comp_filter] = filter.children.as_slice() else {
+            return Err(error("expected a single comp-filter"));
+        };
+
+        Ok(Self::new(CompFilter::from_element(comp_filter)?))
+    }
+
+    /**
+     * Whether the calendar object matches the filter, the dates and floating date-times are in
+     * `timezone`, the `calendar-timezone` of the collection, or in UTC without one.
+     */
+    #[must_use]
+    pub fn matches(
+        &self,
+        vcalendar: &crate::VCalendar,
+        timezone: Option<&crate::VTimezone>,
+    ) -> bool {
+        let evaluator = Evaluator {
+            vcalendar,
+            timezone,
+        };
+
+        evaluator.comp_filter(&self.comp_filter, &[Component::VCalendar(vcalendar)], None)
+    }
+}
+
+/**
+ * See [9.7.1. CALDAV:comp-filter XML Element](https://datatracker.ietf.org/doc/html/rfc4791#section-9.7.1)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CompFilter {
+    pub name: String,
+    pub is_not_defined: bool,
+    pub time_range: Option<TimeRange>,
+    pub prop_filters: Vec<PropFilter>,
+    pub comp_filters: Vec<CompFilter>,
+}
+
+impl CompFilter {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn from_element(element: &Element) -> crate::Result<Self> {
+        let mut comp_filter = Self::new(name(element, "comp-filter")?);
+
+        for child in &element.children {
+            match child.name.as_str() {
+                "is-not-defined" => comp_filter.is_not_defined = true,
+                "time-range" => comp_filter.time_range = Some(time_range(child)?),
+                "prop-filter" => comp_filter
+                    .prop_filters
+                    .push(PropFilter::from_element(child)?),
+                "comp-filter" => comp_filter
+                    .comp_filters
+                    .push(CompFilter::from_element(child)?),
+                name => return Err(error(format!("unexpected {name} in comp-filter"))),
+            }
+        }
+
+        Ok(comp_filter)
+    }
+}
+
+/**
+ * See [9.7.2. CALDAV:prop-filter XML Element](https://datatracker.ietf.org/doc/html/rfc4791#section-9.7.2)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PropFilter {
+    pub name: String,
+    pub is_not_defined: bool,
+    pub time_range: Option<TimeRange>,
+    pub text_match: Option<TextMatch>,
+    pub param_filters: Vec<ParamFilter>,
+}
+
+impl PropFilter {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn from_element(element: &Element) -> crate::Result<Self> {
+        let mut prop_filter = Self::new(name(element, "prop-filter")?);
+
+        for child in &element.children {
+            match child.name.as_str() {
+                "is-not-defined" => prop_filter.is_not_defined = true,
+                "time-range" => prop_filter.time_range = Some(time_range(child)?),
+                "text-match" => prop_filter.text_match = Some(TextMatch::from_element(child)?),
+                "param-filter" => prop_filter
+                    .param_filters
+                    .push(ParamFilter::from_element(child)?),
+                name => return Err(error(format!("unexpected {name} in prop-filter"))),
+            }
+        }
+
+        Ok(prop_filter)
+    }
+}
+
+/**
+ * See [9.7.3. CALDAV:param-filter XML Element](https://datatracker.ietf.org/doc/html/rfc4791#section-9.7.3)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ParamFilter {
+    pub name: String,
+    pub is_not_defined: bool,
+    pub text_match: Option<TextMatch>,
+}
+
+impl ParamFilter {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn from_element(element: &Element) -> crate::Result<Self> {
+        let mut param_filter = Self::new(name(element, "param-filter")?);
+
+        for child in &element.children {
+            match child.name.as_str() {
+                "is-not-defined" => param_filter.is_not_defined = true,
+                "text-match" => param_filter.text_match = Some(TextMatch::from_element(child)?),
+                name => return Err(error(format!("unexpected {name} in param-filter"))),
+            }
+        }
+
+        Ok(param_filter)
+    }
+}
+
+/**
+ * See [9.7.5. CALDAV:text-match XML Element](https://datatracker.ietf.org/doc/html/rfc4791#section-9.7.5)
+ *
+ * A substring match.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TextMatch {
+    pub text: String,
+    pub collation: Collation,
+    pub negate: bool,
+}
+
+impl TextMatch {
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn matches(&self, value: &str) -> bool {
+        let found = match self.collation {
+            Collation::AsciiCasemap => value
+                .to_ascii_lowercase()
+                .contains(&self.text.to_ascii_lowercase()),
+            Collation::Octet => value.contains(&self.text),
+            Collation::UnicodeCasemap => value.to_lowercase().contains(&self.text.to_lowercase()),
+        };
+
+        found != self.negate
+    }
+
+    fn from_element(element: &Element) -> crate::Result<Self> {
+        let collation = element
+            .attribute("collation")
+            .map(str::parse)
+            .transpose()?
+            .unwrap_or_default();
+
+        let negate = match element.attribute("negate-condition") {
+            None | Some("no") => false,
+            Some("yes") => true,
+            Some(value) => return Err(error(format!("invalid negate-condition {value}"))),
+        };
+
+        Ok(Self {
+            text: element.text.clone(),
+            collation,
+            negate,
+        })
+    }
+}
+
+/**
+ * See [7.5. Searching Text: Collations](https://datatracker.ietf.org/doc/html/rfc4791#section-7.5)
+ */
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Collation {
+    #[default]
+    AsciiCasemap,
+    Octet,
+    UnicodeCasemap,
+}
+
+impl std::str::FromStr for Collation {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let collation = match s {
+            "i;ascii-casemap" => Self::AsciiCasemap,
+            "i;octet" => Self::Octet,
+            "i;unicode-casemap" => Self::UnicodeCasemap,
+            _ => return Err(error(format!("unsupported collation {s}"))),
+        };
+
+        Ok(collation)
+    }
+}
+
+impl std::fmt::Display for Collation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::AsciiCasemap => "i;ascii-casemap",
+            Self::Octet => "i;octet",
+            Self::UnicodeCasemap => "i;unicode-casemap",
+        };
+
+        f.write_str(s)
+    }
+}
+
+fn name<'a>(element: &'a Element, filter: &str) -> crate::Result<&'a str> {
+    element
+        .attribute("name")
+        .ok_or_else(|| error(format!("missing name of {filter}")))
+}
+
+/**
+ * See [9.9. CALDAV:time-range XML Element](https://datatracker.ietf.org/doc/html/rfc4791#section-9.9)
+ */
+fn time_range(element: &Element) -> crate::Result<TimeRange> {
+    let bound = |name| {
+        element
+            .attribute(name)
+            .map(|value| {
+                value
+                    .parse::<crate::DateTime>()
+                    .map_err(|_| error(format!("invalid {name} {value}")))
+            })
+            .transpose()
+    };
+
+    let time_range = TimeRange {
+        start: bound("start")?,
+        end: bound("end")?,
+    };
+
+    if time_range.start.is_none() && time_range.end.is_none() {
+        return Err(error("time-range without start nor end"));
+    }
+
+    Ok(time_range)
+}
+
+fn error(message: impl ToString) -> crate::Error {
+    crate::Error::Filter(message.to_string())
+}
+
+/**
+ * A component of the typed calendar, walked by the filters.
+ */
+#[derive(Clone, Copy)]
+enum Component<'a> {
+    VCalendar(&'a crate::VCalendar),
+    VAlarm(&'a crate::VAlarm),
+    VEvent(&'a crate::VEvent),
+    VFreebusy(&'a crate::VFreebusy),
+    VJournal(&'a crate::VJournal),
+    VTodo(&'a crate::VTodo),
+    VTimezone(&'a crate::VTimezone),
+    Standard(&'a crate::components::vtimezone::Standard),
+    Daylight(&'a crate::components::vtimezone::Daylight),
+}
+
+impl<'a> Component<'a> {
+    fn name(self) -> &'static str {
+        match self {
+            Self::VCalendar(_) => "VCALENDAR",
+            Self::VAlarm(_) => "VALARM",
+            Self::VEvent(_) => "VEVENT",
+            Self::VFreebusy(_) => "VFREEBUSY",
+            Self::VJournal(_) => "VJOURNAL",
+            Self::VTodo(_) => "VTODO",
+            Self::VTimezone(_) => "VTIMEZONE",
+            Self::Standard(_) => "STANDARD",
+            Self::Daylight(_) => "DAYLIGHT",
+        }
+    }
+
+    fn properties(self) -> Vec<crate::ContentLine> {
+        match self {
+            Self::VCalendar(vcalendar) => vcalendar.properties(),
+            Self::VAlarm(crate::VAlarm::Audio(audio)) => audio.properties(),
+            Self::VAlarm(crate::VAlarm::Display(display)) => display.properties(),
+            Self::VAlarm(crate::VAlarm::Email(email)) => email.properties(),
+            Self::VAlarm(crate::VAlarm::NoAction(none)) => none.properties(),
+            Self::VAlarm(crate::VAlarm::Custom(custom)) => custom.properties(),
+            Self::VEvent(vevent) => vevent.properties(),
+            Self::VFreebusy(vfreebusy) => vfreebusy.properties(),
+            Self::VJournal(vjournal) => vjournal.properties(),
+            Self::VTodo(vtodo) => vtodo.properties(),
+            Self::VTimezone(vtimezone) => vtimezone.properties(),
+            Self::Standard(standard) => standard.properties(),
+            Self::Daylight(daylight) => daylight.properties(),
+        }
+    }
+
+    fn components(self) -> Vec<Self> {
+        match self {
+            Self::VCalendar(vcalendar) => vcalendar
+                .alarms
+                .iter()
+                .map(Self::VAlarm)
+                .chain(vcalendar.events.iter().map(Self::VEvent))
+                .chain(vcalendar.freebusy.iter().map(Self::VFreebusy))
+                .chain(vcalendar.journals.iter().map(Self::VJournal))
+                .chain(vcalendar.todo.iter().map(Self::VTodo))
+                .chain(vcalendar.timezones.iter().map(Self::VTimezone))
+                .collect(),
+            Self::VEvent(vevent) => vevent.alarms.iter().map(Self::VAlarm).collect(),
+            Self::VTodo(vtodo) => vtodo.alarms.iter().map(Self::VAlarm).collect(),
+            Self::VTimezone(vtimezone) => vtimezone
+                .standard
+                .iter()
+                .map(Self::Standard)
+                .chain(vtimezone.daylight.iter().map(Self::Daylight))
+                .collect(),
+            Self::VAlarm(_)
+            | Self::VFreebusy(_)
+            | Self::VJournal(_)
+            | Self::Standard(_)
+            | Self::Daylight(_) => Vec::new(),
+        }
+    }
+}
+
+/**
+ * Evaluates the filters on the components of a calendar, the dates and floating date-times are
+ * in `timezone`.
+ */
+struct Evaluator<'a> {
+    vcalendar: &'a crate::VCalendar,
+    timezone: Option<&'a crate::VTimezone>,
+}
+
+impl<'a> Evaluator<'a> {
+    fn comp_filter(
+        &self,
+        filter: &CompFilter,
+        components: &[Component<'a>],
+        parent: Option<Component<'a>>,
+    ) -> bool {
+        let mut components = components
+            .iter()
+            .filter(|x| x.name().eq_ignore_ascii_case(&filter.name));
+
+        if filter.is_not_defined {
+            return components.next().is_none();
+        }
+
+        components.any(|&component| {
+            filter
+                .time_range
+                .as_ref()
+                .is_none_or(|range| self.time_range(range, component, parent))
+                && (filter.prop_filters.is_empty() || {
+                    let properties = component.properties();
+
+                    filter
+                        .prop_filters
+                        .iter()
+                        .all(|x| self.prop_filter(x, &properties))
+                })
+                && (filter.comp_filters.is_empty() || {
+                    let components = component.components();
+
+                    filter
+                        .comp_filters
+                        .iter()
+                        .all(|x| self.comp_filter(x, &components, Some(component)))
+                })
+        })
+    }
+
+    fn prop_filter(&self, filter: &PropFilter, properties: &[crate::ContentLine]) -> bool {
+        let mut properties = properties
+            .iter()
+            .filter(|x| x.key.eq_ignore_ascii_case(&filter.name));
+
+        if filter.is_not_defined {
+            return properties.next().is_none();
+        }
+
+        properties.any(|property| {
+            filter
+                .time_range
+                .as_ref()
+                .is_none_or(|range| self.property_time_range(range, property))
+                && filter
+                    .text_match
+                    .as_ref()
+                    .is_none_or(|x| x.matches(&crate::ser::unescape(&property.value)))
+                && filter
+                    .param_filters
+                    .iter()
+                    .all(|x| param_filter(x, property))
+        })
+    }
+
+    fn overlap(&self, range: &TimeRange) -> crate::time_range::Overlap<'a> {
+        crate::time_range::Overlap::floating(range, &self.vcalendar.timezones, self.timezone)
+    }
+
+    fn time_range(
+        &self,
+        range: &TimeRange,
+        component: Component<'a>,
+        parent: Option<Component<'a>>,
+    ) -> bool {
+        let overlap = self.overlap(range);
+
+        match component {
+            Component::VEvent(vevent) => {
+                overlap.vevent(vevent, &overridden(&self.vcalendar.events, vevent))
+            }
+            Component::VFreebusy(vfreebusy) => overlap.vfreebusy(vfreebusy),
+            Component::VJournal(vjournal) => {
+                overlap.vjournal(vjournal, &overridden(&self.vcalendar.journals, vjournal))
+            }
+            Component::VTodo(vtodo) => {
+                overlap.vtodo(vtodo, &overridden(&self.vcalendar.todo, vtodo))
+            }
+            Component::VAlarm(valarm) => match parent {
+                Some(Component::VEvent(vevent)) => overlap.valarm_of(vevent, valarm),
+                Some(Component::VTodo(vtodo)) => overlap.valarm_of(vtodo, valarm),
+                _ => overlap.valarm(valarm),
+            },
+            Component::VCalendar(_)
+            | Component::VTimezone(_)
+            | Component::Standard(_)
+            | Component::Daylight(_) => false,
+        }
+    }
+
+    /**
+     * Whether one of the date or date-time values is in the range.
+     */
+    fn property_time_range(&self, range: &TimeRange, property: &crate::ContentLine) -> bool {
+        let overlap = self.overlap(range);
+        let tzid = property.params.get("TZID");
+
+        property.value.split(',').any(|value| {
+            let Ok(date) = value.parse::<crate::Date>() else {
+                return false;
+            };
+
+            let date = match (date, tzid) {
+                (crate::Date::DateTime(date_time), Some(tzid)) => {
+                    crate::Date::DateTime(date_time.with_tzid(tzid))
+                }
+                (date, _) => date,
+            };
+
+            overlap.date(&date)
+        })
+    }
+}
+
+fn param_filter(filter: &ParamFilter, property: &crate::ContentLine) -> bool {
+    let mut params = property
+        .params
+        .iter()
+        .filter(|(k, _)| k.eq_ignore_ascii_case(&filter.name));
+
+    if filter.is_not_defined {
+        return params.next().is_none();
+    }
+
+    params.any(|(_, value)| filter.text_match.as_ref().is_none_or(|x| x.matches(value)))
+}
+
+/**
+ * Instances of `master` overridden by other components of the calendar.
+ */
+fn overridden<T: crate::iter::Override>(components: &[T], master: &T) -> Vec<crate::Date> {
+    if master.recurid().is_some() {
+        return Vec::new();
+    }
+
+    components
+        .iter()
+        .filter(|x| x.uid() == master.uid())
+        .filter_map(|x| x.recurid().cloned())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//EN\r
+BEGIN:VTIMEZONE\r
+TZID:Europe/Paris\r
+BEGIN:STANDARD\r
+DTSTART:19701025T030000\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:19700329T020000\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20210517T051025Z\r
+DTSTART;TZID=Europe/Paris:20210301T100000\r
+DTEND;TZID=Europe/Paris:20210301T110000\r
+RRULE:FREQ=WEEKLY;COUNT=4\r
+SUMMARY:Réunion d’équipe\r
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:alice@example.com\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+DESCRIPTION:Reminder\r
+TRIGGER:-PT15M\r
+END:VALARM\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    fn filter(xml: &str) -> super::Filter {
+        super::Filter::from_xml(&format!(
+            r#"<C:filter xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <C:comp-filter name="VCALENDAR">{xml}</C:comp-filter>
+</C:filter>"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn text_match() {
+        let mut text_match = super::TextMatch::new("RÉUNION");
+        assert!(!text_match.matches("Réunion"));
+
+        text_match.collation = super::Collation::UnicodeCasemap;
+        assert!(text_match.matches("Réunion"));
+
+        text_match.collation = super::Collation::Octet;
+        assert!(!text_match.matches("réunions"));
+        assert!(text_match.matches("RÉUNIONS"));
+
+        text_match.negate = true;
+        assert!(!text_match.matches("RÉUNIONS"));
+    }
+
+    #[test]
+    fn comp_filter() -> crate::Result {
+        let vcalendar: crate::VCalendar = CALENDAR.parse()?;
+
+        assert!(filter(r#"<C:comp-filter name="VEVENT"/>"#).matches(&vcalendar, None));
+        assert!(!filter(r#"<C:comp-filter name="VTODO"/>"#).matches(&vcalendar, None));
+        assert!(
+            filter(r#"<C:comp-filter name="VTODO"><C:is-not-defined/></C:comp-filter>"#)
+                .matches(&vcalendar, None)
+        );
+        assert!(
+            !super::Filter::new(super::CompFilter::new("VEVENT")).matches(&vcalendar, None),
+            "the top-level filter applies to VCALENDAR"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn prop_filter() -> crate::Result {
+        let vcalendar: crate::VCalendar = CALENDAR.parse()?;
+
+        let summary = |text_match: &str| {
+            filter(&format!(
+                r#"<C:comp-filter name="VEVENT">
+  <C:prop-filter name="SUMMARY">{text_match}</C:prop-filter>
+</C:comp-filter>"#
+            ))
+        };
+
+        assert!(summary("<C:text-match>réunion</C:text-match>").matches(&vcalendar, None));
+        assert!(
+            !summary(r#"<C:text-match negate-condition="yes">équipe</C:text-match>"#)
+                .matches(&vcalendar, None)
+        );
+        assert!(
+            summary(r#"<C:text-match collation="i;unicode-casemap">RÉUNION</C:text-match>"#)
+                .matches(&vcalendar, None)
+        );
+
+        let partstat = |partstat: &str| {
+            filter(&format!(
+                r#"<C:comp-filter name="VEVENT">
+  <C:prop-filter name="ATTENDEE">
+    <C:text-match>alice</C:text-match>
+    <C:param-filter name="PARTSTAT"><C:text-match>{partstat}</C:text-match></C:param-filter>
+  </C:prop-filter>
+</C:comp-filter>"#
+            ))
+        };
+
+        assert!(partstat("accepted").matches(&vcalendar, None));
+        assert!(!partstat("declined").matches(&vcalendar, None));
+
+        let dtstart = |start: &str, end: &str| {
+            filter(&format!(
+                r#"<C:comp-filter name="VEVENT">
+  <C:prop-filter name="DTSTART"><C:time-range start="{start}" end="{end}"/></C:prop-filter>
+</C:comp-filter>"#
+            ))
+        };
+
+        assert!(dtstart("20210301T090000Z", "20210301T090001Z").matches(&vcalendar, None));
+        assert!(!dtstart("20210301T100000Z", "20210301T110000Z").matches(&vcalendar, None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_range() -> crate::Result {
+        let mut vcalendar: crate::VCalendar = CALENDAR.parse()?;
+
+        let event = |start: &str, end: &str| {
+            filter(&format!(
+                r#"<C:comp-filter name="VEVENT"><C:time-range start="{start}" end="{end}"/></C:comp-filter>"#
+            ))
+        };
+
+        assert!(event("20210315T093000Z", "20210315T094500Z").matches(&vcalendar, None));
+        assert!(!event("20210329T093000Z", "20210329T094500Z").matches(&vcalendar, None));
+        assert!(!event("20210401T000000Z", "20210501T000000Z").matches(&vcalendar, None));
+
+        let mut moved = vcalendar.events[0].clone();
+        moved.rrule = None;
+        moved.recurid = Some(moved.dtstart.clone());
+        moved.dtstart = "20210302T100000Z".parse()?;
+        moved.dtend = Some("20210302T110000Z".parse()?);
+        vcalendar.events.push(moved);
+
+        assert!(!event("20210301T000000Z", "20210302T000000Z").matches(&vcalendar, None));
+        assert!(event("20210302T000000Z", "20210303T000000Z").matches(&vcalendar, None));
+
+        let alarm = |start: &str, end: &str| {
+            filter(&format!(
+                r#"<C:comp-filter name="VEVENT">
+  <C:comp-filter name="VALARM"><C:time-range start="{start}" end="{end}"/></C:comp-filter>
+</C:comp-filter>"#
+            ))
+        };
+
+        assert!(alarm("20210308T084500Z", "20210308T085000Z").matches(&vcalendar, None));
+        assert!(!alarm("20210308T090000Z", "20210308T100000Z").matches(&vcalendar, None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn floating() -> crate::Result {
+        let mut vcalendar: crate::VCalendar = CALENDAR.parse()?;
+        let paris = vcalendar.timezones[0].clone();
+        vcalendar.timezones.clear();
+
+        let vevent = &mut vcalendar.events[0];
+        vevent.rrule = None;
+        vevent.dtstart = "20210301T100000".parse()?;
+        vevent.dtend = Some("20210301T110000".parse()?);
+
+        let filter = filter(
+            r#"<C:comp-filter name="VEVENT"><C:time-range start="20210301T093000Z" end="20210301T094500Z"/></C:comp-filter>"#,
+        );
+
+        assert!(!filter.matches(&vcalendar, None));
+        assert!(filter.matches(&vcalendar, Some(&paris)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_xml() {
+        let filter = super::Filter::from_xml(
+            r#"<?xml version="1.0" encoding="utf-8" ?>
+<C:calendar-query xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <D:prop>
+    <D:getetag/>
+  </D:prop>
+  <C:filter>
+    <C:comp-filter name="VCALENDAR">
+      <C:comp-filter name="VTODO">
+        <C:prop-filter name="COMPLETED">
+          <C:is-not-defined/>
+        </C:prop-filter>
+        <C:prop-filter name="STATUS">
+          <C:text-match negate-condition="yes">CANCELLED</C:text-match>
+        </C:prop-filter>
+      </C:comp-filter>
+    </C:comp-filter>
+  </C:filter>
+</C:calendar-query>"#,
+        );
+
+        let mut completed = super::PropFilter::new("COMPLETED");
+        completed.is_not_defined = true;
+
+        let mut status = super::PropFilter::new("STATUS");
+        status.text_match = Some(super::TextMatch {
+            negate: true,
+            ..super::TextMatch::new("CANCELLED")
+        });
+
+        let mut vtodo = super::CompFilter::new("VTODO");
+        vtodo.prop_filters = vec![completed, status];
+
+        let mut vcalendar = super::CompFilter::new("VCALENDAR");
+        vcalendar.comp_filters = vec![vtodo];
+
+        assert_eq!(filter, Ok(super::Filter::new(vcalendar)));
+    }
+
+    #[test]
+    fn invalid() {
+        let invalid =
+            |xml: &str| matches!(super::Filter::from_xml(xml), Err(crate::Error::Filter(_)));
+
+        assert!(invalid("<filter/>"));
+        assert!(invalid(r#"<filter><comp-filter/></filter>"#));
+        assert!(invalid(
+            r#"<filter><comp-filter name="VCALENDAR"><time-range/></comp-filter></filter>"#
+        ));
+        assert!(invalid(
+            r#"<filter><comp-filter name="VCALENDAR"><prop-filter name="UID"><text-match collation="i;klingon">a</text-match></prop-filter></comp-filter></filter>"#
+        ));
+    }
+}
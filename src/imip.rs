@@ -0,0 +1,299 @@
+/*!
+ * See [RFC 6047](https://datatracker.ietf.org/doc/html/rfc6047)
+ */
+
+/**
+ * Extracts the calendars of the `text/calendar` parts of an email.
+ *
+ * The `method` parameter of the part is used when the calendar has no METHOD, and both must
+ * agree otherwise.
+ */
+pub fn calendars(eml: &[u8]) -> crate::Result<Vec<crate::VCalendar>> {
+    let message = mail_parser::MessageParser::default()
+        .parse(eml)
+        .ok_or_else(|| crate::Error::Imip("Invalid email".to_string()))?;
+
+    let mut calendars = Vec::new();
+    extract(&message, &mut calendars)?;
+
+    Ok(calendars)
+}
+
+fn extract(message: &mail_parser::Message, calendars: &mut Vec<crate::VCalendar>) -> crate::Result {
+    use mail_parser::MimeHeaders as _;
+
+    for part in &message.parts {
+        if let Some(nested) = part.message() {
+            extract(nested, calendars)?;
+            continue;
+        }
+
+        if !part.is_content_type("text", "calendar") {
+            continue;
+        }
+
+        let content = part
+            .text_contents()
+            .ok_or_else(|| crate::Error::Imip("Invalid text/calendar encoding".to_string()))?;
+        let mut calendar = crate::VCalendar::try_from(content)?;
+
+        let method = part
+            .content_type()
+            .and_then(|x| x.attribute("method"))
+            .map(str::parse::<crate::Method>)
+            .transpose()?;
+
+        match (&calendar.method, method) {
+            (None, method) => calendar.method = method,
+            (Some(a), Some(b)) if *a != b => {
+                return Err(crate::Error::Imip(format!(
+                    "METHOD {a} doesn't match the content type method {b}"
+                )));
+            }
+            _ => (),
+        }
+
+        calendars.push(calendar);
+    }
+
+    Ok(())
+}
+
+/**
+ * Builds a `multipart/alternative` email carrying an iTIP message.
+ *
+ * Without explicit addresses, the organizer sends requests to the attendees and the attendee
+ * replies to the organizer.
+ *
+ * ```
+ * let event = ikal::VEvent {
+ *     uid: "123456789@example.com".into(),
+ *     dtstamp: "19970901T130000Z".parse()?,
+ *     dtstart: "19970903T163000Z".parse()?,
+ *     summary: Some("Annual Employee Review".into()),
+ *     organizer: Some("mailto:a@example.com".into()),
+ *     attendee: vec!["mailto:b@example.com".into()],
+ *
+ *     ..Default::default()
+ * };
+ * let calendar = ikal::itip::Builder::new(&event)
+ *     .dtstamp("19970902T090000".parse()?)
+ *     .request()?;
+ *
+ * let eml = ikal::imip::Builder::new(&calendar).build()?;
+ * let calendars = ikal::imip::calendars(eml.as_bytes())?;
+ * assert_eq!(calendars[0].method, Some(ikal::Method::Request));
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+pub struct Builder<'a> {
+    calendar: &'a crate::VCalendar,
+    from: Option<String>,
+    to: Vec<String>,
+    subject: Option<String>,
+    text: Option<String>,
+}
+
+impl<'a> Builder<'a> {
+    #[must_use]
+    pub fn new(calendar: &'a crate::VCalendar) -> Self {
+        Self {
+            calendar,
+            from: None,
+            to: Vec::new(),
+            subject: None,
+            text: None,
+        }
+    }
+
+    #[must_use]
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+
+    #[must_use]
+    pub fn to(mut self, to: impl Into<String>) -> Self {
+        self.to.push(to.into());
+        self
+    }
+
+    /**
+     * Defaults to the summary of the component.
+     */
+    #[must_use]
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    /**
+     * Plain text alternative, defaults to the description of the component.
+     */
+    #[must_use]
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn build(self) -> crate::Result<String> {
+        let method = self
+            .calendar
+            .method
+            .as_ref()
+            .ok_or_else(|| crate::Error::Imip("missing METHOD".to_string()))?;
+
+        let component = Component::from(self.calendar)
+            .ok_or_else(|| crate::Error::Imip("no event or to-do".to_string()))?;
+
+        let (sender, recipients) = match method {
+            crate::Method::Reply | crate::Method::Refresh | crate::Method::Counter => (
+                component.attendee.first().copied(),
+                component.organizer.into_iter().collect(),
+            ),
+            _ => (component.organizer, component.attendee.clone()),
+        };
+
+        let from = self
+            .from
+            .or_else(|| sender.map(address))
+            .ok_or_else(|| crate::Error::Imip("missing sender".to_string()))?;
+
+        let to = if self.to.is_empty() {
+            recipients.into_iter().map(address).collect()
+        } else {
+            self.to
+        };
+
+        if to.is_empty() {
+            return Err(crate::Error::Imip("missing recipient".to_string()));
+        }
+
+        let subject = self
+            .subject
+            .or_else(|| component.summary.map(|x| x.text.clone()))
+            .unwrap_or_else(|| method.to_string());
+
+        let text = self
+            .text
+            .or_else(|| component.description.map(|x| x.text.clone()))
+            .unwrap_or_else(|| subject.clone());
+
+        let calendar_type = mail_builder::headers::content_type::ContentType::new("text/calendar")
+            .attribute("method", method.to_string())
+            .attribute("charset", "utf-8");
+
+        let body = mail_builder::mime::MimePart::new(
+            "multipart/alternative",
+            vec![
+                mail_builder::mime::MimePart::new("text/plain", text),
+                mail_builder::mime::MimePart::new(calendar_type, crate::ser::ical(self.calendar)),
+            ],
+        );
+
+        mail_builder::MessageBuilder::new()
+            .from(from)
+            .to(to)
+            .subject(subject)
+            .body(body)
+            .write_to_string()
+            .map_err(|err| crate::Error::Imip(err.to_string()))
+    }
+}
+
+struct Component<'a> {
+    organizer: Option<&'a crate::Uri>,
+    attendee: Vec<&'a crate::Uri>,
+    summary: Option<&'a crate::Text>,
+    description: Option<&'a crate::Text>,
+}
+
+impl<'a> Component<'a> {
+    fn from(calendar: &'a crate::VCalendar) -> Option<Self> {
+        if let Some(event) = calendar.events.first() {
+            Some(Self {
+                organizer: event.organizer.as_ref(),
+                attendee: event.attendee.iter().collect(),
+                summary: event.summary.as_ref(),
+                description: event.description.as_ref(),
+            })
+        } else {
+            calendar.todo.first().map(|todo| Self {
+                organizer: todo.organizer.as_ref(),
+                attendee: todo.attendee.iter().collect(),
+                summary: todo.summary.as_ref(),
+                description: None,
+            })
+        }
+    }
+}
+
+fn address(uri: &crate::Uri) -> String {
+    let uri = uri.uri.as_str();
+
+    uri.get(..7)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+        .map_or(uri, |_| &uri[7..])
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn calendars() -> crate::Result {
+        let eml = std::fs::read("tests/imip/1.eml").unwrap();
+        let calendars = crate::imip::calendars(&eml)?;
+
+        assert_eq!(calendars.len(), 1);
+        assert_eq!(calendars[0].method, Some(crate::Method::Request));
+        assert_eq!(
+            calendars[0].events[0].summary,
+            Some("Réunion d’équipe".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn method_mismatch() {
+        let eml = std::fs::read_to_string("tests/imip/1.eml")
+            .unwrap()
+            .replace("method=REQUEST", "method=CANCEL");
+
+        assert!(matches!(
+            crate::imip::calendars(eml.as_bytes()),
+            Err(crate::Error::Imip(_))
+        ));
+    }
+
+    #[test]
+    fn build() -> crate::Result {
+        let event = crate::vevent! {
+            uid: "123456789@example.com",
+            dtstamp: "19970901T130000",
+            dtstart: "19970903T163000",
+            summary: "Annual Employee Review",
+            organizer: "mailto:a@example.com",
+            attendee: ["mailto:b@example.com"],
+        }?;
+
+        let mut attendee = crate::Uri::from("mailto:b@example.com");
+        attendee
+            .params
+            .insert("PARTSTAT".to_string(), "ACCEPTED".to_string());
+        let reply = crate::itip::Builder::new(&event)
+            .dtstamp("19970902T090000".parse()?)
+            .reply(&attendee)?;
+
+        let eml = crate::imip::Builder::new(&reply).build()?;
+
+        assert!(eml.contains("From: <b@example.com>\r\n"));
+        assert!(eml.contains("To: <a@example.com>\r\n"));
+        assert!(eml.contains("Subject: Annual Employee Review\r\n"));
+        assert!(eml.contains("multipart/alternative"));
+        assert!(eml.contains("text/calendar; method=\"REPLY\"; charset=\"utf-8\""));
+        assert_eq!(crate::imip::calendars(eml.as_bytes())?, vec![reply]);
+
+        Ok(())
+    }
+}
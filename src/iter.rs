@@ -105,6 +105,52 @@ impl Recurring for crate::VTodo {
     }
 }
 
+/**
+ * Components with a recurrence identifier.
+ */
+pub(crate) trait Override: Recurring {
+    fn uid(&self) -> &str;
+    fn recurid(&self) -> Option<&crate::Date>;
+    fn rdate(&self) -> &[crate::RDate];
+    fn rdate_mut(&mut self) -> &mut Vec<crate::RDate>;
+    fn exdate_mut(&mut self) -> &mut Vec<crate::Date>;
+    fn rrule_mut(&mut self) -> Option<&mut crate::Recur>;
+}
+
+macro_rules! impl_override {
+    ($($ty:ty),+) => {
+        $(
+            impl Override for $ty {
+                fn uid(&self) -> &str {
+                    &self.uid
+                }
+
+                fn recurid(&self) -> Option<&crate::Date> {
+                    self.recurid.as_ref()
+                }
+
+                fn rdate(&self) -> &[crate::RDate] {
+                    &self.rdate
+                }
+
+                fn rdate_mut(&mut self) -> &mut Vec<crate::RDate> {
+                    &mut self.rdate
+                }
+
+                fn exdate_mut(&mut self) -> &mut Vec<crate::Date> {
+                    &mut self.exdate
+                }
+
+                fn rrule_mut(&mut self) -> Option<&mut crate::Recur> {
+                    self.rrule.as_mut()
+                }
+            }
+        )+
+    };
+}
+
+impl_override!(crate::VEvent, crate::VJournal, crate::VTodo);
+
 pub struct Recur<T: Recurring> {
     item: T,
 }
@@ -114,18 +160,24 @@ impl<T: Recurring> Recur<T> {
         Self { item: item.clone() }
     }
 
-    pub fn between<D: Into<crate::Date> + Copy>(self, start: D, end: D) -> impl Iterator<Item = T> {
-        self.skip_while(move |x| x.dtstart().unwrap() < &start.into())
-            .take_while(move |x| x.dtstart().unwrap() < &end.into())
+    pub fn between<D: Into<crate::Date>>(self, start: D, end: D) -> impl Iterator<Item = T> {
+        let start = start.into();
+        let end = end.into();
+
+        self.skip_while(move |x| x.dtstart().unwrap() < &start)
+            .take_while(move |x| x.dtstart().unwrap() < &end)
     }
 
-    pub fn at<D: Into<crate::Date> + Copy>(self, date: D) -> impl Iterator<Item = T> {
+    pub fn at<D: Into<crate::Date>>(self, date: D) -> impl Iterator<Item = T> {
+        let date = date.into();
         let delta = chrono::TimeDelta::days(1);
-        self.between(date.into(), date.into() + delta)
+        self.between(date.clone(), date + delta)
     }
 
-    pub fn after<D: Into<crate::Date> + Copy>(self, date: D) -> impl Iterator<Item = T> {
-        self.skip_while(move |x| x.dtstart().unwrap() < &date.into())
+    pub fn after<D: Into<crate::Date>>(self, date: D) -> impl Iterator<Item = T> {
+        let date = date.into();
+
+        self.skip_while(move |x| x.dtstart().unwrap() < &date)
     }
 }
 
@@ -147,14 +199,19 @@ impl<T: Recurring> Iterator for Recur<T> {
                 return None;
             }
 
-            let dtstart = rrule.clone() + *dtstart;
-            next.set_dtstart(dtstart);
+            let dtstart = rrule.clone() + dtstart.clone();
+            next.set_dtstart(dtstart.clone());
 
             if let Some(dtend) = next.dtend() {
-                let dtend = rrule.clone() + *dtend;
+                let dtend = rrule.clone() + dtend.clone();
                 next.set_dtend(dtend);
             }
 
+            if let Some(due) = next.due() {
+                let due = rrule.clone() + due.clone();
+                next.set_due(due);
+            }
+
             next.set_rrule(rrule.clone());
 
             if next.exdate().contains(&dtstart) {
@@ -175,11 +232,254 @@ impl<T: Recurring> Iterator for Recur<T> {
     }
 }
 
+pub trait Alarming: Recurring {
+    fn alarms(&self) -> &[crate::VAlarm];
+
+    /**
+     * Instant used by triggers related to the end of the component.
+     */
+    fn end(&self) -> Option<crate::DateTime>;
+}
+
+impl Alarming for crate::VEvent {
+    fn alarms(&self) -> &[crate::VAlarm] {
+        &self.alarms
+    }
+
+    fn end(&self) -> Option<crate::DateTime> {
+        if let Some(dtend) = self.dtend.clone() {
+            return Some(dtend.into());
+        }
+
+        let duration = self.duration.unwrap_or_else(|| {
+            if self.dtstart.has_time() {
+                crate::Duration::zero()
+            } else {
+                crate::Duration::days(1)
+            }
+        });
+
+        Some(crate::DateTime::from(self.dtstart.clone()) + duration)
+    }
+}
+
+impl Alarming for crate::VTodo {
+    fn alarms(&self) -> &[crate::VAlarm] {
+        &self.alarms
+    }
+
+    fn end(&self) -> Option<crate::DateTime> {
+        if let Some(due) = self.due.clone() {
+            return Some(due.into());
+        }
+
+        Some(crate::DateTime::from(self.dtstart.clone()?) + self.duration?)
+    }
+}
+
+/**
+ * An alarm firing for an occurrence of a component.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alarm<T> {
+    pub instant: crate::DateTime,
+    pub alarm: crate::VAlarm,
+    pub occurrence: T,
+}
+
+enum Occurrences<T: Recurring> {
+    Single(Option<T>),
+    Recur(Recur<T>),
+}
+
+impl<T: Recurring> Iterator for Occurrences<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Single(item) => item.take(),
+            Self::Recur(recur) => recur.next(),
+        }
+    }
+}
+
+/**
+ * Time-ordered iterator over the alarms of every occurrence of a component.
+ *
+ * Triggers relative to the start or the end are resolved for each occurrence and the `REPEAT`
+ * alarms are expanded. Absolute triggers only fire once, for the first occurrence.
+ */
+pub struct Alarms<'a, T: Alarming> {
+    occurrences: std::iter::Peekable<Occurrences<T>>,
+    pending: std::collections::VecDeque<Alarm<T>>,
+    lead: chrono::TimeDelta,
+    relative: bool,
+    first: bool,
+    timezones: &'a [crate::VTimezone],
+}
+
+impl<'a, T: Alarming> Alarms<'a, T> {
+    pub(crate) fn from(item: &T) -> Self {
+        let occurrences = if item.rrule().is_some() && item.dtstart().is_some() {
+            Occurrences::Recur(Recur::from(item))
+        } else {
+            Occurrences::Single(Some(item.clone()))
+        };
+
+        // Occurrences share the dates of the master: a relative trigger it can’t resolve, like the
+        // end of a todo without DUE nor DURATION, never fires and mustn’t be looked for forever
+        let relative = item.alarms().iter().any(|x| match x.trigger() {
+            crate::Trigger::Duration(_, crate::trigger::Related::Start) => item.dtstart().is_some(),
+            crate::Trigger::Duration(_, crate::trigger::Related::End) => item.end().is_some(),
+            crate::Trigger::DateTime(_) => false,
+        });
+
+        let lead = item
+            .alarms()
+            .iter()
+            .filter_map(|x| match x.trigger() {
+                crate::Trigger::Duration(offset, _) => Some(-chrono::TimeDelta::from(*offset)),
+                crate::Trigger::DateTime(_) => None,
+            })
+            .max()
+            .unwrap_or_default()
+            .max(chrono::TimeDelta::zero());
+
+        Self {
+            occurrences: occurrences.peekable(),
+            pending: std::collections::VecDeque::new(),
+            lead,
+            relative,
+            first: true,
+            timezones: &[],
+        }
+    }
+
+    /**
+     * Resolves the `TZID` of the instants with `timezones`, the alarms are ordered by their UTC
+     * time. Floating instants, and the ones in a time zone not found, are in the local time zone.
+     */
+    #[must_use]
+    pub fn with_timezones(mut self, timezones: &'a [crate::VTimezone]) -> Self {
+        self.timezones = timezones;
+        self
+    }
+
+    pub fn between<D: Into<crate::DateTime>>(
+        self,
+        start: D,
+        end: D,
+    ) -> impl Iterator<Item = Alarm<T>> {
+        let timezones = self.timezones;
+        let start = crate::convert::instant(&start.into(), timezones);
+        let end = crate::convert::instant(&end.into(), timezones);
+
+        self.skip_while(move |x| crate::convert::instant(&x.instant, timezones) < start)
+            .take_while(move |x| crate::convert::instant(&x.instant, timezones) < end)
+    }
+
+    pub fn after<D: Into<crate::DateTime>>(self, date: D) -> impl Iterator<Item = Alarm<T>> {
+        let timezones = self.timezones;
+        let date = crate::convert::instant(&date.into(), timezones);
+
+        self.skip_while(move |x| crate::convert::instant(&x.instant, timezones) < date)
+    }
+
+    fn utc(&self, date_time: &crate::DateTime) -> chrono::NaiveDateTime {
+        crate::convert::instant(date_time, self.timezones)
+    }
+
+    fn anchor(occurrence: &T) -> Option<crate::DateTime> {
+        occurrence
+            .dtstart()
+            .or(occurrence.due())
+            .map(|x| crate::DateTime::from(x.clone()))
+    }
+
+    fn expand(&mut self, occurrence: T) {
+        for alarm in occurrence.alarms() {
+            let instant = match alarm.trigger() {
+                crate::Trigger::DateTime(dt) if self.first => dt.clone(),
+                crate::Trigger::DateTime(_) => continue,
+                crate::Trigger::Duration(offset, crate::trigger::Related::Start) => {
+                    let Some(dtstart) = occurrence.dtstart() else {
+                        continue;
+                    };
+
+                    crate::DateTime::from(dtstart.clone()) + *offset
+                }
+                crate::Trigger::Duration(offset, crate::trigger::Related::End) => {
+                    let Some(end) = occurrence.end() else {
+                        continue;
+                    };
+
+                    end + *offset
+                }
+            };
+
+            let (repeat, duration) = match (alarm.repeat(), alarm.duration()) {
+                (Some(repeat), Some(duration)) => (repeat, duration),
+                _ => (0, crate::Duration::zero()),
+            };
+
+            for n in 0..=repeat {
+                let alarm = Alarm {
+                    instant: instant.clone() + duration * n,
+                    alarm: alarm.clone(),
+                    occurrence: occurrence.clone(),
+                };
+
+                let utc = self.utc(&alarm.instant);
+                let pos = self
+                    .pending
+                    .partition_point(|x| self.utc(&x.instant) <= utc);
+                self.pending.insert(pos, alarm);
+            }
+        }
+
+        self.first = false;
+    }
+}
+
+impl<T: Alarming> Iterator for Alarms<'_, T> {
+    type Item = Alarm<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let exhausted = !self.first && !self.relative;
+
+            let horizon = if exhausted {
+                None
+            } else {
+                self.occurrences
+                    .peek()
+                    .and_then(Self::anchor)
+                    .map(|x| x + -self.lead)
+            };
+
+            match (self.pending.front(), horizon) {
+                (Some(_), None) => return self.pending.pop_front(),
+                (Some(alarm), Some(horizon)) if self.utc(&alarm.instant) <= self.utc(&horizon) => {
+                    return self.pending.pop_front();
+                }
+                _ => (),
+            }
+
+            if exhausted {
+                return None;
+            }
+
+            let occurrence = self.occurrences.next()?;
+            self.expand(occurrence);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[test]
     fn at() {
-        let now = chrono::Local::now().date_naive().into();
+        let now: crate::Date = chrono::Local::now().date_naive().into();
 
         let event = crate::vevent! {
             dtstart: "20240101",
@@ -191,7 +491,7 @@ mod test {
         }
         .unwrap();
 
-        let next = event.recurrent().at(now).next().unwrap();
+        let next = event.recurrent().at(now.clone()).next().unwrap();
 
         assert_eq!(next.dtstart, now);
         assert_eq!(next.dtend, Some(now));
@@ -221,7 +521,7 @@ mod test {
             rrule: {
                 freq: Monthly,
                 interval: 1,
-                until: now,
+                until: now.clone(),
             }
         }
         .unwrap();
@@ -299,4 +599,216 @@ mod test {
 
         assert_eq!(iter.count(), 2);
     }
+
+    #[test]
+    fn alarms() -> crate::Result {
+        let mut event = crate::vevent! {
+            dtstart: "20240101T090000",
+            dtend: "20240101T100000",
+            rrule: {
+                freq: Daily,
+                count: 3,
+            },
+        }?;
+        event.alarms.push(crate::valarm! {
+            @display,
+            trigger: "-P1D",
+            description: "Tomorrow",
+        }?);
+
+        let mut end = crate::valarm! {
+            @display,
+            description: "Finished",
+        }?;
+        if let crate::VAlarm::Display(display) = &mut end {
+            display.trigger =
+                crate::Trigger::Duration(crate::Duration::zero(), crate::trigger::Related::End);
+        }
+        event.alarms.push(end);
+
+        let instants = event
+            .alarm_instants()
+            .map(|x| (x.instant.to_string(), x.occurrence.dtstart.to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            instants,
+            [
+                ("20231231T090000", "20240101T090000"),
+                ("20240101T090000", "20240102T090000"),
+                ("20240101T100000", "20240101T090000"),
+                ("20240102T090000", "20240103T090000"),
+                ("20240102T100000", "20240102T090000"),
+                ("20240103T100000", "20240103T090000"),
+            ]
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn alarms_repeat() -> crate::Result {
+        let mut event = crate::vevent! {
+            dtstart: "20240101T090000",
+        }?;
+        event.alarms.push(crate::valarm! {
+            @audio,
+            trigger: "-PT30M",
+            duration: "PT10M",
+            repeat: 2,
+        }?);
+
+        let instants = event
+            .alarm_instants()
+            .map(|x| x.instant.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            instants,
+            ["20240101T083000", "20240101T084000", "20240101T085000"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn alarms_timezones() -> crate::Result {
+        let vcalendar: crate::VCalendar = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//EN\r
+BEGIN:VTIMEZONE\r
+TZID:America/New_York\r
+BEGIN:STANDARD\r
+DTSTART:19701101T020000\r
+TZOFFSETFROM:-0400\r
+TZOFFSETTO:-0500\r
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r
+END:STANDARD\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20231201T000000Z\r
+DTSTART;TZID=America/New_York:20240101T090000\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+DESCRIPTION:Soon\r
+TRIGGER:-PT15M\r
+END:VALARM\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+DESCRIPTION:Today\r
+TRIGGER;VALUE=DATE-TIME:20240101T100000Z\r
+END:VALARM\r
+END:VEVENT\r
+END:VCALENDAR\r
+"
+        .parse()?;
+
+        let instants = vcalendar.events[0]
+            .alarm_instants()
+            .with_timezones(&vcalendar.timezones)
+            .map(|x| x.instant.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(instants, ["20240101T100000Z", "20240101T084500"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn alarms_between() -> crate::Result {
+        let mut event = crate::vevent! {
+            dtstart: "20240101T090000",
+            rrule: {
+                freq: Weekly,
+            },
+        }?;
+        event.alarms.push(crate::valarm! {
+            @display,
+            trigger: "-PT15M",
+            description: "Weekly meeting",
+        }?);
+        event.alarms.push(crate::valarm! {
+            @display,
+            trigger: "20231225T120000",
+            description: "Once",
+        }?);
+
+        let start: crate::DateTime = "20240110T000000".parse()?;
+        let end: crate::DateTime = "20240201T000000".parse()?;
+
+        let instants = event
+            .alarm_instants()
+            .between(start, end)
+            .map(|x| x.instant.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            instants,
+            ["20240115T084500", "20240122T084500", "20240129T084500"]
+        );
+
+        assert_eq!(
+            event.alarm_instants().next().unwrap().instant,
+            "20231225T120000".parse()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn alarms_vtodo() -> crate::Result {
+        let mut vtodo = crate::vtodo! {
+            dtstart: "20240101T090000",
+            due: "20240105T180000",
+            rrule: {
+                freq: Weekly,
+                count: 2,
+            },
+        }?;
+
+        let mut due = crate::valarm! {
+            @display,
+            description: "Due soon",
+        }?;
+        if let crate::VAlarm::Display(display) = &mut due {
+            display.trigger =
+                crate::Trigger::Duration(-crate::Duration::hours(1), crate::trigger::Related::End);
+        }
+        vtodo.alarms.push(due);
+
+        let instants = vtodo
+            .alarm_instants()
+            .map(|x| x.instant.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(instants, ["20240105T170000", "20240112T170000"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn alarms_unresolved() -> crate::Result {
+        let mut vtodo = crate::vtodo! {
+            dtstart: "20240101T090000",
+            rrule: {
+                freq: Daily,
+            },
+        }?;
+
+        let mut end = crate::valarm! {
+            @display,
+            description: "Never",
+        }?;
+        if let crate::VAlarm::Display(display) = &mut end {
+            display.trigger =
+                crate::Trigger::Duration(-crate::Duration::hours(1), crate::trigger::Related::End);
+        }
+        vtodo.alarms.push(end);
+
+        assert_eq!(vtodo.alarm_instants().next(), None);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,1017 @@
+/*!
+ * See [RFC 5546](https://datatracker.ietf.org/doc/html/rfc5546)
+ */
+
+/**
+ * Scheduling properties of a component, see
+ * [3.1. Common Component Restriction Tables](https://datatracker.ietf.org/doc/html/rfc5546#section-3.1).
+ */
+pub struct Scheduling<'a> {
+    pub dtstamp: &'a crate::DateTime,
+    pub uid: &'a crate::Text,
+    pub organizer: Option<&'a crate::Uri>,
+    pub attendee: &'a [crate::Uri],
+    pub sequence: Option<u32>,
+    pub summary: Option<&'a crate::Text>,
+    pub recurid: Option<&'a crate::Date>,
+    pub status: Option<&'a crate::Status>,
+    pub rstatus: &'a [crate::RequestStatus],
+    pub exdate: &'a [crate::Date],
+}
+
+/**
+ * Mutable scheduling properties of a component, see [`Scheduling`].
+ */
+pub struct SchedulingMut<'a> {
+    pub dtstamp: &'a mut crate::DateTime,
+    pub uid: &'a mut crate::Text,
+    pub organizer: &'a mut Option<crate::Uri>,
+    pub attendee: &'a mut Vec<crate::Uri>,
+    pub sequence: &'a mut Option<u32>,
+    pub summary: &'a mut Option<crate::Text>,
+    pub recurid: &'a mut Option<crate::Date>,
+    pub status: &'a mut Option<crate::Status>,
+    pub rstatus: &'a mut Vec<crate::RequestStatus>,
+    pub exdate: &'a mut Vec<crate::Date>,
+}
+
+/**
+ * A component which can be sent in an iTIP message.
+ */
+pub trait Schedulable: crate::iter::Recurring {
+    fn scheduling(&self) -> Scheduling<'_>;
+
+    fn scheduling_mut(&mut self) -> SchedulingMut<'_>;
+
+    /**
+     * Returns the components of this type in `calendar`.
+     */
+    fn components(calendar: &crate::VCalendar) -> &[Self];
+
+    /**
+     * Returns the occurrence of a recurring component starting at `recurid`, as an override.
+     */
+    fn instance(&self, recurid: crate::Date) -> Option<Self>;
+
+    /**
+     * Whether the component has an instance at `recurid`, `None` if it can't be determined.
+     */
+    fn generates(&self, recurid: &crate::Date) -> Option<bool>;
+
+    /**
+     * Returns a copy restricted to the properties allowed in a REFRESH.
+     */
+    #[must_use]
+    fn refresh(&self) -> Self;
+
+    /**
+     * Returns a copy restricted to the properties allowed in a DECLINECOUNTER, without the
+     * proposed changes.
+     */
+    #[must_use]
+    fn decline_counter(&self) -> Self;
+
+    fn into_calendar(self) -> crate::VCalendar;
+}
+
+macro_rules! schedulable {
+    ($ty:ty, $components:ident) => {
+        impl Schedulable for $ty {
+            fn scheduling(&self) -> Scheduling<'_> {
+                Scheduling {
+                    dtstamp: &self.dtstamp,
+                    uid: &self.uid,
+                    organizer: self.organizer.as_ref(),
+                    attendee: &self.attendee,
+                    sequence: self.sequence,
+                    summary: self.summary.as_ref(),
+                    recurid: self.recurid.as_ref(),
+                    status: self.status.as_ref(),
+                    rstatus: &self.rstatus,
+                    exdate: &self.exdate,
+                }
+            }
+
+            fn scheduling_mut(&mut self) -> SchedulingMut<'_> {
+                SchedulingMut {
+                    dtstamp: &mut self.dtstamp,
+                    uid: &mut self.uid,
+                    organizer: &mut self.organizer,
+                    attendee: &mut self.attendee,
+                    sequence: &mut self.sequence,
+                    summary: &mut self.summary,
+                    recurid: &mut self.recurid,
+                    status: &mut self.status,
+                    rstatus: &mut self.rstatus,
+                    exdate: &mut self.exdate,
+                }
+            }
+
+            fn components(calendar: &crate::VCalendar) -> &[Self] {
+                &calendar.$components
+            }
+
+            fn instance(&self, recurid: crate::Date) -> Option<Self> {
+                use crate::iter::Recurring as _;
+
+                let mut instance = self
+                    .recurrent()
+                    .take_while(|x| x.dtstart().is_some_and(|x| *x <= recurid))
+                    .find(|x| x.dtstart() == Some(&recurid))?;
+
+                instance.recurid = Some(recurid);
+                instance.rrule = None;
+                instance.rdate.clear();
+                instance.exdate.clear();
+
+                Some(instance)
+            }
+
+            fn generates(&self, recurid: &crate::Date) -> Option<bool> {
+                crate::validate::generates(self, recurid)
+            }
+
+            fn refresh(&self) -> Self {
+                Self {
+                    dtstamp: self.dtstamp.clone(),
+                    uid: self.uid.clone(),
+                    dtstart: self.dtstart.clone(),
+                    organizer: self.organizer.clone(),
+                    attendee: self.attendee.clone(),
+                    recurid: self.recurid.clone(),
+                    comment: self.comment.clone(),
+                    x_prop: self.x_prop.clone(),
+
+                    ..Default::default()
+                }
+            }
+
+            fn decline_counter(&self) -> Self {
+                Self {
+                    sequence: self.sequence,
+                    rstatus: self.rstatus.clone(),
+
+                    ..self.refresh()
+                }
+            }
+
+            fn into_calendar(self) -> crate::VCalendar {
+                crate::VCalendar {
+                    $components: vec![self],
+
+                    ..Default::default()
+                }
+            }
+        }
+    };
+}
+
+schedulable!(crate::VEvent, events);
+schedulable!(crate::VTodo, todo);
+
+/**
+ * Builds an iTIP message from an event or a to-do.
+ *
+ * Missing required properties are reported as [`crate::Error::Itip`], properties forbidden by the
+ * method are removed from the message.
+ *
+ * ```
+ * let event = ikal::VEvent {
+ *     uid: "123456789@example.com".into(),
+ *     dtstamp: "19970901T130000Z".parse()?,
+ *     dtstart: "19970903T163000Z".parse()?,
+ *     summary: Some("Annual Employee Review".into()),
+ *     organizer: Some("mailto:a@example.com".into()),
+ *     attendee: vec!["mailto:b@example.com".into()],
+ *
+ *     ..Default::default()
+ * };
+ *
+ * let message = ikal::itip::Builder::new(&event).request()?;
+ * assert_eq!(message.method, Some(ikal::Method::Request));
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+pub struct Builder<T: Schedulable> {
+    component: T,
+    dtstamp: crate::DateTime,
+    prodid: crate::Text,
+    revise: bool,
+}
+
+impl<T: Schedulable> Builder<T> {
+    #[must_use]
+    pub fn new(component: &T) -> Self {
+        use crate::scheduler::Clock as _;
+
+        Self {
+            component: component.clone(),
+            dtstamp: crate::scheduler::SystemClock.now(),
+            prodid: concat!("-//ikal//NONSGML ikal ", env!("CARGO_PKG_VERSION"), "//EN").into(),
+            revise: false,
+        }
+    }
+
+    /**
+     * Overrides the message creation date, now by default.
+     */
+    #[must_use]
+    pub fn dtstamp(mut self, dtstamp: crate::DateTime) -> Self {
+        self.dtstamp = dtstamp;
+        self
+    }
+
+    #[must_use]
+    pub fn prodid(mut self, prodid: impl Into<crate::Text>) -> Self {
+        self.prodid = prodid.into();
+        self
+    }
+
+    /**
+     * Increments the sequence number, for a significant change of a published or requested
+     * component. ADD and CANCEL always increment it.
+     */
+    #[must_use]
+    pub fn revise(mut self) -> Self {
+        self.revise = true;
+        self
+    }
+
+    /**
+     * See [3.2.1. PUBLISH](https://datatracker.ietf.org/doc/html/rfc5546#section-3.2.1)
+     */
+    pub fn publish(self) -> crate::Result<crate::VCalendar> {
+        self.build(crate::Method::Publish, |scheduling| {
+            scheduling.attendee.clear();
+            scheduling.rstatus.clear();
+
+            Ok(())
+        })
+    }
+
+    /**
+     * See [3.2.2. REQUEST](https://datatracker.ietf.org/doc/html/rfc5546#section-3.2.2)
+     */
+    pub fn request(self) -> crate::Result<crate::VCalendar> {
+        self.build(crate::Method::Request, |scheduling| {
+            require_attendee(&crate::Method::Request, scheduling)?;
+            scheduling.rstatus.clear();
+
+            Ok(())
+        })
+    }
+
+    /**
+     * See [3.2.3. REPLY](https://datatracker.ietf.org/doc/html/rfc5546#section-3.2.3)
+     *
+     * `attendee` is the replying attendee, with its new `PARTSTAT`.
+     */
+    pub fn reply(self, attendee: &crate::Uri) -> crate::Result<crate::VCalendar> {
+        self.build(crate::Method::Reply, |scheduling| {
+            single_attendee(&crate::Method::Reply, scheduling, attendee)
+        })
+    }
+
+    /**
+     * See [3.2.4. ADD](https://datatracker.ietf.org/doc/html/rfc5546#section-3.2.4)
+     */
+    pub fn add(self) -> crate::Result<crate::VCalendar> {
+        self.build(crate::Method::Add, |scheduling| {
+            require_attendee(&crate::Method::Add, scheduling)?;
+            *scheduling.recurid = None;
+            scheduling.rstatus.clear();
+
+            Ok(())
+        })
+    }
+
+    /**
+     * See [3.2.5. CANCEL](https://datatracker.ietf.org/doc/html/rfc5546#section-3.2.5)
+     */
+    pub fn cancel(self) -> crate::Result<crate::VCalendar> {
+        self.build(crate::Method::Cancel, |scheduling| {
+            *scheduling.status = Some(crate::Status::Cancelled);
+            scheduling.rstatus.clear();
+
+            Ok(())
+        })
+    }
+
+    /**
+     * See [3.2.6. REFRESH](https://datatracker.ietf.org/doc/html/rfc5546#section-3.2.6)
+     *
+     * `DTSTART` is kept for events, the property being required by [`crate::VEvent`].
+     */
+    pub fn refresh(mut self, attendee: &crate::Uri) -> crate::Result<crate::VCalendar> {
+        self.component = self.component.refresh();
+
+        self.build(crate::Method::Refresh, |scheduling| {
+            single_attendee(&crate::Method::Refresh, scheduling, attendee)
+        })
+    }
+
+    /**
+     * See [3.2.7. COUNTER](https://datatracker.ietf.org/doc/html/rfc5546#section-3.2.7)
+     *
+     * A missing `SEQUENCE` is written as 0, its default value.
+     */
+    pub fn counter(self) -> crate::Result<crate::VCalendar> {
+        self.build(crate::Method::Counter, |scheduling| {
+            if scheduling.summary.is_none() {
+                return Err(crate::Error::Itip(format!(
+                    "{} requires a SUMMARY",
+                    crate::Method::Counter
+                )));
+            }
+
+            scheduling.sequence.get_or_insert(0);
+            scheduling.rstatus.clear();
+
+            Ok(())
+        })
+    }
+
+    /**
+     * See [3.2.8. DECLINECOUNTER](https://datatracker.ietf.org/doc/html/rfc5546#section-3.2.8)
+     *
+     * `DTSTART` is kept for events, the property being required by [`crate::VEvent`].
+     */
+    pub fn decline_counter(mut self) -> crate::Result<crate::VCalendar> {
+        self.component = self.component.decline_counter();
+
+        self.build(crate::Method::DeclineCounter, |scheduling| {
+            require_attendee(&crate::Method::DeclineCounter, scheduling)
+        })
+    }
+
+    fn build<F>(mut self, method: crate::Method, f: F) -> crate::Result<crate::VCalendar>
+    where
+        F: FnOnce(&mut SchedulingMut) -> crate::Result,
+    {
+        let revise = self.revise || matches!(method, crate::Method::Add | crate::Method::Cancel);
+
+        {
+            let mut scheduling = self.component.scheduling_mut();
+
+            if scheduling.organizer.is_none() {
+                return Err(crate::Error::Itip(format!(
+                    "{method} requires an ORGANIZER"
+                )));
+            }
+
+            if revise {
+                *scheduling.sequence = Some(scheduling.sequence.unwrap_or_default() + 1);
+            }
+            *scheduling.dtstamp = self.dtstamp;
+
+            f(&mut scheduling)?;
+        }
+
+        let mut calendar = self.component.into_calendar();
+        calendar.prodid = self.prodid;
+        calendar.version = "2.0".into();
+        calendar.method = Some(method);
+
+        Ok(calendar)
+    }
+}
+
+fn require_attendee(method: &crate::Method, scheduling: &SchedulingMut) -> crate::Result {
+    if scheduling.attendee.is_empty() {
+        return Err(crate::Error::Itip(format!("{method} requires an ATTENDEE")));
+    }
+
+    Ok(())
+}
+
+fn single_attendee(
+    method: &crate::Method,
+    scheduling: &mut SchedulingMut,
+    attendee: &crate::Uri,
+) -> crate::Result {
+    if !scheduling
+        .attendee
+        .iter()
+        .any(|x| x.uri.eq_ignore_ascii_case(&attendee.uri))
+    {
+        return Err(crate::Error::Itip(format!(
+            "{method}: {} is not an ATTENDEE",
+            attendee.uri
+        )));
+    }
+
+    *scheduling.attendee = vec![attendee.clone()];
+
+    Ok(())
+}
+
+/**
+ * Outcome of [`process`].
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub enum Processed<T> {
+    /** The message was applied, these are the new stored components */
+    Updated(Vec<T>),
+    /** A proposal of the attendee, the stored components are unchanged */
+    Counter(T),
+    /** The message is older than the stored components and was ignored */
+    Outdated,
+    /** The message doesn't match the stored components and was ignored */
+    Conflict(String),
+}
+
+/**
+ * Applies an incoming iTIP message to the stored components of a same UID, the master component
+ * and its overrides.
+ *
+ * See [2.1.5. Message Sequencing](https://datatracker.ietf.org/doc/html/rfc5546#section-2.1.5)
+ */
+pub fn process<T: Schedulable>(
+    stored: &[T],
+    message: &crate::VCalendar,
+) -> crate::Result<Processed<T>> {
+    let method = message
+        .method
+        .as_ref()
+        .ok_or_else(|| crate::Error::Itip("missing METHOD".to_string()))?;
+
+    let incoming = T::components(message);
+    if incoming.is_empty() {
+        return Err(crate::Error::Itip(format!("{method} without component")));
+    }
+
+    let mut stored = stored.to_vec();
+    let uid = incoming[0].scheduling().uid.clone();
+
+    if let Some(other) = incoming
+        .iter()
+        .chain(stored.iter())
+        .map(|x| x.scheduling().uid)
+        .find(|x| x.text != uid.text)
+    {
+        return Ok(Processed::Conflict(format!(
+            "UID mismatch: {} and {}",
+            uid.text, other.text
+        )));
+    }
+
+    for component in incoming {
+        let component = component.clone();
+
+        let processed = match method {
+            crate::Method::Publish | crate::Method::Request => request(&mut stored, component),
+            crate::Method::Reply => reply(&mut stored, component)?,
+            crate::Method::Cancel => cancel(&mut stored, component),
+            crate::Method::Counter => Some(counter(&stored, component)),
+            method => return Err(crate::Error::Itip(format!("{method} is not supported"))),
+        };
+
+        if let Some(processed) = processed {
+            return Ok(processed);
+        }
+    }
+
+    Ok(Processed::Updated(stored))
+}
+
+fn request<T: Schedulable>(stored: &mut Vec<T>, component: T) -> Option<Processed<T>> {
+    let recurid = component.scheduling().recurid.cloned();
+
+    match position(stored, recurid.as_ref()) {
+        Some(index) => {
+            if version(&component) < version(&stored[index]) {
+                return Some(Processed::Outdated);
+            }
+
+            stored[index] = component;
+        }
+        None => stored.push(component),
+    }
+
+    // A new version of the series supersedes older overrides
+    if recurid.is_none()
+        && let Some(master) = position(stored, None)
+    {
+        let (sequence, _) = version(&stored[master]);
+
+        stored.retain(|x| {
+            let (override_sequence, _) = version(x);
+
+            x.scheduling().recurid.is_none() || override_sequence >= sequence
+        });
+    }
+
+    None
+}
+
+/**
+ * `ATTENDEE` parameter of the stored components with the `DTSTAMP` of the last REPLY applied,
+ * named as by CalendarServer.
+ */
+const REPLY_DTSTAMP: &str = "X-CALENDARSERVER-DTSTAMP";
+
+fn reply<T: Schedulable>(stored: &mut Vec<T>, component: T) -> crate::Result<Option<Processed<T>>> {
+    let scheduling = component.scheduling();
+
+    let [attendee] = scheduling.attendee else {
+        return Err(crate::Error::Itip(
+            "REPLY requires exactly one ATTENDEE".to_string(),
+        ));
+    };
+    let attendee = attendee.clone();
+    let recurid = scheduling.recurid.cloned();
+    let (sequence, dtstamp) = version(&component);
+
+    let index = match (position(stored, recurid.as_ref()), recurid) {
+        (Some(index), _) => index,
+        (None, Some(recurid)) => {
+            let Some(instance) =
+                position(stored, None).and_then(|x| stored[x].instance(recurid.clone()))
+            else {
+                return Ok(Some(Processed::Conflict(format!(
+                    "no instance at {recurid}"
+                ))));
+            };
+
+            stored.push(instance);
+            stored.len() - 1
+        }
+        (None, None) => {
+            return Ok(Some(Processed::Conflict("no stored component".to_string())));
+        }
+    };
+
+    let target = stored[index].scheduling_mut();
+
+    if sequence < target.sequence.unwrap_or_default() {
+        return Ok(Some(Processed::Outdated));
+    }
+
+    let Some(existing) = target
+        .attendee
+        .iter_mut()
+        .find(|x| x.uri.eq_ignore_ascii_case(&attendee.uri))
+    else {
+        return Ok(Some(Processed::Conflict(format!(
+            "{} is not an ATTENDEE",
+            attendee.uri
+        ))));
+    };
+
+    let dtstamp = crate::convert::instant(&dtstamp, &[]);
+    let applied = existing
+        .params
+        .get(REPLY_DTSTAMP)
+        .and_then(|x| x.parse::<crate::DateTime>().ok())
+        .map(|x| crate::convert::instant(&x, &[]));
+
+    if applied.is_some_and(|applied| dtstamp < applied) {
+        return Ok(Some(Processed::Outdated));
+    }
+
+    existing.params.extend(attendee.params);
+    existing.params.insert(
+        REPLY_DTSTAMP.to_string(),
+        dtstamp.format("%Y%m%dT%H%M%SZ").to_string(),
+    );
+
+    Ok(None)
+}
+
+fn cancel<T: Schedulable>(stored: &mut [T], component: T) -> Option<Processed<T>> {
+    let recurid = component.scheduling().recurid.cloned();
+    let incoming = version(&component);
+
+    let Some(index) = position(stored, recurid.as_ref()).or_else(|| position(stored, None)) else {
+        return Some(Processed::Conflict("no stored component".to_string()));
+    };
+
+    if incoming < version(&stored[index]) {
+        return Some(Processed::Outdated);
+    }
+
+    let cancelled = match recurid {
+        // Whole series
+        None => &mut stored[..],
+        Some(recurid) => {
+            if stored[index].scheduling().recurid.is_none()
+                && stored[index].generates(&recurid) == Some(false)
+            {
+                return Some(Processed::Conflict(format!("no instance at {recurid}")));
+            }
+
+            let scheduling = stored[index].scheduling_mut();
+
+            if scheduling.recurid.is_none() {
+                if !scheduling.exdate.contains(&recurid) {
+                    scheduling.exdate.push(recurid);
+                }
+                *scheduling.sequence = Some(incoming.0);
+                *scheduling.dtstamp = incoming.1.clone();
+
+                return None;
+            }
+
+            &mut stored[index..=index]
+        }
+    };
+
+    for component in cancelled {
+        let scheduling = component.scheduling_mut();
+
+        *scheduling.status = Some(crate::Status::Cancelled);
+        *scheduling.sequence = Some(incoming.0);
+        *scheduling.dtstamp = incoming.1.clone();
+    }
+
+    None
+}
+
+fn counter<T: Schedulable>(stored: &[T], component: T) -> Processed<T> {
+    let recurid = component.scheduling().recurid.cloned();
+
+    let Some(index) = position(stored, recurid.as_ref()).or_else(|| position(stored, None)) else {
+        return Processed::Conflict("no stored component".to_string());
+    };
+
+    if version(&component).0 < version(&stored[index]).0 {
+        return Processed::Outdated;
+    }
+
+    Processed::Counter(component)
+}
+
+fn position<T: Schedulable>(stored: &[T], recurid: Option<&crate::Date>) -> Option<usize> {
+    stored
+        .iter()
+        .position(|x| x.scheduling().recurid == recurid)
+}
+
+fn version<T: Schedulable>(component: &T) -> (u32, crate::DateTime) {
+    let scheduling = component.scheduling();
+
+    (
+        scheduling.sequence.unwrap_or_default(),
+        scheduling.dtstamp.clone(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    fn event() -> crate::Result<crate::VEvent> {
+        crate::vevent! {
+            uid: "123456789@example.com",
+            dtstamp: "19970901T130000",
+            dtstart: "19970903T163000",
+            summary: "Annual Employee Review",
+            sequence: 2,
+            organizer: "mailto:a@example.com",
+            attendee: ["mailto:a@example.com", "mailto:b@example.com"],
+        }
+    }
+
+    fn builder(event: &crate::VEvent) -> crate::Result<crate::itip::Builder<crate::VEvent>> {
+        Ok(crate::itip::Builder::new(event)
+            .dtstamp("19970902T090000".parse()?)
+            .prodid("-//Example//EN"))
+    }
+
+    #[test]
+    fn request() -> crate::Result {
+        let event = event()?;
+        let message = builder(&event)?.request()?;
+
+        similar_asserts::assert_eq!(
+            crate::ser::ical(&message),
+            "BEGIN:VCALENDAR\r
+PRODID:-//Example//EN\r
+VERSION:2.0\r
+METHOD:REQUEST\r
+BEGIN:VEVENT\r
+DTSTAMP:19970902T090000\r
+UID:123456789@example.com\r
+DTSTART:19970903T163000\r
+ORGANIZER:mailto:a@example.com\r
+SEQUENCE:2\r
+SUMMARY:Annual Employee Review\r
+ATTENDEE:mailto:a@example.com\r
+ATTENDEE:mailto:b@example.com\r
+END:VEVENT\r
+END:VCALENDAR\r
+"
+        );
+
+        let message = builder(&event)?.revise().request()?;
+        assert_eq!(message.events[0].sequence, Some(3));
+
+        let mut event = event;
+        event.attendee.clear();
+        assert!(matches!(
+            builder(&event)?.request(),
+            Err(crate::Error::Itip(_))
+        ));
+
+        event.organizer = None;
+        assert!(matches!(
+            builder(&event)?.publish(),
+            Err(crate::Error::Itip(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publish() -> crate::Result {
+        let message = builder(&event()?)?.publish()?;
+
+        assert_eq!(message.method, Some(crate::Method::Publish));
+        assert!(message.events[0].attendee.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn reply() -> crate::Result {
+        let event = event()?;
+
+        let mut attendee = crate::Uri::from("mailto:B@example.com");
+        attendee
+            .params
+            .insert("PARTSTAT".to_string(), "ACCEPTED".to_string());
+
+        let message = builder(&event)?.reply(&attendee)?;
+        assert_eq!(message.events[0].attendee, vec![attendee]);
+        assert_eq!(message.events[0].sequence, Some(2));
+
+        let stranger = crate::Uri::from("mailto:c@example.com");
+        assert!(matches!(
+            builder(&event)?.reply(&stranger),
+            Err(crate::Error::Itip(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn add() -> crate::Result {
+        let mut event = event()?;
+        event.recurid = Some("19970903T163000".parse()?);
+
+        let message = builder(&event)?.add()?;
+        assert_eq!(message.events[0].sequence, Some(3));
+        assert_eq!(message.events[0].recurid, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancel() -> crate::Result {
+        let mut event = event()?;
+        event.sequence = None;
+
+        let message = builder(&event)?.cancel()?;
+        assert_eq!(message.events[0].sequence, Some(1));
+        assert_eq!(message.events[0].status, Some(crate::Status::Cancelled));
+
+        Ok(())
+    }
+
+    #[test]
+    fn refresh() -> crate::Result {
+        let attendee = crate::Uri::from("mailto:b@example.com");
+        let message = builder(&event()?)?.refresh(&attendee)?;
+
+        let event = &message.events[0];
+        assert_eq!(event.summary, None);
+        assert_eq!(event.sequence, None);
+        assert_eq!(event.attendee, vec![attendee]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn todo() -> crate::Result {
+        let todo = crate::vtodo! {
+            uid: "uid4@example.com",
+            dtstamp: "19970901T130000",
+            summary: "Submit Income Taxes",
+            organizer: "mailto:a@example.com",
+            attendee: ["mailto:b@example.com"],
+        }?;
+
+        let message = crate::itip::Builder::new(&todo).counter()?;
+        assert_eq!(message.method, Some(crate::Method::Counter));
+        assert_eq!(message.todo.len(), 1);
+
+        let message = crate::itip::Builder::new(&todo).decline_counter()?;
+        assert_eq!(message.method, Some(crate::Method::DeclineCounter));
+
+        Ok(())
+    }
+
+    #[test]
+    fn counter() -> crate::Result {
+        let mut event = event()?;
+        event.sequence = None;
+
+        let message = builder(&event)?.counter()?;
+        assert_eq!(message.events[0].sequence, Some(0));
+
+        event.summary = None;
+        assert_eq!(
+            builder(&event)?.counter(),
+            Err(crate::Error::Itip("COUNTER requires a SUMMARY".to_string()))
+        );
+
+        event.organizer = None;
+        assert!(matches!(
+            builder(&event)?.counter(),
+            Err(crate::Error::Itip(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn decline_counter() -> crate::Result {
+        let mut proposal = event()?;
+        proposal.dtend = Some("19970903T190000".parse()?);
+        proposal.location = Some("Conference room".into());
+
+        let message = builder(&proposal)?.decline_counter()?;
+        let event = &message.events[0];
+        assert_eq!(event.summary, None);
+        assert_eq!(event.dtend, None);
+        assert_eq!(event.location, None);
+        assert_eq!(event.sequence, Some(2));
+        assert_eq!(event.attendee.len(), 2);
+
+        proposal.attendee.clear();
+        assert!(matches!(
+            builder(&proposal)?.decline_counter(),
+            Err(crate::Error::Itip(_))
+        ));
+
+        Ok(())
+    }
+
+    fn series() -> crate::Result<crate::VEvent> {
+        let mut event = event()?;
+        event.rrule = Some("FREQ=WEEKLY;COUNT=4".parse()?);
+
+        Ok(event)
+    }
+
+    fn partstat(event: &crate::VEvent, attendee: usize) -> Option<&str> {
+        event.attendee[attendee]
+            .params
+            .get("PARTSTAT")
+            .map(String::as_str)
+    }
+
+    #[test]
+    fn process_request() -> crate::Result {
+        let stored = vec![event()?];
+
+        let message = builder(&stored[0])?.revise().request()?;
+        let crate::itip::Processed::Updated(updated) = crate::itip::process(&stored, &message)?
+        else {
+            panic!()
+        };
+        assert_eq!(updated[0].sequence, Some(3));
+
+        let message = crate::itip::Builder::new(&updated[0])
+            .dtstamp("19970901T090000".parse()?)
+            .request()?;
+        let mut old = message.clone();
+        old.events[0].sequence = Some(2);
+        assert_eq!(
+            crate::itip::process(&updated, &old)?,
+            crate::itip::Processed::Outdated
+        );
+
+        let mut other = message;
+        other.events[0].uid = "other".into();
+        assert!(matches!(
+            crate::itip::process(&updated, &other)?,
+            crate::itip::Processed::Conflict(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn process_reply() -> crate::Result {
+        let stored = vec![series()?];
+
+        let mut attendee = crate::Uri::from("mailto:b@example.com");
+        attendee
+            .params
+            .insert("PARTSTAT".to_string(), "ACCEPTED".to_string());
+
+        let message = builder(&stored[0])?.reply(&attendee)?;
+        let crate::itip::Processed::Updated(updated) = crate::itip::process(&stored, &message)?
+        else {
+            panic!()
+        };
+        assert_eq!(partstat(&updated[0], 1), Some("ACCEPTED"));
+
+        // An older reply of the same attendee arriving late
+        let mut late = attendee.clone();
+        late.params
+            .insert("PARTSTAT".to_string(), "TENTATIVE".to_string());
+        let message = builder(&stored[0])?
+            .dtstamp("19970901T100000".parse()?)
+            .reply(&late)?;
+        assert_eq!(
+            crate::itip::process(&updated, &message)?,
+            crate::itip::Processed::Outdated
+        );
+
+        let mut instance = stored[0].clone();
+        instance.recurid = Some("19970910T163000".parse()?);
+        attendee
+            .params
+            .insert("PARTSTAT".to_string(), "DECLINED".to_string());
+        let message = builder(&instance)?.reply(&attendee)?;
+        let crate::itip::Processed::Updated(updated) = crate::itip::process(&updated, &message)?
+        else {
+            panic!()
+        };
+        assert_eq!(updated.len(), 2);
+        assert_eq!(updated[1].dtstart, "19970910T163000".parse()?);
+        assert_eq!(updated[1].rrule, None);
+        assert_eq!(partstat(&updated[0], 1), Some("ACCEPTED"));
+        assert_eq!(partstat(&updated[1], 1), Some("DECLINED"));
+
+        let mut outdated = message;
+        outdated.events[0].sequence = Some(1);
+        assert_eq!(
+            crate::itip::process(&updated, &outdated)?,
+            crate::itip::Processed::Outdated
+        );
+
+        let mut stranger = builder(&stored[0])?.reply(&attendee)?;
+        stranger.events[0].attendee[0].uri = "mailto:c@example.com".to_string();
+        assert!(matches!(
+            crate::itip::process(&stored, &stranger)?,
+            crate::itip::Processed::Conflict(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn process_cancel() -> crate::Result {
+        let stored = vec![series()?];
+
+        let mut instance = stored[0].clone();
+        instance.recurid = Some("19970910T163000".parse()?);
+        let message = builder(&instance)?.cancel()?;
+        let crate::itip::Processed::Updated(updated) = crate::itip::process(&stored, &message)?
+        else {
+            panic!()
+        };
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].exdate, vec!["19970910T163000".parse()?]);
+        assert_eq!(updated[0].status, None);
+        assert!(
+            !updated[0]
+                .recurrent()
+                .any(|x| x.dtstart == "19970910T163000".parse().unwrap())
+        );
+
+        let mut unknown = stored[0].clone();
+        unknown.recurid = Some("19970911T163000".parse()?);
+        let message = builder(&unknown)?.cancel()?;
+        assert!(matches!(
+            crate::itip::process(&updated, &message)?,
+            crate::itip::Processed::Conflict(_)
+        ));
+
+        let message = builder(&updated[0])?.cancel()?;
+        let crate::itip::Processed::Updated(updated) = crate::itip::process(&updated, &message)?
+        else {
+            panic!()
+        };
+        assert_eq!(updated[0].status, Some(crate::Status::Cancelled));
+        assert_eq!(updated[0].sequence, Some(4));
+
+        Ok(())
+    }
+
+    #[test]
+    fn process_counter() -> crate::Result {
+        let stored = vec![event()?];
+
+        let mut proposal = stored[0].clone();
+        proposal.dtstart = "19970904T163000".parse()?;
+        let message = builder(&proposal)?.counter()?;
+        assert_eq!(
+            crate::itip::process(&stored, &message)?,
+            crate::itip::Processed::Counter(message.events[0].clone())
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,375 @@
+/*!
+ * See [RFC 7265](https://datatracker.ietf.org/doc/html/rfc7265)
+ *
+ * ```
+ * let calendar: ikal::VCalendar = std::fs::read_to_string("tests/calendars/1.ics")?.try_into()?;
+ *
+ * let jcal = ikal::jcal::to_string(&calendar)?;
+ * let parsed: ikal::VCalendar = ikal::jcal::from_str(&jcal)?;
+ *
+ * assert_eq!(parsed, calendar);
+ * # Ok::<(), Box<dyn std::error::Error>>(())
+ * ```
+ */
+
+use serde_json::Value;
+
+/**
+ * Converts a component to its jCal representation.
+ */
+pub fn to_value<T: crate::ser::Serialize>(component: &T) -> crate::Result<Value> {
+    let ical = crate::ser::ical(component).replace("\r\n ", "");
+    let tree = crate::tree::Component::parse(&ical)?;
+
+    Ok(component_to_value(&tree))
+}
+
+pub fn to_string<T: crate::ser::Serialize>(component: &T) -> crate::Result<String> {
+    to_value(component).map(|x| x.to_string())
+}
+
+/**
+ * Parses a component from its jCal representation.
+ */
+pub fn from_value<T>(value: &Value) -> crate::Result<T>
+where
+    T: std::str::FromStr<Err = crate::Error>,
+{
+    component_from_value(value)?.to_string().parse()
+}
+
+pub fn from_str<T>(s: &str) -> crate::Result<T>
+where
+    T: std::str::FromStr<Err = crate::Error>,
+{
+    let value = serde_json::from_str(s).map_err(|err| crate::Error::Jcal(err.to_string()))?;
+
+    from_value(&value)
+}
+
+fn component_to_value(component: &crate::tree::Component) -> Value {
+    let properties = component.properties.iter().map(property_to_value).collect();
+    let components = component
+        .components
+        .iter()
+        .map(component_to_value)
+        .collect();
+
+    Value::Array(vec![
+        Value::String(component.name.to_lowercase()),
+        Value::Array(properties),
+        Value::Array(components),
+    ])
+}
+
+fn property_to_value(property: &crate::ContentLine) -> Value {
+    let ty = crate::tree::value_type(property);
+    let ty = ty.as_str();
+
+    // A redundant VALUE is kept as parameter, the type alone would drop it on import
+    let params = property
+        .params
+        .iter()
+        .filter(|(k, _)| k.as_str() != "VALUE" || ty == crate::tree::default_type(&property.key))
+        .map(|(k, v)| (k.to_lowercase(), Value::String(v.clone())))
+        .collect();
+
+    let mut value = vec![
+        Value::String(property.key.to_lowercase()),
+        Value::Object(params),
+        Value::String(ty.to_string()),
+    ];
+
+    if ty == "unknown" {
+        value.push(Value::String(property.value.clone()));
+    } else if ty == "recur" {
+        value.push(recur_to_value(&property.value));
+    } else if ty == "text" && property.key == "REQUEST-STATUS" {
+        let status = crate::tree::split(&property.value, ';')
+            .map(|x| Value::String(crate::ser::unescape(x)))
+            .collect();
+        value.push(Value::Array(status));
+    } else if ty == "float" && property.key == "GEO" {
+        let geo = property.value.split(';').map(|x| scalar(ty, x)).collect();
+        value.push(Value::Array(geo));
+    } else if crate::tree::is_multiple(property) {
+        value.extend(crate::tree::split(&property.value, ',').map(|x| scalar(ty, x)));
+    } else {
+        value.push(scalar(ty, &property.value));
+    }
+
+    Value::Array(value)
+}
+
+fn scalar(ty: &str, value: &str) -> Value {
+    match ty {
+        "boolean" => Value::Bool(value.eq_ignore_ascii_case("TRUE")),
+        "float" => value
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map_or_else(|| Value::String(value.to_string()), Value::Number),
+        "integer" => value
+            .parse::<i64>()
+            .map_or_else(|_| Value::String(value.to_string()), Value::from),
+        _ => Value::String(crate::tree::to_iso(ty, value)),
+    }
+}
+
+fn recur_to_value(value: &str) -> Value {
+    let recur = crate::tree::recur(value)
+        .into_iter()
+        .map(|(k, values)| {
+            let mut values = values
+                .into_iter()
+                .map(|x| {
+                    if is_numeric_part(&k) {
+                        x.parse::<i64>().map_or(Value::String(x), Value::from)
+                    } else {
+                        Value::String(x)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let value = if values.len() == 1 {
+                values.remove(0)
+            } else {
+                Value::Array(values)
+            };
+
+            (k, value)
+        })
+        .collect();
+
+    Value::Object(recur)
+}
+
+/**
+ * Whether a recurrence rule part is a number.
+ */
+fn is_numeric_part(part: &str) -> bool {
+    matches!(
+        part,
+        "count"
+            | "interval"
+            | "bysecond"
+            | "byminute"
+            | "byhour"
+            | "bymonthday"
+            | "byyearday"
+            | "byweekno"
+            | "bymonth"
+            | "bysetpos"
+    )
+}
+
+fn component_from_value(value: &Value) -> crate::Result<crate::tree::Component> {
+    let [name, properties, components] = array(value, "component")? else {
+        return Err(invalid("component", value));
+    };
+
+    let name = name
+        .as_str()
+        .ok_or_else(|| invalid("component name", name))?;
+
+    Ok(crate::tree::Component {
+        name: name.to_uppercase(),
+        properties: array(properties, "properties")?
+            .iter()
+            .map(property_from_value)
+            .collect::<crate::Result<_>>()?,
+        components: array(components, "components")?
+            .iter()
+            .map(component_from_value)
+            .collect::<crate::Result<_>>()?,
+    })
+}
+
+fn property_from_value(value: &Value) -> crate::Result<crate::ContentLine> {
+    let [name, params, ty, values @ ..] = array(value, "property")? else {
+        return Err(invalid("property", value));
+    };
+
+    let key = name
+        .as_str()
+        .ok_or_else(|| invalid("property name", name))?
+        .to_uppercase();
+    let ty = ty.as_str().ok_or_else(|| invalid("value type", ty))?;
+
+    let mut params = params
+        .as_object()
+        .ok_or_else(|| invalid("parameters", params))?
+        .iter()
+        .map(|(k, v)| {
+            let v = match v {
+                Value::String(s) => s.clone(),
+                Value::Array(values) => values
+                    .iter()
+                    .map(|x| x.as_str().map(ToString::to_string).unwrap_or(x.to_string()))
+                    .collect::<Vec<_>>()
+                    .join(","),
+                v => v.to_string(),
+            };
+
+            (k.to_uppercase(), v)
+        })
+        .collect::<std::collections::BTreeMap<_, _>>();
+
+    if ty != "unknown" && ty != crate::tree::default_type(&key) {
+        params.insert("VALUE".to_string(), ty.to_uppercase());
+    }
+
+    let value = match (ty, values) {
+        ("unknown", [Value::String(value)]) => value.clone(),
+        ("recur", [recur]) => recur_from_value(recur)?,
+        // Structured values of GEO and REQUEST-STATUS
+        (_, [Value::Array(values)]) => values
+            .iter()
+            .map(|x| scalar_from_value(ty, x))
+            .collect::<crate::Result<Vec<_>>>()?
+            .join(";"),
+        (_, values) => values
+            .iter()
+            .map(|x| scalar_from_value(ty, x))
+            .collect::<crate::Result<Vec<_>>>()?
+            .join(","),
+    };
+
+    Ok(crate::ContentLine { key, params, value })
+}
+
+fn scalar_from_value(ty: &str, value: &Value) -> crate::Result<String> {
+    let s = match value {
+        Value::String(s) => s.clone(),
+        Value::Bool(true) => "TRUE".to_string(),
+        Value::Bool(false) => "FALSE".to_string(),
+        Value::Number(n) => n.to_string(),
+        value => return Err(invalid("value", value)),
+    };
+
+    Ok(crate::tree::from_iso(ty, &s))
+}
+
+fn recur_from_value(value: &Value) -> crate::Result<String> {
+    let recur = value.as_object().ok_or_else(|| invalid("recur", value))?;
+
+    let parts = recur
+        .iter()
+        .map(|(k, v)| {
+            let values = match v {
+                Value::Array(values) => values.iter().collect(),
+                value => vec![value],
+            };
+
+            let values = values
+                .into_iter()
+                .map(|x| scalar_from_value("recur", x))
+                .collect::<crate::Result<_>>()?;
+
+            Ok((k.clone(), values))
+        })
+        .collect::<crate::Result<_>>()?;
+
+    Ok(crate::tree::join_recur(parts))
+}
+
+fn array<'a>(value: &'a Value, what: &str) -> crate::Result<&'a [Value]> {
+    value
+        .as_array()
+        .map(Vec::as_slice)
+        .ok_or_else(|| invalid(what, value))
+}
+
+fn invalid(what: &str, value: &Value) -> crate::Error {
+    crate::Error::Jcal(format!("invalid {what}: {value}"))
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn to_value() -> crate::Result {
+        let vcalendar = crate::vcalendar! {
+            version: "2.0",
+            prodid: "-//Example Inc.//Example Calendar//EN",
+            events: [
+                {
+                    uid: "4088E990AD89CB3DBB484909",
+                    dtstamp: "20080205T191224",
+                    dtstart: "20081006",
+                    summary: "Planning meeting; room 2",
+                    categories: ["Work", "Meeting"],
+                    sequence: 2,
+                    rrule: {
+                        freq: Weekly,
+                        count: 4,
+                    },
+                }
+            ],
+        }?;
+
+        let jcal = crate::jcal::to_value(&vcalendar)?;
+
+        similar_asserts::assert_eq!(
+            jcal,
+            serde_json::json!([
+                "vcalendar",
+                [
+                    ["prodid", {}, "text", "-//Example Inc.//Example Calendar//EN"],
+                    ["version", {}, "text", "2.0"],
+                ],
+                [
+                    [
+                        "vevent",
+                        [
+                            ["dtstamp", {}, "date-time", "2008-02-05T19:12:24"],
+                            ["uid", {}, "text", "4088E990AD89CB3DBB484909"],
+                            ["dtstart", {}, "date", "2008-10-06"],
+                            ["sequence", {}, "integer", 2],
+                            ["summary", {}, "text", "Planning meeting; room 2"],
+                            ["rrule", {}, "recur", {"freq": "WEEKLY", "count": 4}],
+                            ["categories", {}, "text", "Work", "Meeting"],
+                        ],
+                        [],
+                    ],
+                ],
+            ])
+        );
+
+        assert_eq!(crate::jcal::from_value(&jcal), Ok(vcalendar));
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() {
+        let tests = std::path::Path::new("tests");
+
+        for dir in tests.read_dir().unwrap() {
+            for file in dir.unwrap().path().read_dir().unwrap() {
+                let file = file.unwrap().path();
+
+                if file.extension() != Some(std::ffi::OsStr::new("ics")) {
+                    continue;
+                }
+
+                let input = std::fs::read_to_string(&file).unwrap();
+                let Ok(calendar) = crate::VCalendar::try_from(input) else {
+                    continue;
+                };
+
+                let jcal = crate::jcal::to_string(&calendar).unwrap();
+                let parsed = crate::jcal::from_str::<crate::VCalendar>(&jcal);
+
+                similar_asserts::assert_eq!(parsed, Ok(calendar), "{file:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            crate::jcal::from_str::<crate::VCalendar>("[\"vcalendar\", []]"),
+            Err(crate::Error::Jcal(_))
+        ));
+    }
+}
@@ -0,0 +1,1994 @@
+/*!
+ * See [RFC 8984](https://datatracker.ietf.org/doc/html/rfc8984) and the
+ * [iCalendar mapping](https://datatracker.ietf.org/doc/draft-ietf-calext-jscalendar-icalendar/).
+ *
+ * Properties without JSCalendar equivalent are reported in [`Conversion::unmapped`], using the
+ * iCalendar property name on export and the JSCalendar property name on import.
+ *
+ * ```
+ * let event = ikal::VEvent {
+ *     uid: "a8df6573-0474-496d-8496-033ad45d7fea".into(),
+ *     dtstamp: "20200102T182304Z".parse()?,
+ *     dtstart: "20200102T110000Z".parse()?,
+ *     duration: Some(ikal::Duration::hours(1)),
+ *     summary: Some("Some event".into()),
+ *
+ *     ..Default::default()
+ * };
+ *
+ * let conversion = ikal::jscalendar::event(&event, &[]);
+ * assert_eq!(conversion.value["start"], "2020-01-02T11:00:00");
+ * assert_eq!(conversion.value["timeZone"], "Etc/UTC");
+ *
+ * let vevents = ikal::jscalendar::vevents(&conversion.value)?;
+ * assert_eq!(vevents.value, vec![event]);
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+
+use serde_json::{Map, Value};
+
+/**
+ * Result of a conversion, with the properties that couldn't be mapped.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conversion<T> {
+    pub value: T,
+    pub unmapped: Vec<String>,
+}
+
+/**
+ * Converts an event and its overridden instances to a JSCalendar `Event`.
+ */
+#[must_use]
+pub fn event(event: &crate::VEvent, overrides: &[crate::VEvent]) -> Conversion<Value> {
+    export(event, overrides)
+}
+
+/**
+ * Converts a to-do and its overridden instances to a JSCalendar `Task`.
+ */
+#[must_use]
+pub fn task(todo: &crate::VTodo, overrides: &[crate::VTodo]) -> Conversion<Value> {
+    export(todo, overrides)
+}
+
+/**
+ * Converts a calendar to a JSCalendar `Group`, with its time zones.
+ */
+#[must_use]
+pub fn group(calendar: &crate::VCalendar) -> Conversion<Value> {
+    let mut unmapped = Vec::new();
+    let mut object = Map::new();
+
+    object.insert("@type".to_string(), "Group".into());
+    if let Some(uid) = &calendar.uid {
+        object.insert("uid".to_string(), uid.text.clone().into());
+    }
+    object.insert("prodId".to_string(), calendar.prodid.text.clone().into());
+    if let Some(last_modified) = &calendar.last_modified {
+        object.insert("updated".to_string(), utc(last_modified).into());
+    }
+    if let Some(name) = calendar.name.first() {
+        object.insert("title".to_string(), name.text.clone().into());
+    }
+    if let Some(description) = calendar.description.first() {
+        object.insert("description".to_string(), description.text.clone().into());
+    }
+    if let Some(color) = calendar.color {
+        object.insert("color".to_string(), color.to_string().into());
+    }
+    if !calendar.categories.is_empty() {
+        object.insert("keywords".to_string(), keywords(&calendar.categories));
+    }
+    if let Some(source) = &calendar.source {
+        object.insert("source".to_string(), source.uri.clone().into());
+    }
+
+    let mut entries = Vec::new();
+    entries.extend(group_entries(&calendar.events, &mut unmapped));
+    entries.extend(group_entries(&calendar.todo, &mut unmapped));
+    object.insert("entries".to_string(), entries.into());
+
+    if !calendar.timezones.is_empty() {
+        let timezones = calendar
+            .timezones
+            .iter()
+            .map(|x| (format!("/{}", x.tzid.text), timezone(x, &mut unmapped)))
+            .collect();
+        object.insert("timeZones".to_string(), Value::Object(timezones));
+    }
+
+    for (name, len) in [
+        ("CALSCALE", usize::from(calendar.calscale.is_some())),
+        ("METHOD", usize::from(calendar.method.is_some())),
+        ("URL", usize::from(calendar.url.is_some())),
+        (
+            "REFRESH-INTERVAL",
+            usize::from(calendar.refresh_interval.is_some()),
+        ),
+        ("NAME", calendar.name.len().saturating_sub(1)),
+        ("DESCRIPTION", calendar.description.len().saturating_sub(1)),
+        ("IMAGE", calendar.image.len()),
+        ("VALARM", calendar.alarms.len()),
+        ("VFREEBUSY", calendar.freebusy.len()),
+        ("VJOURNAL", calendar.journals.len()),
+    ] {
+        if len > 0 {
+            unmapped.push(name.to_string());
+        }
+    }
+    unmapped.extend(calendar.x_prop.keys().cloned());
+    unmapped.extend(calendar.iana_prop.keys().cloned());
+    unmapped.sort();
+    unmapped.dedup();
+
+    Conversion {
+        value: Value::Object(object),
+        unmapped,
+    }
+}
+
+/**
+ * Converts a JSCalendar `Event` to an event followed by its overridden instances.
+ */
+pub fn vevents(value: &Value) -> crate::Result<Conversion<Vec<crate::VEvent>>> {
+    import(value)
+}
+
+/**
+ * Converts a JSCalendar `Task` to a to-do followed by its overridden instances.
+ */
+pub fn vtodos(value: &Value) -> crate::Result<Conversion<Vec<crate::VTodo>>> {
+    import(value)
+}
+
+/**
+ * Converts a JSCalendar `Group` to a calendar.
+ */
+pub fn vcalendar(value: &Value) -> crate::Result<Conversion<crate::VCalendar>> {
+    let mut object = object(value, "Group")?;
+    let mut unmapped = Vec::new();
+
+    let mut calendar = crate::VCalendar {
+        prodid: take_str(&mut object, "prodId")?
+            .unwrap_or_else(|| "-//ikal//EN".to_string())
+            .into(),
+        version: "2.0".into(),
+        ..Default::default()
+    };
+
+    calendar.uid = take_str(&mut object, "uid")?.map(Into::into);
+    calendar.last_modified = take_str(&mut object, "updated")?
+        .map(|x| parse_utc(&x))
+        .transpose()?;
+    calendar.name = take_str(&mut object, "title")?
+        .map(Into::into)
+        .into_iter()
+        .collect();
+    calendar.description = take_str(&mut object, "description")?
+        .map(Into::into)
+        .into_iter()
+        .collect();
+    calendar.color = take_str(&mut object, "color")?
+        .map(|x| x.parse())
+        .transpose()?;
+    calendar.categories = take(&mut object, "keywords")
+        .map(|x| keys(&x, "keywords"))
+        .transpose()?
+        .unwrap_or_default()
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    calendar.source = take_str(&mut object, "source")?.map(Into::into);
+
+    if let Some(timezones) = take(&mut object, "timeZones") {
+        for timezone in as_object(&timezones, "timeZones")?.values() {
+            calendar.timezones.push(vtimezone(timezone, &mut unmapped)?);
+        }
+    }
+
+    if let Some(entries) = take(&mut object, "entries") {
+        let entries = entries
+            .as_array()
+            .ok_or_else(|| invalid("entries", &entries))?;
+
+        for (n, entry) in entries.iter().enumerate() {
+            match entry.get("@type").and_then(Value::as_str) {
+                Some("Event") => {
+                    let mut conversion = vevents(entry)?;
+                    calendar.events.append(&mut conversion.value);
+                    unmapped.append(&mut conversion.unmapped);
+                }
+                Some("Task") => {
+                    let mut conversion = vtodos(entry)?;
+                    calendar.todo.append(&mut conversion.value);
+                    unmapped.append(&mut conversion.unmapped);
+                }
+                _ => unmapped.push(format!("entries/{n}")),
+            }
+        }
+    }
+
+    unmapped.extend(object.into_iter().map(|(k, _)| k));
+
+    Ok(Conversion {
+        value: calendar,
+        unmapped,
+    })
+}
+
+/**
+ * Properties handled by the recurrence and not by overrides patches.
+ */
+const RECURRENCE: [&str; 5] = [
+    "@type",
+    "uid",
+    "recurrenceId",
+    "recurrenceRules",
+    "recurrenceOverrides",
+];
+
+fn export<T: Object>(master: &T, overrides: &[T]) -> Conversion<Value> {
+    let mut unmapped = Vec::new();
+    let mut object = master.to_object(&mut unmapped);
+    let time_zone = object.get("timeZone").cloned();
+
+    let mut recurrence_overrides = object
+        .remove("recurrenceOverrides")
+        .and_then(|x| x.as_object().cloned())
+        .unwrap_or_default();
+
+    for instance in overrides {
+        let Some(recurid) = instance.recurid() else {
+            continue;
+        };
+        let mut instance = instance.to_object(&mut unmapped);
+
+        // A time zone only implied by the instance start is still part of the patch
+        if instance.get("timeZone") == time_zone.as_ref() {
+            instance.remove("timeZone");
+        }
+
+        let mut patch = Map::new();
+        for (k, v) in &instance {
+            if !RECURRENCE.contains(&k.as_str()) && object.get(k) != Some(v) {
+                patch.insert(k.clone(), v.clone());
+            }
+        }
+        for k in object.keys() {
+            if !RECURRENCE.contains(&k.as_str()) && k != "timeZone" && !instance.contains_key(k) {
+                patch.insert(k.clone(), Value::Null);
+            }
+        }
+
+        recurrence_overrides.insert(local(recurid).0, Value::Object(patch));
+    }
+
+    if !recurrence_overrides.is_empty() {
+        object.insert(
+            "recurrenceOverrides".to_string(),
+            Value::Object(recurrence_overrides),
+        );
+    }
+
+    unmapped.sort();
+    unmapped.dedup();
+
+    Conversion {
+        value: Value::Object(object),
+        unmapped,
+    }
+}
+
+fn import<T: Object>(value: &Value) -> crate::Result<Conversion<Vec<T>>> {
+    let mut master = object(value, T::TYPE)?;
+    let mut unmapped = Vec::new();
+
+    let overrides = take(&mut master, "recurrenceOverrides")
+        .map(|x| as_object(&x, "recurrenceOverrides").cloned())
+        .transpose()?
+        .unwrap_or_default();
+
+    let mut instances = Vec::new();
+    let mut exdate = Vec::new();
+    let mut rdate = Vec::new();
+
+    for (recurid, patch) in overrides {
+        let patch = as_object(&patch, "recurrenceOverrides")?;
+
+        if patch.get("excluded") == Some(&Value::Bool(true)) {
+            exdate.push(recurid);
+        } else if patch.is_empty() {
+            rdate.push(recurid);
+        } else {
+            let mut instance = master.clone();
+            instance.remove("recurrenceRules");
+
+            for (path, value) in patch {
+                apply(&mut instance, path, value.clone());
+            }
+            instance.insert("recurrenceId".to_string(), recurid.into());
+
+            instances.push(instance);
+        }
+    }
+
+    if !exdate.is_empty() {
+        master.insert("excluded".to_string(), exdate.into());
+    }
+    if !rdate.is_empty() {
+        master.insert("included".to_string(), rdate.into());
+    }
+
+    let mut components = vec![T::from_object(master, &mut unmapped)?];
+    for instance in instances {
+        components.push(T::from_object(instance, &mut unmapped)?);
+    }
+
+    unmapped.sort();
+    unmapped.dedup();
+
+    Ok(Conversion {
+        value: components,
+        unmapped,
+    })
+}
+
+fn group_entries<T: Object>(components: &[T], unmapped: &mut Vec<String>) -> Vec<Value> {
+    let mut entries = Vec::new();
+
+    for component in components {
+        let overrides = components
+            .iter()
+            .filter(|x| x.uid() == component.uid() && x.recurid().is_some())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let conversion = if component.recurid().is_none() {
+            export(component, &overrides)
+        } else if components
+            .iter()
+            .any(|x| x.uid() == component.uid() && x.recurid().is_none())
+        {
+            continue;
+        } else {
+            export(component, &[])
+        };
+
+        entries.push(conversion.value);
+        unmapped.extend(conversion.unmapped);
+    }
+
+    entries
+}
+
+/**
+ * Applies a patch of a recurrence override, the path is a JSON pointer.
+ */
+fn apply(object: &mut Map<String, Value>, path: &str, value: Value) {
+    let mut keys = path.split('/').collect::<Vec<_>>();
+    let Some(last) = keys.pop() else {
+        return;
+    };
+
+    let mut object = object;
+    for key in keys {
+        let entry = object
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        if !entry.is_object() {
+            *entry = Value::Object(Map::new());
+        }
+
+        object = entry.as_object_mut().unwrap();
+    }
+
+    if value.is_null() {
+        object.remove(last);
+    } else {
+        object.insert(last.to_string(), value);
+    }
+}
+
+trait Object: Clone + Sized {
+    const TYPE: &'static str;
+
+    fn uid(&self) -> &crate::Text;
+
+    fn recurid(&self) -> Option<&crate::Date>;
+
+    fn to_object(&self, unmapped: &mut Vec<String>) -> Map<String, Value>;
+
+    fn from_object(object: Map<String, Value>, unmapped: &mut Vec<String>) -> crate::Result<Self>;
+}
+
+macro_rules! object {
+    ($ty:ty, $name:literal, $to:ident, $from:ident) => {
+        impl Object for $ty {
+            const TYPE: &'static str = $name;
+
+            fn uid(&self) -> &crate::Text {
+                &self.uid
+            }
+
+            fn recurid(&self) -> Option<&crate::Date> {
+                self.recurid.as_ref()
+            }
+
+            fn to_object(&self, unmapped: &mut Vec<String>) -> Map<String, Value> {
+                let mut object = Map::new();
+
+                object.insert("@type".to_string(), $name.into());
+                object.insert("uid".to_string(), self.uid.text.clone().into());
+                object.insert("updated".to_string(), utc(&self.dtstamp).into());
+                if let Some(created) = &self.created {
+                    object.insert("created".to_string(), utc(created).into());
+                }
+                if let Some(sequence) = self.sequence {
+                    object.insert("sequence".to_string(), sequence.into());
+                }
+                if let Some(summary) = &self.summary {
+                    object.insert("title".to_string(), summary.text.clone().into());
+                }
+
+                $to(self, &mut object, unmapped);
+
+                if let Some(recurid) = &self.recurid {
+                    object.insert("recurrenceId".to_string(), local(recurid).0.into());
+                }
+                if let Some(class) = &self.class {
+                    match class {
+                        crate::Class::Public => {
+                            object.insert("privacy".to_string(), "public".into());
+                        }
+                        crate::Class::Private => {
+                            object.insert("privacy".to_string(), "private".into());
+                        }
+                        crate::Class::Confidential => {
+                            object.insert("privacy".to_string(), "secret".into());
+                        }
+                        crate::Class::Custom(_) => unmapped.push("CLASS".to_string()),
+                    }
+                }
+                if let Some(priority) = self.priority {
+                    object.insert("priority".to_string(), priority.into());
+                }
+                if let Some(color) = self.color {
+                    object.insert("color".to_string(), color.to_string().into());
+                }
+                if !self.categories.is_empty() {
+                    object.insert("keywords".to_string(), keywords(&self.categories));
+                }
+                if self.location.is_some() || self.geo.is_some() {
+                    let mut location = Map::new();
+                    location.insert("@type".to_string(), "Location".into());
+                    if let Some(name) = &self.location {
+                        location.insert("name".to_string(), name.text.clone().into());
+                    }
+                    if let Some(geo) = &self.geo {
+                        location.insert(
+                            "coordinates".to_string(),
+                            format!("geo:{},{}", geo.lat, geo.lon).into(),
+                        );
+                    }
+                    object.insert(
+                        "locations".to_string(),
+                        serde_json::json!({ "1": location }),
+                    );
+                }
+                if !self.conference.is_empty() {
+                    object.insert(
+                        "virtualLocations".to_string(),
+                        virtual_locations(&self.conference, unmapped),
+                    );
+                }
+                if let Some(url) = &self.url {
+                    object.insert(
+                        "links".to_string(),
+                        serde_json::json!({ "1": { "@type": "Link", "href": url.uri } }),
+                    );
+                }
+                if !self.related_to.is_empty() {
+                    object.insert("relatedTo".to_string(), related_to(&self.related_to));
+                }
+                if let Some(rrule) = &self.rrule {
+                    object.insert(
+                        "recurrenceRules".to_string(),
+                        vec![recurrence_rule(rrule)].into(),
+                    );
+                }
+
+                let mut overrides = Map::new();
+                for exdate in &self.exdate {
+                    overrides.insert(local(exdate).0, serde_json::json!({ "excluded": true }));
+                }
+                for rdate in &self.rdate {
+                    rdate_overrides(rdate, &mut overrides);
+                }
+                if !overrides.is_empty() {
+                    object.insert("recurrenceOverrides".to_string(), Value::Object(overrides));
+                }
+
+                if self.organizer.is_some() || !self.attendee.is_empty() {
+                    participants(
+                        self.organizer.as_ref(),
+                        &self.attendee,
+                        &mut object,
+                        unmapped,
+                    );
+                }
+                if !self.alarms.is_empty() {
+                    object.insert(
+                        "alerts".to_string(),
+                        alerts(&self.alarms, self.summary.as_ref(), unmapped),
+                    );
+                }
+
+                for (name, len) in [
+                    ("LAST-MODIFIED", usize::from(self.last_modified.is_some())),
+                    ("ATTACH", self.attach.len()),
+                    ("COMMENT", self.comment.len()),
+                    ("CONTACT", self.contact.len()),
+                    ("REQUEST-STATUS", self.rstatus.len()),
+                    ("RESOURCES", self.resources.len()),
+                    ("IMAGE", self.image.len()),
+                ] {
+                    if len > 0 {
+                        unmapped.push(name.to_string());
+                    }
+                }
+                unmapped.extend(self.x_prop.keys().cloned());
+                unmapped.extend(self.iana_prop.keys().cloned());
+
+                object
+            }
+
+            fn from_object(
+                mut object: Map<String, Value>,
+                unmapped: &mut Vec<String>,
+            ) -> crate::Result<Self> {
+                let mut component = Self::default();
+
+                object.remove("@type");
+                let zone = zone(&mut object, unmapped)?;
+
+                component.uid = take_str(&mut object, "uid")?
+                    .ok_or_else(|| crate::Error::Jscalendar("missing uid".to_string()))?
+                    .into();
+                component.dtstamp = take_str(&mut object, "updated")?
+                    .map(|x| parse_utc(&x))
+                    .transpose()?
+                    .unwrap_or_default();
+                component.created = take_str(&mut object, "created")?
+                    .map(|x| parse_utc(&x))
+                    .transpose()?;
+                component.sequence = take_u64(&mut object, "sequence")?
+                    .map(u32::try_from)
+                    .transpose()
+                    .map_err(|err| crate::Error::Jscalendar(err.to_string()))?;
+                component.summary = take_str(&mut object, "title")?.map(Into::into);
+                component.recurid = take_str(&mut object, "recurrenceId")?
+                    .map(|x| zone.parse(&x))
+                    .transpose()?;
+
+                $from(&mut component, &mut object, &zone, unmapped)?;
+
+                component.class = match take_str(&mut object, "privacy")?.as_deref() {
+                    Some("public") => Some(crate::Class::Public),
+                    Some("private") => Some(crate::Class::Private),
+                    Some("secret") => Some(crate::Class::Confidential),
+                    Some(_) => {
+                        unmapped.push("privacy".to_string());
+                        None
+                    }
+                    None => None,
+                };
+                component.priority = take_u64(&mut object, "priority")?
+                    .map(u8::try_from)
+                    .transpose()
+                    .map_err(|err| crate::Error::Jscalendar(err.to_string()))?;
+                component.color = take_str(&mut object, "color")?
+                    .map(|x| x.parse())
+                    .transpose()?;
+                component.categories = take(&mut object, "keywords")
+                    .map(|x| keys(&x, "keywords"))
+                    .transpose()?
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect();
+                if let Some(locations) = take(&mut object, "locations") {
+                    let (location, geo) = locations_from(&locations, unmapped)?;
+                    component.location = location;
+                    component.geo = geo;
+                }
+                if let Some(virtual_locations) = take(&mut object, "virtualLocations") {
+                    component.conference = conferences(&virtual_locations)?;
+                }
+                if let Some(links) = take(&mut object, "links") {
+                    component.url = url(&links, unmapped)?;
+                }
+                if let Some(related_to) = take(&mut object, "relatedTo") {
+                    component.related_to = related_to_from(&related_to)?;
+                }
+                if let Some(rules) = take(&mut object, "recurrenceRules") {
+                    let rules = rules
+                        .as_array()
+                        .ok_or_else(|| invalid("recurrenceRules", &rules))?;
+
+                    if rules.len() > 1 {
+                        unmapped.push("recurrenceRules".to_string());
+                    }
+                    component.rrule = rules
+                        .first()
+                        .map(|x| recur(x, &zone))
+                        .transpose()?;
+                }
+                if let Some(excluded) = take(&mut object, "excluded") {
+                    component.exdate = dates(&excluded, &zone)?;
+                }
+                if let Some(included) = take(&mut object, "included") {
+                    component.rdate = vec![crate::RDate::Date(dates(&included, &zone)?)];
+                }
+                if let Some(participants) = take(&mut object, "participants") {
+                    let reply_to = take(&mut object, "replyTo");
+                    let (organizer, attendee) =
+                        participants_from(&participants, reply_to.as_ref(), unmapped)?;
+                    component.organizer = organizer;
+                    component.attendee = attendee;
+                } else if let Some(reply_to) = take(&mut object, "replyTo") {
+                    component.organizer = send_to(&reply_to).map(Into::into);
+                }
+                if let Some(alerts) = take(&mut object, "alerts") {
+                    component.alarms = alarms(&alerts, component.summary.as_ref())?;
+                }
+
+                unmapped.extend(object.into_iter().map(|(k, _)| k));
+
+                Ok(component)
+            }
+        }
+    };
+}
+
+object!(crate::VEvent, "Event", event_to_object, event_from_object);
+object!(crate::VTodo, "Task", task_to_object, task_from_object);
+
+fn event_to_object(
+    event: &crate::VEvent,
+    object: &mut Map<String, Value>,
+    unmapped: &mut Vec<String>,
+) {
+    start(&event.dtstart, object);
+
+    if let Some(description) = &event.description {
+        object.insert("description".to_string(), description.text.clone().into());
+    }
+
+    let duration = event.duration.or_else(|| {
+        event.dtend.as_ref().map(|x| match (&event.dtstart, x) {
+            (crate::Date::Date(start), crate::Date::Date(end)) => {
+                crate::Duration::days((*end - *start).num_days().try_into().unwrap_or_default())
+            }
+            _ => (crate::DateTime::from(x.clone()) - crate::DateTime::from(event.dtstart.clone()))
+                .into(),
+        })
+    });
+    if let Some(duration) = duration {
+        object.insert("duration".to_string(), duration.to_string().into());
+    }
+
+    match &event.status {
+        Some(crate::Status::Tentative) => {
+            object.insert("status".to_string(), "tentative".into());
+        }
+        Some(crate::Status::Confirmed) => {
+            object.insert("status".to_string(), "confirmed".into());
+        }
+        Some(crate::Status::Cancelled) => {
+            object.insert("status".to_string(), "cancelled".into());
+        }
+        Some(_) => unmapped.push("STATUS".to_string()),
+        None => (),
+    }
+
+    match &event.transp {
+        Some(crate::TimeTransparency::Opaque) => {
+            object.insert("freeBusyStatus".to_string(), "busy".into());
+        }
+        Some(crate::TimeTransparency::Transparent) => {
+            object.insert("freeBusyStatus".to_string(), "free".into());
+        }
+        None => (),
+    }
+}
+
+fn event_from_object(
+    event: &mut crate::VEvent,
+    object: &mut Map<String, Value>,
+    zone: &Zone,
+    unmapped: &mut Vec<String>,
+) -> crate::Result {
+    event.dtstart = take_str(object, "start")?
+        .map(|x| zone.parse(&x))
+        .transpose()?
+        .ok_or_else(|| crate::Error::Jscalendar("missing start".to_string()))?;
+    event.description = take_str(object, "description")?.map(Into::into);
+    event.duration = take_str(object, "duration")?
+        .map(|x| x.parse())
+        .transpose()?;
+
+    event.status = match take_str(object, "status")?.as_deref() {
+        Some("tentative") => Some(crate::Status::Tentative),
+        Some("confirmed") => Some(crate::Status::Confirmed),
+        Some("cancelled") => Some(crate::Status::Cancelled),
+        Some(_) => {
+            unmapped.push("status".to_string());
+            None
+        }
+        None => None,
+    };
+
+    event.transp = match take_str(object, "freeBusyStatus")?.as_deref() {
+        Some("busy") => Some(crate::TimeTransparency::Opaque),
+        Some("free") => Some(crate::TimeTransparency::Transparent),
+        Some(_) => {
+            unmapped.push("freeBusyStatus".to_string());
+            None
+        }
+        None => None,
+    };
+
+    Ok(())
+}
+
+fn task_to_object(
+    todo: &crate::VTodo,
+    object: &mut Map<String, Value>,
+    unmapped: &mut Vec<String>,
+) {
+    if let Some(dtstart) = &todo.dtstart {
+        start(dtstart, object);
+    }
+
+    if let Some(due) = &todo.due {
+        let (due, time_zone) = local(due);
+        object.insert("due".to_string(), due.into());
+        if let Some(time_zone) = time_zone {
+            object.insert("timeZone".to_string(), time_zone.into());
+        }
+    }
+
+    if let Some(duration) = todo.duration {
+        object.insert("estimatedDuration".to_string(), duration.to_string().into());
+    }
+
+    if let Some(percent_complete) = todo.percent_complete {
+        object.insert("percentComplete".to_string(), percent_complete.into());
+    }
+
+    let progress = match &todo.status {
+        Some(crate::Status::NeedsAction) => Some("needs-action"),
+        Some(crate::Status::InProcess) => Some("in-process"),
+        Some(crate::Status::Completed) => Some("completed"),
+        Some(crate::Status::Cancelled) => Some("cancelled"),
+        Some(_) => {
+            unmapped.push("STATUS".to_string());
+            None
+        }
+        None => None,
+    };
+    if let Some(progress) = progress {
+        object.insert("progress".to_string(), progress.into());
+    }
+
+    if let Some(completed) = &todo.completed {
+        object.insert("progressUpdated".to_string(), utc(completed).into());
+    }
+}
+
+fn task_from_object(
+    todo: &mut crate::VTodo,
+    object: &mut Map<String, Value>,
+    zone: &Zone,
+    unmapped: &mut Vec<String>,
+) -> crate::Result {
+    todo.dtstart = take_str(object, "start")?
+        .map(|x| zone.parse(&x))
+        .transpose()?;
+    todo.due = take_str(object, "due")?
+        .map(|x| zone.parse(&x))
+        .transpose()?;
+    todo.duration = take_str(object, "estimatedDuration")?
+        .map(|x| x.parse())
+        .transpose()?;
+    todo.percent_complete = take_u64(object, "percentComplete")?
+        .map(u8::try_from)
+        .transpose()
+        .map_err(|err| crate::Error::Jscalendar(err.to_string()))?;
+
+    todo.status = match take_str(object, "progress")?.as_deref() {
+        Some("needs-action") => Some(crate::Status::NeedsAction),
+        Some("in-process") => Some(crate::Status::InProcess),
+        Some("completed") => Some(crate::Status::Completed),
+        Some("cancelled") => Some(crate::Status::Cancelled),
+        Some(_) => {
+            unmapped.push("progress".to_string());
+            None
+        }
+        None => None,
+    };
+
+    todo.completed = take_str(object, "progressUpdated")?
+        .map(|x| parse_utc(&x))
+        .transpose()?;
+
+    Ok(())
+}
+
+fn start(dtstart: &crate::Date, object: &mut Map<String, Value>) {
+    let (start, time_zone) = local(dtstart);
+
+    object.insert("start".to_string(), start.into());
+    if let Some(time_zone) = time_zone {
+        object.insert("timeZone".to_string(), time_zone.into());
+    }
+    if !dtstart.has_time() {
+        object.insert("showWithoutTime".to_string(), true.into());
+    }
+}
+
+/**
+ * Time zone of the local date-times of an object.
+ */
+struct Zone {
+    utc: bool,
+    tzid: Option<String>,
+    show_without_time: bool,
+}
+
+fn zone(object: &mut Map<String, Value>, unmapped: &mut Vec<String>) -> crate::Result<Zone> {
+    let (utc, tzid) = match take(object, "timeZone") {
+        None | Some(Value::Null) => (false, None),
+        Some(Value::String(time_zone)) if time_zone == "Etc/UTC" || time_zone == "UTC" => {
+            (true, None)
+        }
+        Some(Value::String(time_zone)) => (false, Some(time_zone)),
+        Some(_) => {
+            // Dates with an invalid time zone become floating
+            unmapped.push("timeZone".to_string());
+            (false, None)
+        }
+    };
+
+    let show_without_time = match take(object, "showWithoutTime") {
+        None => false,
+        Some(value) => value
+            .as_bool()
+            .ok_or_else(|| invalid("showWithoutTime", &value))?,
+    };
+
+    Ok(Zone {
+        utc,
+        tzid,
+        show_without_time,
+    })
+}
+
+impl Zone {
+    fn parse(&self, value: &str) -> crate::Result<crate::Date> {
+        let naive = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")?;
+
+        let date = if self.show_without_time && naive.time() == chrono::NaiveTime::MIN {
+            crate::Date::Date(naive.date())
+        } else if self.utc {
+            crate::DateTime::Local(naive.and_utc().with_timezone(&chrono::Local)).into()
+        } else if let Some(tzid) = &self.tzid {
+            crate::DateTime::Zoned(naive, tzid.as_str().into()).into()
+        } else {
+            crate::DateTime::Naive(naive).into()
+        };
+
+        Ok(date)
+    }
+}
+
+/**
+ * Returns the `LocalDateTime` of a date and its time zone.
+ */
+fn local(date: &crate::Date) -> (String, Option<String>) {
+    const FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+    match date {
+        crate::Date::Date(date) => (date.format("%Y-%m-%dT00:00:00").to_string(), None),
+        crate::Date::DateTime(crate::DateTime::Naive(naive)) => {
+            (naive.format(FORMAT).to_string(), None)
+        }
+        crate::Date::DateTime(crate::DateTime::Local(local)) => (
+            local.to_utc().format(FORMAT).to_string(),
+            Some("Etc/UTC".to_string()),
+        ),
+        crate::Date::DateTime(crate::DateTime::Zoned(naive, tzid)) => {
+            (naive.format(FORMAT).to_string(), Some(tzid.to_string()))
+        }
+    }
+}
+
+/**
+ * Returns the `UTCDateTime` of a date-time, floating date-times are considered in UTC.
+ */
+fn utc(date_time: &crate::DateTime) -> String {
+    const FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+    match date_time {
+        crate::DateTime::Naive(naive) | crate::DateTime::Zoned(naive, _) => {
+            naive.format(FORMAT).to_string()
+        }
+        crate::DateTime::Local(local) => local.to_utc().format(FORMAT).to_string(),
+    }
+}
+
+fn parse_utc(value: &str) -> crate::Result<crate::DateTime> {
+    let naive = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%SZ")?;
+
+    Ok(crate::DateTime::Local(
+        naive.and_utc().with_timezone(&chrono::Local),
+    ))
+}
+
+fn dates(value: &Value, zone: &Zone) -> crate::Result<Vec<crate::Date>> {
+    value
+        .as_array()
+        .ok_or_else(|| invalid("recurrenceOverrides", value))?
+        .iter()
+        .map(|x| {
+            x.as_str()
+                .ok_or_else(|| invalid("recurrenceOverrides", x))
+                .and_then(|x| zone.parse(x))
+        })
+        .collect()
+}
+
+fn rdate_overrides(rdate: &crate::RDate, overrides: &mut Map<String, Value>) {
+    match rdate {
+        crate::RDate::Date(dates) => {
+            for date in dates {
+                overrides.insert(local(date).0, Value::Object(Map::new()));
+            }
+        }
+        crate::RDate::Period(periods) => {
+            for period in periods {
+                let start = match period {
+                    crate::Period::StartEnd(x) => x.start.clone(),
+                    crate::Period::StartDur(x) => x.start.clone(),
+                };
+
+                overrides.insert(
+                    local(&start.into()).0,
+                    serde_json::json!({ "duration": crate::Duration::from(period.duration()).to_string() }),
+                );
+            }
+        }
+    }
+}
+
+fn keywords(categories: &[crate::Text]) -> Value {
+    categories
+        .iter()
+        .map(|x| (x.text.clone(), Value::Bool(true)))
+        .collect::<Map<_, _>>()
+        .into()
+}
+
+/**
+ * Returns the keys of a `String[Boolean]` set.
+ */
+fn keys(value: &Value, what: &str) -> crate::Result<Vec<String>> {
+    Ok(as_object(value, what)?
+        .iter()
+        .filter(|(_, v)| v.as_bool() == Some(true))
+        .map(|(k, _)| k.clone())
+        .collect())
+}
+
+fn locations_from(
+    value: &Value,
+    unmapped: &mut Vec<String>,
+) -> crate::Result<(Option<crate::Text>, Option<crate::Geo>)> {
+    let locations = as_object(value, "locations")?;
+
+    if locations.len() > 1 {
+        unmapped.push("locations".to_string());
+    }
+
+    let Some(location) = locations.values().next() else {
+        return Ok((None, None));
+    };
+
+    let name = location.get("name").and_then(Value::as_str).map(Into::into);
+
+    let geo = location
+        .get("coordinates")
+        .and_then(Value::as_str)
+        .and_then(|x| x.strip_prefix("geo:"))
+        .map(|x| {
+            let (lat, lon) = x
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .split_once(',')
+                .ok_or_else(|| crate::Error::Jscalendar(format!("invalid coordinates: {x}")))?;
+
+            let parse = |x: &str| {
+                x.parse::<f32>()
+                    .map_err(|err| crate::Error::Jscalendar(err.to_string()))
+            };
+
+            Ok::<_, crate::Error>(crate::Geo {
+                lat: parse(lat)?,
+                lon: parse(lon)?,
+            })
+        })
+        .transpose()?;
+
+    Ok((name, geo))
+}
+
+fn virtual_locations(conferences: &[crate::Conference], unmapped: &mut Vec<String>) -> Value {
+    let mut locations = Map::new();
+
+    for (n, conference) in conferences.iter().enumerate() {
+        let mut location = Map::new();
+
+        location.insert("@type".to_string(), "VirtualLocation".into());
+        location.insert("uri".to_string(), conference.uri.clone().into());
+        if let Some(label) = &conference.label {
+            location.insert("name".to_string(), label.clone().into());
+        }
+        if !conference.feature.is_empty() {
+            let features = conference
+                .feature
+                .iter()
+                .map(|x| (x.to_string().to_lowercase(), Value::Bool(true)))
+                .collect::<Map<_, _>>();
+            location.insert("features".to_string(), features.into());
+        }
+        if conference.params.keys().any(|x| x != "VALUE") {
+            unmapped.push("CONFERENCE".to_string());
+        }
+
+        locations.insert((n + 1).to_string(), location.into());
+    }
+
+    locations.into()
+}
+
+fn conferences(value: &Value) -> crate::Result<Vec<crate::Conference>> {
+    as_object(value, "virtualLocations")?
+        .values()
+        .map(|location| {
+            let uri = location
+                .get("uri")
+                .and_then(Value::as_str)
+                .ok_or_else(|| invalid("virtualLocations", location))?;
+
+            let feature = location
+                .get("features")
+                .map(|x| keys(x, "features"))
+                .transpose()?
+                .unwrap_or_default()
+                .into_iter()
+                .map(|x| x.to_uppercase().parse())
+                .collect::<crate::Result<_>>()?;
+
+            Ok(crate::Conference {
+                params: [("VALUE".to_string(), "URI".to_string())].into(),
+                feature,
+                label: location
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .map(ToString::to_string),
+                uri: uri.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn url(value: &Value, unmapped: &mut Vec<String>) -> crate::Result<Option<crate::Uri>> {
+    let links = as_object(value, "links")?;
+
+    if links.len() > 1 {
+        unmapped.push("links".to_string());
+    }
+
+    Ok(links
+        .values()
+        .next()
+        .and_then(|x| x.get("href"))
+        .and_then(Value::as_str)
+        .map(Into::into))
+}
+
+fn related_to(related_to: &[crate::Text]) -> Value {
+    related_to
+        .iter()
+        .map(|x| {
+            let mut relation = Map::new();
+            if let Some(reltype) = x.params.get("RELTYPE") {
+                relation.insert(reltype.to_lowercase(), Value::Bool(true));
+            }
+
+            let value = serde_json::json!({ "@type": "Relation", "relation": relation });
+
+            (x.text.clone(), value)
+        })
+        .collect::<Map<_, _>>()
+        .into()
+}
+
+fn related_to_from(value: &Value) -> crate::Result<Vec<crate::Text>> {
+    as_object(value, "relatedTo")?
+        .iter()
+        .map(|(uid, relation)| {
+            let mut text = crate::Text::from(uid.as_str());
+
+            let reltypes = relation
+                .get("relation")
+                .map(|x| keys(x, "relation"))
+                .transpose()?
+                .unwrap_or_default();
+            if let Some(reltype) = reltypes.first() {
+                text.params
+                    .insert("RELTYPE".to_string(), reltype.to_uppercase());
+            }
+
+            Ok(text)
+        })
+        .collect()
+}
+
+fn recurrence_rule(recur: &crate::Recur) -> Value {
+    let mut rule = Map::new();
+
+    rule.insert("@type".to_string(), "RecurrenceRule".into());
+    rule.insert(
+        "frequency".to_string(),
+        recur.freq.to_string().to_lowercase().into(),
+    );
+    if recur.interval != 1 {
+        rule.insert("interval".to_string(), recur.interval.into());
+    }
+    if let Some(wkst) = &recur.wkst {
+        rule.insert(
+            "firstDayOfWeek".to_string(),
+            wkst.to_string().to_lowercase().into(),
+        );
+    }
+    if !recur.by_day.is_empty() {
+        let by_day = recur
+            .by_day
+            .iter()
+            .map(|x| {
+                let mut day = Map::new();
+                day.insert("@type".to_string(), "NDay".into());
+                day.insert(
+                    "day".to_string(),
+                    x.weekday.to_string().to_lowercase().into(),
+                );
+                if let Some(ord) = x.ord {
+                    day.insert("nthOfPeriod".to_string(), ord.into());
+                }
+
+                Value::Object(day)
+            })
+            .collect::<Vec<_>>();
+        rule.insert("byDay".to_string(), by_day.into());
+    }
+    if !recur.by_month.is_empty() {
+        let by_month = recur
+            .by_month
+            .iter()
+            .map(|x| Value::String(x.to_string()))
+            .collect::<Vec<_>>();
+        rule.insert("byMonth".to_string(), by_month.into());
+    }
+    for (name, values) in [
+        ("byMonthDay", &recur.by_monthday),
+        ("byYearDay", &recur.by_yearday),
+        ("byWeekNo", &recur.by_weekno),
+        ("byHour", &recur.by_hour),
+        ("byMinute", &recur.by_minute),
+        ("bySecond", &recur.by_second),
+        ("bySetPosition", &recur.by_setpos),
+    ] {
+        if !values.is_empty() {
+            rule.insert(name.to_string(), values.clone().into());
+        }
+    }
+    if let Some(count) = recur.count {
+        rule.insert("count".to_string(), count.into());
+    }
+    if let Some(until) = &recur.until {
+        rule.insert("until".to_string(), local(until).0.into());
+    }
+
+    Value::Object(rule)
+}
+
+fn recur(value: &Value, zone: &Zone) -> crate::Result<crate::Recur> {
+    let rule = as_object(value, "recurrenceRules")?;
+    let mut recur = crate::Recur::default();
+
+    let int = |value: &Value| -> crate::Result<i8> {
+        value
+            .as_i64()
+            .and_then(|x| i8::try_from(x).ok())
+            .ok_or_else(|| invalid("recurrenceRules", value))
+    };
+    let ints = |name: &str| -> crate::Result<Vec<i8>> {
+        rule.get(name)
+            .and_then(Value::as_array)
+            .map(|x| x.iter().map(int).collect())
+            .unwrap_or(Ok(Vec::new()))
+    };
+
+    recur.freq = rule
+        .get("frequency")
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid("recurrenceRules", value))?
+        .to_uppercase()
+        .parse()?;
+    if let Some(interval) = rule.get("interval") {
+        recur.interval = int(interval)?
+            .try_into()
+            .map_err(|err: std::num::TryFromIntError| crate::Error::Jscalendar(err.to_string()))?;
+    }
+    recur.wkst = rule
+        .get("firstDayOfWeek")
+        .and_then(Value::as_str)
+        .map(|x| x.to_uppercase().parse())
+        .transpose()?;
+    if let Some(by_day) = rule.get("byDay").and_then(Value::as_array) {
+        recur.by_day = by_day
+            .iter()
+            .map(|x| {
+                let weekday = x
+                    .get("day")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| invalid("byDay", x))?
+                    .to_uppercase()
+                    .parse()?;
+                let ord = x.get("nthOfPeriod").map(int).transpose()?;
+
+                Ok(crate::WeekdayNum { weekday, ord })
+            })
+            .collect::<crate::Result<_>>()?;
+    }
+    if let Some(by_month) = rule.get("byMonth").and_then(Value::as_array) {
+        recur.by_month = by_month
+            .iter()
+            .map(|x| {
+                x.as_str()
+                    .and_then(|x| x.parse().ok())
+                    .ok_or_else(|| invalid("byMonth", x))
+            })
+            .collect::<crate::Result<_>>()?;
+    }
+    recur.by_monthday = ints("byMonthDay")?;
+    recur.by_yearday = ints("byYearDay")?;
+    recur.by_weekno = ints("byWeekNo")?;
+    recur.by_hour = ints("byHour")?;
+    recur.by_minute = ints("byMinute")?;
+    recur.by_second = ints("bySecond")?;
+    recur.by_setpos = ints("bySetPosition")?;
+    recur.count = rule
+        .get("count")
+        .map(|x| {
+            x.as_u64()
+                .and_then(|x| u8::try_from(x).ok())
+                .ok_or_else(|| invalid("count", x))
+        })
+        .transpose()?;
+    recur.until = rule
+        .get("until")
+        .and_then(Value::as_str)
+        .map(|x| zone.parse(x))
+        .transpose()?;
+
+    Ok(recur)
+}
+
+fn participants(
+    organizer: Option<&crate::Uri>,
+    attendees: &[crate::Uri],
+    object: &mut Map<String, Value>,
+    unmapped: &mut Vec<String>,
+) {
+    let mut participants = Vec::<(&str, Map<String, Value>)>::new();
+
+    if let Some(organizer) = organizer {
+        let mut participant = participant(organizer, "ORGANIZER", unmapped);
+        participant.insert("roles".to_string(), serde_json::json!({ "owner": true }));
+        participants.push((&organizer.uri, participant));
+
+        object.insert(
+            "replyTo".to_string(),
+            serde_json::json!({ method(&organizer.uri): organizer.uri }),
+        );
+    }
+
+    for attendee in attendees {
+        let mut roles = Map::new();
+        match attendee.params.get("ROLE").map(String::as_str) {
+            Some("CHAIR") => {
+                roles.insert("attendee".to_string(), true.into());
+                roles.insert("chair".to_string(), true.into());
+            }
+            Some("OPT-PARTICIPANT") => {
+                roles.insert("attendee".to_string(), true.into());
+                roles.insert("optional".to_string(), true.into());
+            }
+            Some("NON-PARTICIPANT") => {
+                roles.insert("informational".to_string(), true.into());
+            }
+            _ => {
+                roles.insert("attendee".to_string(), true.into());
+            }
+        }
+
+        let mut participant = participant(attendee, "ATTENDEE", unmapped);
+        if let Some(partstat) = attendee.params.get("PARTSTAT") {
+            participant.insert(
+                "participationStatus".to_string(),
+                partstat.to_lowercase().into(),
+            );
+        }
+        if attendee.params.get("RSVP").map(String::as_str) == Some("TRUE") {
+            participant.insert("expectReply".to_string(), true.into());
+        }
+
+        if let Some((_, existing)) = participants
+            .iter_mut()
+            .find(|(uri, _)| uri.eq_ignore_ascii_case(&attendee.uri))
+        {
+            let Some(Value::Object(existing_roles)) = existing.get_mut("roles") else {
+                unreachable!();
+            };
+            existing_roles.append(&mut roles);
+            participant.remove("roles");
+            existing.append(&mut participant);
+        } else {
+            participant.insert("roles".to_string(), roles.into());
+            participants.push((&attendee.uri, participant));
+        }
+    }
+
+    let participants = participants
+        .into_iter()
+        .enumerate()
+        .map(|(n, (_, participant))| ((n + 1).to_string(), Value::Object(participant)))
+        .collect::<Map<_, _>>();
+
+    object.insert("participants".to_string(), participants.into());
+}
+
+fn participant(uri: &crate::Uri, property: &str, unmapped: &mut Vec<String>) -> Map<String, Value> {
+    let mut participant = Map::new();
+
+    participant.insert("@type".to_string(), "Participant".into());
+    participant.insert(
+        "sendTo".to_string(),
+        serde_json::json!({ method(&uri.uri): uri.uri }),
+    );
+
+    for (k, v) in &uri.params {
+        match k.as_str() {
+            "CN" => {
+                participant.insert("name".to_string(), v.clone().into());
+            }
+            "CUTYPE" if v != "UNKNOWN" => {
+                participant.insert("kind".to_string(), v.to_lowercase().into());
+            }
+            "EMAIL" => {
+                participant.insert("email".to_string(), v.clone().into());
+            }
+            "ROLE" | "PARTSTAT" | "RSVP" if property == "ATTENDEE" => (),
+            _ => unmapped.push(format!("{property};{k}")),
+        }
+    }
+
+    participant
+}
+
+/**
+ * Returns the `sendTo` method of an address.
+ */
+fn method(uri: &str) -> &'static str {
+    if uri
+        .get(..7)
+        .is_some_and(|x| x.eq_ignore_ascii_case("mailto:"))
+    {
+        "imip"
+    } else {
+        "other"
+    }
+}
+
+fn send_to(value: &Value) -> Option<String> {
+    let send_to = value.as_object()?;
+
+    send_to
+        .get("imip")
+        .or_else(|| send_to.values().next())
+        .and_then(Value::as_str)
+        .map(ToString::to_string)
+}
+
+fn participants_from(
+    value: &Value,
+    reply_to: Option<&Value>,
+    unmapped: &mut Vec<String>,
+) -> crate::Result<(Option<crate::Uri>, Vec<crate::Uri>)> {
+    let mut organizer = reply_to.and_then(send_to).map(crate::Uri::from);
+    let mut attendees = Vec::new();
+
+    for (id, participant) in as_object(value, "participants")? {
+        let Some(address) = participant.get("sendTo").and_then(send_to) else {
+            unmapped.push(format!("participants/{id}"));
+            continue;
+        };
+
+        let roles = participant
+            .get("roles")
+            .map(|x| keys(x, "roles"))
+            .transpose()?
+            .unwrap_or_default();
+        let has_role = |role: &str| roles.iter().any(|x| x == role);
+
+        let mut uri = crate::Uri::from(address);
+        if let Some(name) = participant.get("name").and_then(Value::as_str) {
+            uri.params.insert("CN".to_string(), name.to_string());
+        }
+        if let Some(kind) = participant.get("kind").and_then(Value::as_str) {
+            uri.params.insert("CUTYPE".to_string(), kind.to_uppercase());
+        }
+        if let Some(email) = participant.get("email").and_then(Value::as_str) {
+            uri.params.insert("EMAIL".to_string(), email.to_string());
+        }
+
+        if has_role("owner") {
+            organizer = Some(uri.clone());
+        }
+
+        if has_role("owner") && roles.len() == 1 {
+            continue;
+        }
+
+        let role = if has_role("chair") {
+            Some("CHAIR")
+        } else if has_role("optional") {
+            Some("OPT-PARTICIPANT")
+        } else if has_role("informational") && !has_role("attendee") {
+            Some("NON-PARTICIPANT")
+        } else {
+            None
+        };
+        if let Some(role) = role {
+            uri.params.insert("ROLE".to_string(), role.to_string());
+        }
+        if let Some(status) = participant
+            .get("participationStatus")
+            .and_then(Value::as_str)
+        {
+            uri.params
+                .insert("PARTSTAT".to_string(), status.to_uppercase());
+        }
+        if participant.get("expectReply") == Some(&Value::Bool(true)) {
+            uri.params.insert("RSVP".to_string(), "TRUE".to_string());
+        }
+
+        attendees.push(uri);
+    }
+
+    Ok((organizer, attendees))
+}
+
+fn alerts(
+    alarms: &[crate::VAlarm],
+    summary: Option<&crate::Text>,
+    unmapped: &mut Vec<String>,
+) -> Value {
+    let mut alerts = Map::new();
+
+    for (n, alarm) in alarms.iter().enumerate() {
+        let action = match alarm {
+            crate::VAlarm::Display(display) => {
+                if Some(&display.description.text) != summary.map(|x| &x.text) {
+                    unmapped.push("VALARM;DESCRIPTION".to_string());
+                }
+                "display"
+            }
+            crate::VAlarm::Email(_) => {
+                unmapped.push("VALARM;ATTENDEE".to_string());
+                "email"
+            }
+            crate::VAlarm::Audio(_) | crate::VAlarm::NoAction(_) | crate::VAlarm::Custom(_) => {
+                unmapped.push("VALARM".to_string());
+                continue;
+            }
+        };
+
+        let trigger = match alarm.trigger() {
+            crate::Trigger::Duration(offset, related) => {
+                let mut trigger = serde_json::json!({
+                    "@type": "OffsetTrigger",
+                    "offset": offset.to_string(),
+                });
+                if *related == crate::trigger::Related::End {
+                    trigger["relativeTo"] = "end".into();
+                }
+
+                trigger
+            }
+            crate::Trigger::DateTime(when) => {
+                serde_json::json!({ "@type": "AbsoluteTrigger", "when": utc(when) })
+            }
+        };
+
+        let mut alert = Map::new();
+        alert.insert("@type".to_string(), "Alert".into());
+        alert.insert("trigger".to_string(), trigger);
+        alert.insert("action".to_string(), action.into());
+        if let Some(acknowledged) = alarm.acknowledged() {
+            alert.insert("acknowledged".to_string(), utc(acknowledged).into());
+        }
+        if !alarm.related_to().is_empty() {
+            alert.insert("relatedTo".to_string(), related_to(alarm.related_to()));
+        }
+        if alarm.repeat().is_some() {
+            unmapped.push("VALARM;REPEAT".to_string());
+        }
+
+        // Alerts without UID are keyed by their position
+        let id = alarm
+            .uid()
+            .map_or_else(|| (n + 1).to_string(), |x| x.text.clone());
+        alerts.insert(id, alert.into());
+    }
+
+    alerts.into()
+}
+
+fn alarms(value: &Value, summary: Option<&crate::Text>) -> crate::Result<Vec<crate::VAlarm>> {
+    let mut alarms = Vec::new();
+
+    for (id, alert) in as_object(value, "alerts")? {
+        let trigger = alert
+            .get("trigger")
+            .ok_or_else(|| invalid("alerts", alert))?;
+
+        let trigger = match trigger.get("@type").and_then(Value::as_str) {
+            Some("AbsoluteTrigger") => {
+                let when = trigger
+                    .get("when")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| invalid("trigger", trigger))?;
+
+                crate::Trigger::DateTime(parse_utc(when)?)
+            }
+            _ => {
+                let offset = trigger
+                    .get("offset")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| invalid("trigger", trigger))?;
+                let related = match trigger.get("relativeTo").and_then(Value::as_str) {
+                    Some("end") => crate::trigger::Related::End,
+                    _ => crate::trigger::Related::Start,
+                };
+
+                crate::Trigger::Duration(offset.parse()?, related)
+            }
+        };
+
+        let uid = id.parse::<usize>().is_err().then(|| id.as_str().into());
+        let acknowledged = alert
+            .get("acknowledged")
+            .and_then(Value::as_str)
+            .map(parse_utc)
+            .transpose()?;
+        let related_to = alert
+            .get("relatedTo")
+            .map(related_to_from)
+            .transpose()?
+            .unwrap_or_default();
+        let description = summary.cloned().unwrap_or_default();
+
+        let alarm = match alert.get("action").and_then(Value::as_str) {
+            Some("email") => crate::VAlarm::from(crate::valarm::Email {
+                trigger,
+                description,
+                summary: summary.cloned().unwrap_or_default(),
+                uid,
+                related_to,
+                acknowledged,
+                ..Default::default()
+            }),
+            _ => crate::VAlarm::from(crate::valarm::Display {
+                trigger,
+                description,
+                uid,
+                related_to,
+                acknowledged,
+                ..Default::default()
+            }),
+        };
+
+        alarms.push(alarm);
+    }
+
+    Ok(alarms)
+}
+
+fn timezone(vtimezone: &crate::VTimezone, unmapped: &mut Vec<String>) -> Value {
+    use crate::ser::Serialize as _;
+
+    let mut timezone = Map::new();
+
+    timezone.insert("@type".to_string(), "TimeZone".into());
+    timezone.insert("tzId".to_string(), vtimezone.tzid.text.clone().into());
+    if let Some(last_modified) = &vtimezone.last_modified {
+        timezone.insert("updated".to_string(), utc(last_modified).into());
+    }
+    if let Some(tzurl) = &vtimezone.tzurl {
+        timezone.insert("url".to_string(), tzurl.uri.clone().into());
+    }
+
+    macro_rules! rules {
+        ($rules:expr) => {
+            $rules
+                .iter()
+                .map(|x| {
+                    let mut rule = Map::new();
+
+                    rule.insert("@type".to_string(), "TimeZoneRule".into());
+                    rule.insert("start".to_string(), local(&x.dtstart).0.into());
+                    rule.insert("offsetFrom".to_string(), x.tzoffsetfrom.ical().into());
+                    rule.insert("offsetTo".to_string(), x.tzoffsetto.ical().into());
+                    if let Some(rrule) = &x.rrule {
+                        rule.insert(
+                            "recurrenceRules".to_string(),
+                            vec![recurrence_rule(rrule)].into(),
+                        );
+                    }
+                    let mut overrides = Map::new();
+                    for rdate in &x.rdate {
+                        rdate_overrides(rdate, &mut overrides);
+                    }
+                    if !overrides.is_empty() {
+                        rule.insert("recurrenceOverrides".to_string(), overrides.into());
+                    }
+                    if !x.tzname.is_empty() {
+                        rule.insert("names".to_string(), keywords(&x.tzname));
+                    }
+                    if !x.comment.is_empty() {
+                        let comments = x
+                            .comment
+                            .iter()
+                            .map(|x| Value::String(x.text.clone()))
+                            .collect::<Vec<_>>();
+                        rule.insert("comments".to_string(), comments.into());
+                    }
+                    unmapped.extend(x.x_prop.keys().cloned());
+                    unmapped.extend(x.iana_prop.keys().cloned());
+
+                    Value::Object(rule)
+                })
+                .collect::<Vec<_>>()
+        };
+    }
+
+    if !vtimezone.standard.is_empty() {
+        timezone.insert("standard".to_string(), rules!(vtimezone.standard).into());
+    }
+    if !vtimezone.daylight.is_empty() {
+        timezone.insert("daylight".to_string(), rules!(vtimezone.daylight).into());
+    }
+
+    unmapped.extend(vtimezone.x_prop.keys().cloned());
+    unmapped.extend(vtimezone.iana_prop.keys().cloned());
+
+    timezone.into()
+}
+
+fn vtimezone(value: &Value, unmapped: &mut Vec<String>) -> crate::Result<crate::VTimezone> {
+    let mut timezone = object(value, "TimeZone")?;
+    let zone = Zone {
+        utc: false,
+        tzid: None,
+        show_without_time: false,
+    };
+
+    let mut vtimezone = crate::VTimezone {
+        tzid: take_str(&mut timezone, "tzId")?
+            .ok_or_else(|| crate::Error::Jscalendar("missing tzId".to_string()))?
+            .into(),
+        last_modified: take_str(&mut timezone, "updated")?
+            .map(|x| parse_utc(&x))
+            .transpose()?,
+        tzurl: take_str(&mut timezone, "url")?.map(Into::into),
+        ..Default::default()
+    };
+
+    macro_rules! rules {
+        ($name:literal, $ty:ty) => {
+            take(&mut timezone, $name)
+                .map(|x| {
+                    x.as_array()
+                        .ok_or_else(|| invalid($name, &x))?
+                        .iter()
+                        .map(|x| {
+                            let mut rule = object(x, "TimeZoneRule")?;
+                            let offset = |rule: &mut Map<String, Value>, name| {
+                                take_str(rule, name)?
+                                    .ok_or_else(|| invalid(name, x))
+                                    .and_then(|x| x.parse().map_err(crate::Error::from))
+                            };
+
+                            let mut component = <$ty>::default();
+                            component.dtstart = take_str(&mut rule, "start")?
+                                .map(|x| zone.parse(&x))
+                                .transpose()?
+                                .ok_or_else(|| invalid("start", x))?;
+                            component.tzoffsetfrom = offset(&mut rule, "offsetFrom")?;
+                            component.tzoffsetto = offset(&mut rule, "offsetTo")?;
+                            if let Some(rules) = take(&mut rule, "recurrenceRules") {
+                                component.rrule = rules
+                                    .as_array()
+                                    .and_then(|x| x.first())
+                                    .map(|x| recur(x, &zone))
+                                    .transpose()?;
+                            }
+                            if let Some(overrides) = take(&mut rule, "recurrenceOverrides") {
+                                let dates = as_object(&overrides, "recurrenceOverrides")?
+                                    .keys()
+                                    .map(|x| zone.parse(x))
+                                    .collect::<crate::Result<_>>()?;
+                                component.rdate = vec![crate::RDate::Date(dates)];
+                            }
+                            component.tzname = take(&mut rule, "names")
+                                .map(|x| keys(&x, "names"))
+                                .transpose()?
+                                .unwrap_or_default()
+                                .into_iter()
+                                .map(Into::into)
+                                .collect();
+                            component.comment = take(&mut rule, "comments")
+                                .and_then(|x| x.as_array().cloned())
+                                .unwrap_or_default()
+                                .iter()
+                                .filter_map(Value::as_str)
+                                .map(Into::into)
+                                .collect();
+
+                            unmapped.extend(rule.into_iter().map(|(k, _)| format!("{}/{k}", $name)));
+
+                            Ok(component)
+                        })
+                        .collect::<crate::Result<Vec<_>>>()
+                })
+                .transpose()?
+                .unwrap_or_default()
+        };
+    }
+
+    vtimezone.standard = rules!("standard", crate::vtimezone::Standard);
+    vtimezone.daylight = rules!("daylight", crate::vtimezone::Daylight);
+
+    unmapped.extend(timezone.into_iter().map(|(k, _)| format!("timeZones/{k}")));
+
+    Ok(vtimezone)
+}
+
+fn object(value: &Value, ty: &str) -> crate::Result<Map<String, Value>> {
+    let object = as_object(value, ty)?;
+
+    match object.get("@type").and_then(Value::as_str) {
+        Some(x) if x == ty => Ok(object.clone()),
+        _ => Err(crate::Error::Jscalendar(format!("expected a {ty} object"))),
+    }
+}
+
+fn as_object<'a>(value: &'a Value, what: &str) -> crate::Result<&'a Map<String, Value>> {
+    value.as_object().ok_or_else(|| invalid(what, value))
+}
+
+fn take(object: &mut Map<String, Value>, key: &str) -> Option<Value> {
+    object.remove(key)
+}
+
+fn take_str(object: &mut Map<String, Value>, key: &str) -> crate::Result<Option<String>> {
+    match object.remove(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(value) => Err(invalid(key, &value)),
+    }
+}
+
+fn take_u64(object: &mut Map<String, Value>, key: &str) -> crate::Result<Option<u64>> {
+    match object.remove(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value.as_u64().map(Some).ok_or_else(|| invalid(key, &value)),
+    }
+}
+
+fn invalid(what: &str, value: &Value) -> crate::Error {
+    crate::Error::Jscalendar(format!("invalid {what}: {value}"))
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn event() -> crate::Result {
+        let mut vevent = crate::vevent! {
+            uid: "a8df6573-0474-496d-8496-033ad45d7fea",
+            dtstamp: "20200102T182304Z",
+            dtstart: "20200102T110000",
+            dtend: "20200102T120000",
+            summary: "Team meeting",
+            location: "Room 3",
+            categories: ["Work"],
+            organizer: "mailto:a@example.com",
+            attendee: ["mailto:b@example.com"],
+            rrule: {
+                freq: Weekly,
+                count: 4,
+            },
+            exdate: ["20200109T110000"],
+        }?;
+        vevent.attendee[0]
+            .params
+            .insert("PARTSTAT".to_string(), "ACCEPTED".to_string());
+        vevent.x_prop.insert("X-ROOM".to_string(), "3".into());
+
+        let mut instance = vevent.clone();
+        instance.rrule = None;
+        instance.exdate.clear();
+        instance.recurid = Some("20200116T110000".parse()?);
+        instance.dtstart = "20200116T140000".parse()?;
+        instance.dtend = Some("20200116T150000".parse()?);
+
+        let conversion = crate::jscalendar::event(&vevent, &[instance]);
+
+        similar_asserts::assert_eq!(
+            conversion.value,
+            serde_json::json!({
+                "@type": "Event",
+                "uid": "a8df6573-0474-496d-8496-033ad45d7fea",
+                "updated": "2020-01-02T18:23:04Z",
+                "title": "Team meeting",
+                "start": "2020-01-02T11:00:00",
+                "duration": "PT1H",
+                "keywords": { "Work": true },
+                "locations": { "1": { "@type": "Location", "name": "Room 3" } },
+                "recurrenceRules": [{
+                    "@type": "RecurrenceRule",
+                    "frequency": "weekly",
+                    "count": 4,
+                }],
+                "recurrenceOverrides": {
+                    "2020-01-09T11:00:00": { "excluded": true },
+                    "2020-01-16T11:00:00": { "start": "2020-01-16T14:00:00" },
+                },
+                "replyTo": { "imip": "mailto:a@example.com" },
+                "participants": {
+                    "1": {
+                        "@type": "Participant",
+                        "sendTo": { "imip": "mailto:a@example.com" },
+                        "roles": { "owner": true },
+                    },
+                    "2": {
+                        "@type": "Participant",
+                        "sendTo": { "imip": "mailto:b@example.com" },
+                        "roles": { "attendee": true },
+                        "participationStatus": "accepted",
+                    },
+                },
+            })
+        );
+        assert_eq!(conversion.unmapped, vec!["X-ROOM".to_string()]);
+
+        let vevents = crate::jscalendar::vevents(&conversion.value)?;
+        assert!(vevents.unmapped.is_empty());
+        assert_eq!(vevents.value.len(), 2);
+        assert_eq!(vevents.value[0].exdate, vevent.exdate);
+        assert_eq!(vevents.value[1].recurid, Some("20200116T110000".parse()?));
+        assert_eq!(vevents.value[1].dtstart, "20200116T140000".parse()?);
+
+        let overrides = &vevents.value[1..];
+        assert_eq!(
+            crate::jscalendar::event(&vevents.value[0], overrides).value,
+            conversion.value
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn task() -> crate::Result {
+        let value = serde_json::json!({
+            "@type": "Task",
+            "uid": "2a358cee-6489-4f14-a57f-c104db4dc2f2",
+            "updated": "2020-01-09T14:32:01Z",
+            "title": "Do something",
+            "due": "2020-01-19T18:00:00",
+            "timeZone": "Etc/UTC",
+            "estimatedDuration": "PT2H",
+            "percentComplete": 50,
+            "progress": "in-process",
+            "alerts": {
+                "1": {
+                    "@type": "Alert",
+                    "trigger": { "@type": "OffsetTrigger", "offset": "-PT15M", "relativeTo": "end" },
+                    "action": "display",
+                },
+            },
+            "localizations": {},
+        });
+
+        let conversion = crate::jscalendar::vtodos(&value)?;
+        assert_eq!(conversion.unmapped, vec!["localizations".to_string()]);
+
+        let vtodo = &conversion.value[0];
+        assert_eq!(vtodo.due, Some("20200119T180000Z".parse()?));
+        assert_eq!(vtodo.status, Some(crate::Status::InProcess));
+        assert_eq!(
+            vtodo.alarms[0].trigger(),
+            &crate::Trigger::Duration(-crate::Duration::minutes(15), crate::trigger::Related::End)
+        );
+
+        let mut expected = value.clone();
+        expected.as_object_mut().unwrap().remove("localizations");
+        assert_eq!(crate::jscalendar::task(vtodo, &[]).value, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn group() -> crate::Result {
+        let calendar =
+            crate::VCalendar::try_from(std::fs::read_to_string("tests/calendars/1.ics").unwrap())?;
+
+        let conversion = crate::jscalendar::group(&calendar);
+        let timezone = &conversion.value["timeZones"]["/Europe/Berlin"];
+        assert_eq!(timezone["standard"][0]["offsetTo"], "+0100");
+        assert_eq!(
+            timezone["daylight"][0]["names"],
+            serde_json::json!({ "CEST": true })
+        );
+        assert_eq!(
+            conversion.unmapped,
+            vec!["CALSCALE", "LAST-MODIFIED", "X-LIC-LOCATION"]
+        );
+
+        let parsed = crate::jscalendar::vcalendar(&conversion.value)?;
+        assert_eq!(parsed.value.events[0].uid, calendar.events[0].uid);
+        assert_eq!(parsed.value.events[0].dtstart, calendar.events[0].dtstart);
+        assert_eq!(parsed.value.events[0].status, calendar.events[0].status);
+        assert_eq!(
+            parsed.value.timezones[0].standard,
+            calendar.timezones[0].standard
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            crate::jscalendar::vevents(&serde_json::json!({ "@type": "Task" })),
+            Err(crate::Error::Jscalendar(_))
+        ));
+    }
+}
@@ -1,13 +1,40 @@
 #![warn(warnings)]
 
+pub mod convert;
+#[cfg(feature = "caldav")]
+pub mod filter;
+#[cfg(feature = "imip")]
+pub mod imip;
 pub mod iter;
+pub mod itip;
+#[cfg(feature = "jcal")]
+pub mod jcal;
+#[cfg(feature = "jscalendar")]
+pub mod jscalendar;
+pub mod normalize;
+pub mod scheduler;
 pub mod ser;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod sync;
+pub mod time_range;
+pub mod tzid;
+pub mod validate;
+pub mod vdir;
+#[cfg(feature = "xcal")]
+pub mod xcal;
 
 mod components;
 mod content_line;
 mod errors;
 mod parser;
 mod properties;
+#[cfg(any(feature = "jcal", feature = "serde", feature = "xcal"))]
+mod tree;
+#[cfg(any(feature = "caldav", feature = "xcal"))]
+mod xml;
 
 pub use components::*;
 pub use errors::*;
@@ -104,7 +131,7 @@ pub use ikal_derive::vtodo;
 
 pub use ikal_derive::{Component, Serialize};
 #[doc(hidden)]
-pub use ikal_derive::{audio, display, email, tz_daylight, tz_standard};
+pub use ikal_derive::{audio, custom, display, email, none, tz_daylight, tz_standard};
 
 use content_line::*;
 
@@ -112,7 +139,8 @@ use content_line::*;
 /**
  * Easily create a [`components::VAlarm`].
  *
- * The first argument of this macro is the kind of alarm: `@audio`, `@display` or `@email`.
+ * The first argument of this macro is the kind of alarm: `@audio`, `@display`, `@email`,
+ * `@none` or `@custom`.
  *
  * See [`vcalendar!`] for more information.
  */
@@ -122,64 +150,6 @@ macro_rules! valarm {
     };
 }
 
-#[doc(hidden)]
-pub fn parse_duration(value: &str) -> crate::Result<chrono::TimeDelta> {
-    let mut duration = chrono::TimeDelta::default();
-    let mut it = value.chars().peekable();
-
-    let mut negative = false;
-    let mut time = false;
-    let mut interval = String::new();
-
-    if it.next_if(|x| *x == '-').is_some() {
-        negative = true;
-    }
-
-    if it.next_if(|x| *x == 'P').is_none() {
-        return Err(crate::Error::ParseDuration(format!(
-            "Invalid duration: {value}"
-        )));
-    }
-
-    loop {
-        if it.next_if(|x| *x == 'T').is_some() {
-            time = true;
-            continue;
-        }
-
-        if let Some(x) = it.next_if(|x| x.is_ascii_digit()) {
-            interval.push(x);
-            continue;
-        };
-
-        let Some(ty) = it.next() else {
-            break;
-        };
-
-        let part = match (time, ty) {
-            (false, 'Y') => chrono::TimeDelta::days(365 * interval.parse::<i64>()?),
-            (false, 'M') => chrono::TimeDelta::days(30 * interval.parse::<i64>()?),
-            (false, 'D') => chrono::TimeDelta::days(interval.parse()?),
-            (true, 'H') => chrono::TimeDelta::hours(interval.parse()?),
-            (true, 'M') => chrono::TimeDelta::minutes(interval.parse()?),
-            (true, 'S') => chrono::TimeDelta::seconds(interval.parse()?),
-            _ => {
-                return Err(crate::Error::ParseDuration(format!(
-                    "Invalid duration: {interval}"
-                )));
-            }
-        };
-
-        duration += part;
-    }
-
-    if negative {
-        duration = -duration;
-    }
-
-    Ok(duration)
-}
-
 #[cfg(test)]
 mod test {
     use std::collections::BTreeMap;
@@ -238,6 +208,50 @@ CALSCALE:GREGORIAN
         assert_eq!(crate::parser::content_lines(line), Ok(("\n", expected)));
     }
 
+    /**
+     * Temporary directory, removed with its content when dropped.
+     */
+    pub(crate) struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        pub fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("ikal-{}-{name}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).unwrap();
+
+            Self(path)
+        }
+
+        pub fn path(&self) -> &std::path::Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /**
+     * Calendar object with a single event.
+     */
+    pub(crate) fn vcalendar(uid: &str, summary: &str) -> crate::VCalendar {
+        crate::vcalendar! {
+            version: "2.0",
+            prodid: "-//ikal//EN",
+            events: [
+                {
+                    uid: uid,
+                    dtstamp: "20210517T051025Z",
+                    dtstart: "20210301T100000Z",
+                    summary: summary,
+                }
+            ],
+        }
+        .unwrap()
+    }
+
     pub(crate) fn test_files<T: std::fmt::Debug + TryFrom<String, Error = crate::Error>>(
         path: &str,
     ) {
@@ -0,0 +1,496 @@
+/*!
+ * Normalisation of the extensions used by the big calendar providers.
+ *
+ * [`crate::VCalendar::normalize`] rewrites them into standard properties:
+ *
+ * - Windows time zone names and display strings used as `TZID` by Outlook, as well as legacy
+ *   aliases, are replaced by their IANA identifier, see [`crate::tzid`];
+ * - events flagged with `X-MICROSOFT-CDO-ALLDAYEVENT` starting at midnight become all-day events;
+ * - the `X-WR-TIMEZONE` of a Google calendar is attached to floating date-times;
+ * - `X-WR-CALNAME` is promoted to the [RFC 7986](https://datatracker.ietf.org/doc/html/rfc7986#section-5.1) `NAME`;
+ * - the coordinates of `X-APPLE-STRUCTURED-LOCATION` fill a missing `GEO`.
+ *
+ * The original `X-` properties are kept. Normalizing twice doesn’t change anything, the
+ * second report is empty.
+ *
+ * ```
+ * let mut vcalendar: ikal::VCalendar = "BEGIN:VCALENDAR\r
+ * VERSION:2.0\r
+ * PRODID:-//Google Inc//Google Calendar 70.9054//EN\r
+ * X-WR-CALNAME:Team\r
+ * X-WR-TIMEZONE:Europe/Paris\r
+ * BEGIN:VEVENT\r
+ * UID:a8df6573-0474-496d-8496-033ad45d7fea\r
+ * DTSTAMP:20210517T051025Z\r
+ * DTSTART:20210517T090000\r
+ * END:VEVENT\r
+ * END:VCALENDAR\r
+ * ".parse()?;
+ *
+ * let report = vcalendar.normalize();
+ * assert_eq!(report.len(), 2);
+ * assert_eq!(vcalendar.name[0].text, "Team");
+ * assert_eq!(vcalendar.events[0].dtstart.tzid(), Some("Europe/Paris"));
+ *
+ * assert!(vcalendar.normalize().is_empty());
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Fix {
+    /** `X-MICROSOFT-CDO-ALLDAYEVENT` converted to `DATE` values */
+    AllDayEvent,
+    /** `X-APPLE-STRUCTURED-LOCATION` copied to `GEO` */
+    AppleLocation,
+    /** `X-WR-CALNAME` copied to `NAME` */
+    CalendarName,
+    /** `X-WR-TIMEZONE` attached to floating date-times */
+    FloatingTimezone,
+    /** Windows time zone name, display string or legacy alias replaced by its IANA identifier */
+    WindowsTimezone,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Change {
+    pub fix: Fix,
+    /**
+     * Modified component, `None` for the calendar itself.
+     */
+    pub component: Option<crate::Components>,
+    /**
+     * UID of the component or the TZID of a time zone, empty for the calendar.
+     */
+    pub id: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.component {
+            Some(component) => write!(f, "{component} {}: {}", self.id, self.message),
+            None => write!(f, "VCALENDAR: {}", self.message),
+        }
+    }
+}
+
+/**
+ * IANA identifier of a Windows zone name, an Outlook display string or a legacy alias.
+ */
+fn windows_zone(tzid: &str) -> Option<&'static str> {
+    crate::tzid::iana(tzid).filter(|iana| *iana != tzid)
+}
+
+/**
+ * Collects the changes of a calendar.
+ */
+#[derive(Default)]
+struct Report {
+    changes: Vec<Change>,
+}
+
+impl Report {
+    fn push(
+        &mut self,
+        fix: Fix,
+        component: Option<crate::Components>,
+        id: &str,
+        message: impl Into<String>,
+    ) {
+        self.changes.push(Change {
+            fix,
+            component,
+            id: id.to_string(),
+            message: message.into(),
+        });
+    }
+}
+
+pub(crate) fn vcalendar(vcalendar: &mut crate::VCalendar) -> Vec<Change> {
+    let mut report = Report::default();
+
+    windows_timezones(vcalendar, &mut report);
+    calendar_name(vcalendar, &mut report);
+    floating_timezone(vcalendar, &mut report);
+
+    for vevent in &mut vcalendar.events {
+        all_day_event(vevent, &mut report);
+    }
+
+    for vevent in &mut vcalendar.events {
+        apple_location(
+            &vevent.x_prop,
+            &mut vevent.geo,
+            crate::Components::Event,
+            &vevent.uid,
+            &mut report,
+        );
+    }
+
+    for vtodo in &mut vcalendar.todo {
+        apple_location(
+            &vtodo.x_prop,
+            &mut vtodo.geo,
+            crate::Components::Todo,
+            &vtodo.uid,
+            &mut report,
+        );
+    }
+
+    report.changes
+}
+
+/**
+ * Calls `f` with the date-times of every component of the calendar, then reports a change
+ * with `message` for each component where `f` returned `true`.
+ */
+fn each_date_time(
+    vcalendar: &mut crate::VCalendar,
+    report: &mut Report,
+    fix: Fix,
+    message: &str,
+    mut f: impl FnMut(&mut crate::DateTime) -> bool,
+) {
+    // Every date-time is visited, not only up to the first change
+    let mut apply = |date_times: Vec<&mut crate::DateTime>| {
+        let mut changed = false;
+
+        for date_time in date_times {
+            changed |= f(date_time);
+        }
+
+        changed
+    };
+
+    let mut changed = Vec::new();
+
+    for vevent in &mut vcalendar.events {
+        if apply(vevent.date_times_mut()) {
+            changed.push((crate::Components::Event, vevent.uid.to_string()));
+        }
+    }
+
+    for vfreebusy in &mut vcalendar.freebusy {
+        if apply(vfreebusy.date_times_mut()) {
+            changed.push((crate::Components::Freebusy, vfreebusy.uid.to_string()));
+        }
+    }
+
+    for vjournal in &mut vcalendar.journals {
+        if apply(vjournal.date_times_mut()) {
+            changed.push((crate::Components::Journal, vjournal.uid.to_string()));
+        }
+    }
+
+    for vtodo in &mut vcalendar.todo {
+        if apply(vtodo.date_times_mut()) {
+            changed.push((crate::Components::Todo, vtodo.uid.to_string()));
+        }
+    }
+
+    for (component, id) in changed {
+        report.push(fix, Some(component), &id, message);
+    }
+}
+
+fn windows_timezones(vcalendar: &mut crate::VCalendar, report: &mut Report) {
+    if let Some(x_wr_timezone) = vcalendar.x_prop.get_mut("X-WR-TIMEZONE")
+        && let Some(iana) = windows_zone(&x_wr_timezone.value)
+    {
+        report.push(
+            Fix::WindowsTimezone,
+            None,
+            "",
+            format!("X-WR-TIMEZONE {} replaced by {iana}", x_wr_timezone.value),
+        );
+        x_wr_timezone.value = iana.to_string();
+    }
+
+    // The IANA time zone may be already defined, next to its Windows name
+    let mut defined = vcalendar
+        .timezones
+        .iter()
+        .map(|vtimezone| vtimezone.tzid.to_string())
+        .collect::<std::collections::BTreeSet<_>>();
+
+    vcalendar.timezones.retain_mut(|vtimezone| {
+        let Some(iana) = windows_zone(&vtimezone.tzid) else {
+            return true;
+        };
+
+        let keep = defined.insert(iana.to_string());
+        let message = if keep {
+            format!("TZID {} replaced by {iana}", vtimezone.tzid)
+        } else {
+            format!("duplicate of {iana} removed")
+        };
+        report.push(
+            Fix::WindowsTimezone,
+            Some(crate::Components::Timezone),
+            &vtimezone.tzid,
+            message,
+        );
+        vtimezone.tzid.text = iana.to_string();
+
+        keep
+    });
+
+    each_date_time(
+        vcalendar,
+        report,
+        Fix::WindowsTimezone,
+        "Windows time zone names replaced by IANA identifiers",
+        |date_time| {
+            let crate::DateTime::Zoned(_, tzid) = date_time else {
+                return false;
+            };
+
+            match windows_zone(tzid) {
+                Some(iana) => {
+                    *tzid = iana.into();
+                    true
+                }
+                None => false,
+            }
+        },
+    );
+}
+
+fn calendar_name(vcalendar: &mut crate::VCalendar, report: &mut Report) {
+    if !vcalendar.name.is_empty() {
+        return;
+    }
+
+    if let Some(x_wr_calname) = vcalendar.x_prop.get("X-WR-CALNAME") {
+        vcalendar.name.push(x_wr_calname.value.as_str().into());
+        report.push(
+            Fix::CalendarName,
+            None,
+            "",
+            format!("NAME set to {}", x_wr_calname.value),
+        );
+    }
+}
+
+fn floating_timezone(vcalendar: &mut crate::VCalendar, report: &mut Report) {
+    let Some(tzid) = vcalendar
+        .x_prop
+        .get("X-WR-TIMEZONE")
+        .map(|x_wr_timezone| x_wr_timezone.value.clone())
+    else {
+        return;
+    };
+
+    each_date_time(
+        vcalendar,
+        report,
+        Fix::FloatingTimezone,
+        &format!("floating date-times attached to {tzid}"),
+        |date_time| {
+            if !matches!(date_time, crate::DateTime::Naive(_)) {
+                return false;
+            }
+
+            *date_time = std::mem::take(date_time).with_tzid(&tzid);
+            true
+        },
+    );
+}
+
+fn all_day_event(vevent: &mut crate::VEvent, report: &mut Report) {
+    let all_day = vevent
+        .x_prop
+        .get("X-MICROSOFT-CDO-ALLDAYEVENT")
+        .is_some_and(|x| x.value.eq_ignore_ascii_case("TRUE"));
+
+    if !all_day || !midnight(&vevent.dtstart) || !vevent.dtend.as_ref().is_none_or(midnight) {
+        return;
+    }
+
+    let dates = std::iter::once(&mut vevent.dtstart)
+        .chain(vevent.dtend.as_mut())
+        .chain(vevent.recurid.as_mut())
+        .chain(vevent.exdate.iter_mut());
+
+    for date in dates {
+        if midnight(date) {
+            *date = crate::Date::Date(date.date_naive());
+        }
+    }
+
+    report.push(
+        Fix::AllDayEvent,
+        Some(crate::Components::Event),
+        &vevent.uid,
+        format!("all-day event starting {}", vevent.dtstart),
+    );
+}
+
+/**
+ * A floating or zoned date-time at midnight.
+ */
+fn midnight(date: &crate::Date) -> bool {
+    match date {
+        crate::Date::DateTime(crate::DateTime::Naive(naive) | crate::DateTime::Zoned(naive, _)) => {
+            naive.time() == chrono::NaiveTime::MIN
+        }
+        _ => false,
+    }
+}
+
+fn apple_location(
+    x_prop: &std::collections::BTreeMap<String, crate::ContentLine>,
+    geo: &mut Option<crate::Geo>,
+    component: crate::Components,
+    id: &str,
+    report: &mut Report,
+) {
+    if geo.is_some() {
+        return;
+    }
+
+    let Some(location) = x_prop.get("X-APPLE-STRUCTURED-LOCATION") else {
+        return;
+    };
+
+    // https://datatracker.ietf.org/doc/html/rfc5870#section-3.3
+    let Some(coordinates) = location.value.strip_prefix("geo:") else {
+        return;
+    };
+    let coordinates = coordinates.split(';').next().unwrap_or_default();
+    let mut coordinates = coordinates.split(',').map(str::parse::<f32>);
+
+    if let (Some(Ok(lat)), Some(Ok(lon))) = (coordinates.next(), coordinates.next()) {
+        *geo = Some(crate::Geo { lat, lon });
+        report.push(
+            Fix::AppleLocation,
+            Some(component),
+            id,
+            format!("GEO set to {lat};{lon}"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn google() -> crate::Result {
+        let mut vcalendar: crate::VCalendar =
+            std::fs::read_to_string("tests/calendars/x_location.ics")
+                .unwrap()
+                .parse()?;
+
+        let report = vcalendar.normalize();
+        let fixes = report.iter().map(|change| change.fix).collect::<Vec<_>>();
+        assert_eq!(
+            fixes,
+            [
+                crate::normalize::Fix::CalendarName,
+                crate::normalize::Fix::AppleLocation
+            ]
+        );
+        assert_eq!(vcalendar.name[0].text, "ITC");
+        assert_eq!(
+            vcalendar.events[0].geo,
+            Some(crate::Geo {
+                lat: 52.382762,
+                lon: 7.528319,
+            })
+        );
+        assert!(vcalendar.x_prop.contains_key("X-WR-CALNAME"));
+
+        assert_eq!(vcalendar.normalize(), Vec::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn outlook() -> crate::Result {
+        let mut vcalendar: crate::VCalendar = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:Microsoft Exchange Server 2010\r
+BEGIN:VTIMEZONE\r
+TZID:Romance Standard Time\r
+BEGIN:STANDARD\r
+DTSTART:16010101T030000\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+RRULE:FREQ=YEARLY;INTERVAL=1;BYDAY=-1SU;BYMONTH=10\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:16010101T020000\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+RRULE:FREQ=YEARLY;INTERVAL=1;BYDAY=-1SU;BYMONTH=3\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:040000008200E00074C5B7101A82E00800000000\r
+DTSTAMP:20230601T080000Z\r
+DTSTART;TZID=Romance Standard Time:20230605T100000\r
+DTEND;TZID=Romance Standard Time:20230605T110000\r
+EXDATE;TZID=Romance Standard Time:20230612T100000\r
+RRULE:FREQ=WEEKLY\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:040000008200E00074C5B7101A82E00800000001\r
+DTSTAMP:20230601T080000Z\r
+DTSTART;TZID=Romance Standard Time:20230614T000000\r
+DTEND;TZID=Romance Standard Time:20230615T000000\r
+X-MICROSOFT-CDO-ALLDAYEVENT:TRUE\r
+END:VEVENT\r
+END:VCALENDAR\r
+"
+        .parse()?;
+
+        let report = vcalendar.normalize();
+        let fixes = report.iter().map(|change| change.fix).collect::<Vec<_>>();
+        assert_eq!(
+            fixes,
+            [
+                crate::normalize::Fix::WindowsTimezone,
+                crate::normalize::Fix::WindowsTimezone,
+                crate::normalize::Fix::WindowsTimezone,
+                crate::normalize::Fix::AllDayEvent,
+            ]
+        );
+
+        assert_eq!(vcalendar.timezones[0].tzid.text, "Europe/Paris");
+        let vevent = &vcalendar.events[0];
+        assert_eq!(vevent.dtstart.tzid(), Some("Europe/Paris"));
+        assert_eq!(vevent.exdate[0].tzid(), Some("Europe/Paris"));
+
+        let vevent = &vcalendar.events[1];
+        assert_eq!(vevent.dtstart, "20230614".parse()?);
+        assert_eq!(vevent.dtend, Some("20230615".parse()?));
+
+        assert!(vcalendar.validate().is_empty());
+        assert_eq!(vcalendar.normalize(), Vec::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_timezone() {
+        let mut vcalendar = crate::VCalendar {
+            timezones: vec![
+                crate::VTimezone {
+                    tzid: "Europe/Paris".into(),
+                    ..Default::default()
+                },
+                crate::VTimezone {
+                    tzid: "Romance Standard Time".into(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let report = vcalendar.normalize();
+        assert_eq!(
+            report[0].to_string(),
+            "VTIMEZONE Romance Standard Time: duplicate of Europe/Paris removed"
+        );
+        assert_eq!(vcalendar.timezones.len(), 1);
+    }
+}
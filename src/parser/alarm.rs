@@ -24,11 +24,44 @@ pub(crate) fn trigger(input: crate::ContentLine) -> crate::Result<crate::Trigger
     use nom::branch::alt;
     use nom::combinator::map;
 
+    let related = input
+        .params
+        .get("RELATED")
+        .map(|x| x.parse())
+        .transpose()?
+        .unwrap_or_default();
+
     alt((
-        map(super::datatype::duration, crate::Trigger::Duration),
+        map(super::datatype::duration, |x| {
+            crate::Trigger::Duration(x, related)
+        }),
         map(super::datatype::date_time, crate::Trigger::DateTime),
     ))
     .parse(input.value.as_str())
     .map_err(crate::Error::from)
     .map(|(_, x)| x)
 }
+
+/**
+ * See [6.1. Acknowledged Property](https://datatracker.ietf.org/doc/html/rfc9074#section-6.1)
+ */
+pub(crate) fn acknowledged(input: crate::ContentLine) -> crate::Result<crate::DateTime> {
+    super::datatype::date_time(&input.value)
+        .map_err(crate::Error::from)
+        .map(|(_, x)| x)
+}
+
+/**
+ * See [8.1. Proximity Property](https://datatracker.ietf.org/doc/html/rfc9074#section-8.1)
+ */
+pub(crate) fn proximity(input: crate::ContentLine) -> crate::Result<crate::Proximity> {
+    input.value.parse()
+}
+
+/**
+ * Marks an alarm as the default one of its calendar, see
+ * [RFC 9074](https://datatracker.ietf.org/doc/html/rfc9074).
+ */
+pub(crate) fn default_alarm(input: crate::ContentLine) -> crate::Result<bool> {
+    super::datatype::boolean(&input.value)
+}
@@ -26,7 +26,6 @@ macro_rules! component {
 
 component!(valarm, crate::VAlarm);
 component!(vfreebusy, crate::VFreebusy);
-component!(vtodo, crate::VTodo);
 component!(vjournal, crate::VJournal);
 component!(standard, crate::vtimezone::Standard);
 component!(daylight, crate::vtimezone::Daylight);
@@ -43,6 +42,14 @@ pub(crate) fn email(_: &str) -> super::NomResult<&str, crate::valarm::Email> {
     unreachable!()
 }
 
+pub(crate) fn noaction(_: &str) -> super::NomResult<&str, crate::valarm::NoAction> {
+    unreachable!()
+}
+
+pub(crate) fn custom(_: &str) -> super::NomResult<&str, crate::valarm::Custom> {
+    unreachable!()
+}
+
 pub(crate) fn vevent(input: &str) -> super::NomResult<&str, crate::VEvent> {
     context(
         "vevent",
@@ -63,6 +70,26 @@ pub(crate) fn vevent(input: &str) -> super::NomResult<&str, crate::VEvent> {
     .parse(input)
 }
 
+pub(crate) fn vtodo(input: &str) -> super::NomResult<&str, crate::VTodo> {
+    context(
+        "vtodo",
+        map_res(
+            delimited(
+                tag("BEGIN:VTODO\r\n"),
+                (super::content_lines, many0(valarm)),
+                tag("END:VTODO\r\n"),
+            ),
+            |(content_lines, alarms)| {
+                let mut vtodo: crate::VTodo = content_lines.try_into()?;
+                vtodo.alarms = alarms;
+
+                Ok::<_, crate::Error>(vtodo)
+            },
+        ),
+    )
+    .parse(input)
+}
+
 pub(crate) fn vtimezone(input: &str) -> super::NomResult<&str, crate::VTimezone> {
     context(
         "vtimezone",
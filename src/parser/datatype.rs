@@ -8,6 +8,17 @@ use nom::combinator::{map, map_res, opt};
 use nom::error::{FromExternalError, context};
 use nom::sequence::{pair, preceded, terminated};
 
+/**
+ * See [3.3.2. Boolean](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.2)
+ */
+pub(crate) fn boolean(input: &str) -> crate::Result<bool> {
+    match input.to_ascii_uppercase().as_str() {
+        "TRUE" => Ok(true),
+        "FALSE" => Ok(false),
+        _ => Err(crate::Error::Boolean(input.to_string())),
+    }
+}
+
 /**
  * See [3.3.3. Calendar User Address](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.3)
  */
@@ -66,11 +77,25 @@ pub(crate) fn date_or_dt(input: &str) -> super::NomResult<&str, crate::Date> {
     .parse(input)
 }
 
+/**
+ * Date or date-time of a property, floating date-times are bound to its `TZID` parameter.
+ */
+pub(crate) fn zoned(input: &crate::ContentLine, value: &str) -> crate::Result<crate::Date> {
+    let date = date_or_dt(value)?.1;
+
+    let date = match input.params.get("TZID") {
+        Some(tzid) => date.with_tzid(tzid),
+        None => date,
+    };
+
+    Ok(date)
+}
+
 /**
  * See [3.3.6. Duration](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6)
  */
-pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration> {
-    fn week(input: &str) -> super::NomResult<&str, i64> {
+pub(crate) fn duration(input: &str) -> super::NomResult<&str, crate::Duration> {
+    fn week(input: &str) -> super::NomResult<&str, u32> {
         context(
             "week",
             map_res(terminated(super::digits, tag("W")), str::parse),
@@ -78,7 +103,7 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         .parse(input)
     }
 
-    fn day(input: &str) -> super::NomResult<&str, i64> {
+    fn day(input: &str) -> super::NomResult<&str, u32> {
         context(
             "day",
             map_res(terminated(super::digits, tag("D")), str::parse),
@@ -86,24 +111,24 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         .parse(input)
     }
 
-    fn time(input: &str) -> super::NomResult<&str, (i64, i64, i64)> {
-        let (input, (h, i, s)) = context(
+    fn time(input: &str) -> super::NomResult<&str, u32> {
+        context(
             "time",
-            preceded(tag("T"), (opt(hour), opt(minute), opt(seconde))),
-        )
-        .parse(input)?;
-
-        Ok((
-            input,
-            (
-                h.unwrap_or_default(),
-                i.unwrap_or_default(),
-                s.unwrap_or_default(),
+            map_res(
+                preceded(tag("T"), (opt(hour), opt(minute), opt(seconde))),
+                |(h, i, s)| {
+                    h.unwrap_or_default()
+                        .checked_mul(3_600)
+                        .zip(i.unwrap_or_default().checked_mul(60))
+                        .and_then(|(h, i)| h.checked_add(i)?.checked_add(s.unwrap_or_default()))
+                        .ok_or(crate::Error::ParseDuration(input.to_string()))
+                },
             ),
-        ))
+        )
+        .parse(input)
     }
 
-    fn hour(input: &str) -> super::NomResult<&str, i64> {
+    fn hour(input: &str) -> super::NomResult<&str, u32> {
         context(
             "hour",
             map_res(terminated(super::digits, tag("H")), str::parse),
@@ -111,7 +136,7 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         .parse(input)
     }
 
-    fn minute(input: &str) -> super::NomResult<&str, i64> {
+    fn minute(input: &str) -> super::NomResult<&str, u32> {
         context(
             "minute",
             map_res(terminated(super::digits, tag("M")), str::parse),
@@ -119,7 +144,7 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         .parse(input)
     }
 
-    fn seconde(input: &str) -> super::NomResult<&str, i64> {
+    fn seconde(input: &str) -> super::NomResult<&str, u32> {
         context(
             "seconde",
             map_res(terminated(super::digits, tag("S")), str::parse),
@@ -131,21 +156,14 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         "duration",
         map(
             pair(
-                opt(tag("-")),
+                opt(nom::branch::alt((tag("+"), tag("-")))),
                 preceded(tag("P"), (opt(week), opt(day), opt(time))),
             ),
-            |(neg, (w, d, t))| {
-                let mut duration = chrono::Duration::weeks(w.unwrap_or_default())
-                    + chrono::Duration::days(d.unwrap_or_default());
-
-                if let Some((h, i, s)) = t {
-                    duration = duration
-                        + chrono::Duration::hours(h)
-                        + chrono::Duration::minutes(i)
-                        + chrono::Duration::seconds(s);
-                }
-
-                if neg.is_some() { -duration } else { duration }
+            |(sign, (w, d, t))| crate::Duration {
+                negative: sign == Some("-"),
+                weeks: w.unwrap_or_default(),
+                days: d.unwrap_or_default(),
+                seconds: t.unwrap_or_default(),
             },
         ),
     )
@@ -15,33 +15,27 @@ pub(crate) fn completed(input: crate::ContentLine) -> crate::Result<crate::DateT
  * See [3.8.2.2. Date-Time End](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.2)
  */
 pub(crate) fn dtend(input: crate::ContentLine) -> crate::Result<crate::Date> {
-    super::datatype::date_or_dt(&input.value)
-        .map_err(crate::Error::from)
-        .map(|(_, x)| x)
+    super::datatype::zoned(&input, &input.value)
 }
 
 /**
  * See [3.8.2.3. Date-Time Due](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.3)
  */
 pub(crate) fn due(input: crate::ContentLine) -> crate::Result<crate::Date> {
-    super::datatype::date_or_dt(&input.value)
-        .map_err(crate::Error::from)
-        .map(|(_, x)| x)
+    super::datatype::zoned(&input, &input.value)
 }
 
 /**
  * See [3.8.2.4. Date-Time Start](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.4)
  */
 pub(crate) fn dtstart(input: crate::ContentLine) -> crate::Result<crate::Date> {
-    super::datatype::date_or_dt(&input.value)
-        .map_err(crate::Error::from)
-        .map(|(_, x)| x)
+    super::datatype::zoned(&input, &input.value)
 }
 
 /**
  * See [3.8.2.5. Duration](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.5)
  */
-pub(crate) fn duration(input: crate::ContentLine) -> crate::Result<chrono::Duration> {
+pub(crate) fn duration(input: crate::ContentLine) -> crate::Result<crate::Duration> {
     super::datatype::duration(&input.value)
         .map_err(crate::Error::from)
         .map(|(_, x)| x)
@@ -9,6 +9,7 @@ mod misc;
 mod properties;
 mod recurrence;
 mod relationship;
+mod rfc7986;
 mod timezone;
 
 pub(crate) use alarm::*;
@@ -20,6 +21,7 @@ pub(crate) use misc::*;
 pub(crate) use properties::*;
 pub(crate) use recurrence::*;
 pub(crate) use relationship::*;
+pub(crate) use rfc7986::*;
 pub(crate) use timezone::*;
 
 use nom::Parser as _;
@@ -99,7 +101,7 @@ fn param(input: &str) -> NomResult<&str, (&str, &str)> {
     .parse(input)
 }
 
-fn params(input: &str) -> NomResult<&str, BTreeMap<String, String>> {
+pub(crate) fn params(input: &str) -> NomResult<&str, BTreeMap<String, String>> {
     context(
         "params",
         fold_many0(param, BTreeMap::new, |mut acc, (key, value)| {
@@ -12,8 +12,8 @@ pub(crate) fn calscale(input: crate::ContentLine) -> crate::Result<crate::Text>
 /**
  * See [3.7.2. Method](https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.2)
  */
-pub(crate) fn method(input: crate::ContentLine) -> crate::Result<crate::Text> {
-    Ok(input.into())
+pub(crate) fn method(input: crate::ContentLine) -> crate::Result<crate::Method> {
+    input.value.parse()
 }
 
 /**
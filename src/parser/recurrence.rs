@@ -9,11 +9,7 @@ pub(crate) fn exdate(input: crate::ContentLine) -> crate::Result<Vec<crate::Date
     input
         .value
         .split(',')
-        .map(|x| {
-            super::datatype::date_or_dt(x)
-                .map(|x| x.1)
-                .map_err(crate::Error::from)
-        })
+        .map(|x| super::datatype::zoned(&input, x))
         .collect()
 }
 
@@ -26,16 +22,16 @@ pub(crate) fn rdate(input: crate::ContentLine) -> crate::Result<crate::RDate> {
     if input.params.get("VALUE") == Some(&"PERIOD".to_string()) {
         let periods = tokens
             .map(super::datatype::period)
+            .map(|x| match input.params.get("TZID") {
+                Some(tzid) => x.map(|x| x.with_tzid(tzid)),
+                None => x,
+            })
             .collect::<crate::Result<Vec<_>>>()?;
 
         Ok(crate::RDate::Period(periods))
     } else {
         let dates = tokens
-            .map(|x| {
-                super::datatype::date_or_dt(x)
-                    .map(|x| x.1)
-                    .map_err(crate::Error::from)
-            })
+            .map(|x| super::datatype::zoned(&input, x))
             .collect::<crate::Result<Vec<_>>>()?;
 
         Ok(crate::RDate::Date(dates))
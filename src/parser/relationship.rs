@@ -33,9 +33,7 @@ pub(crate) fn organizer(input: crate::ContentLine) -> crate::Result<crate::Uri>
  * See [3.8.4.4. Recurrence ID](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.4.4)
  */
 pub(crate) fn recurid(input: crate::ContentLine) -> crate::Result<crate::Date> {
-    super::datatype::date_or_dt(&input.value)
-        .map_err(crate::Error::from)
-        .map(|(_, x)| x)
+    super::datatype::zoned(&input, &input.value)
 }
 
 /**
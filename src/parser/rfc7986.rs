@@ -0,0 +1,129 @@
+/*!
+ * See [5. Properties](https://datatracker.ietf.org/doc/html/rfc7986#section-5)
+ */
+
+/**
+ * See [5.1. NAME Property](https://datatracker.ietf.org/doc/html/rfc7986#section-5.1)
+ */
+pub(crate) fn name(input: crate::ContentLine) -> crate::Result<crate::Text> {
+    Ok(input.into())
+}
+
+/**
+ * See [5.7. REFRESH-INTERVAL Property](https://datatracker.ietf.org/doc/html/rfc7986#section-5.7)
+ */
+pub(crate) fn refresh_interval(input: crate::ContentLine) -> crate::Result<crate::Duration> {
+    super::datatype::duration(&input.value)
+        .map_err(crate::Error::from)
+        .map(|(_, x)| x)
+}
+
+/**
+ * See [5.8. SOURCE Property](https://datatracker.ietf.org/doc/html/rfc7986#section-5.8)
+ */
+pub(crate) fn source(input: crate::ContentLine) -> crate::Result<crate::Uri> {
+    Ok(input.into())
+}
+
+/**
+ * See [5.9. COLOR Property](https://datatracker.ietf.org/doc/html/rfc7986#section-5.9)
+ */
+pub(crate) fn color(input: crate::ContentLine) -> crate::Result<crate::Color> {
+    input.value.parse()
+}
+
+/**
+ * See [5.10. IMAGE Property](https://datatracker.ietf.org/doc/html/rfc7986#section-5.10)
+ */
+pub(crate) fn image(input: crate::ContentLine) -> crate::Result<crate::Image> {
+    let mut params = input.params;
+
+    let value = if params.remove("VALUE").as_deref() == Some("BINARY") {
+        params.remove("ENCODING");
+        crate::image::Value::Binary(input.value)
+    } else {
+        crate::image::Value::Uri(input.value)
+    };
+
+    let display = params
+        .remove("DISPLAY")
+        .map(|x| x.split(',').map(str::parse).collect::<crate::Result<_>>())
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(crate::Image {
+        altrep: params.remove("ALTREP"),
+        display,
+        fmttype: params.remove("FMTTYPE"),
+        value,
+        params,
+    })
+}
+
+/**
+ * See [5.11. CONFERENCE Property](https://datatracker.ietf.org/doc/html/rfc7986#section-5.11)
+ */
+pub(crate) fn conference(input: crate::ContentLine) -> crate::Result<crate::Conference> {
+    let mut params = input.params;
+    params.remove("VALUE");
+
+    let feature = params
+        .remove("FEATURE")
+        .map(|x| x.split(',').map(str::parse).collect::<crate::Result<_>>())
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(crate::Conference {
+        feature,
+        label: params.remove("LABEL"),
+        uri: input.value,
+        params,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn image() -> crate::Result {
+        let content_line = crate::parser::content_line(
+            "IMAGE;VALUE=URI;DISPLAY=BADGE,THUMBNAIL;FMTTYPE=image/png:https://example.com/a.png\r\n",
+        )?
+        .1;
+
+        assert_eq!(
+            crate::parser::image(content_line)?,
+            crate::Image {
+                display: vec![
+                    crate::image::Display::Badge,
+                    crate::image::Display::Thumbnail
+                ],
+                fmttype: Some("image/png".to_string()),
+                ..crate::Image::from("https://example.com/a.png")
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn conference() -> crate::Result {
+        let content_line = crate::parser::content_line(
+            "CONFERENCE;VALUE=URI;FEATURE=PHONE,MODERATOR;LABEL=Moderator dial-in:tel:+1-412-555-0123,,,654321\r\n",
+        )?
+        .1;
+
+        assert_eq!(
+            crate::parser::conference(content_line)?,
+            crate::Conference {
+                feature: vec![
+                    crate::conference::Feature::Phone,
+                    crate::conference::Feature::Moderator
+                ],
+                label: Some("Moderator dial-in".to_string()),
+                ..crate::Conference::from("tel:+1-412-555-0123,,,654321")
+            }
+        );
+
+        Ok(())
+    }
+}
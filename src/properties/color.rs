@@ -0,0 +1,223 @@
+macro_rules! colors {
+    ($( $variant:ident => $name:literal, )*) => {
+        /**
+         * See [5.9. COLOR Property](https://datatracker.ietf.org/doc/html/rfc7986#section-5.9)
+         *
+         * The value is one of the [CSS3 color names](https://www.w3.org/TR/css-color-3/#svg-color).
+         */
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub enum Color {
+            $( $variant, )*
+        }
+
+        impl std::str::FromStr for Color {
+            type Err = crate::Error;
+
+            fn from_str(s: &str) -> crate::Result<Self> {
+                let color = match s.to_ascii_lowercase().as_str() {
+                    $( $name => Self::$variant, )*
+
+                    _ => return Err(crate::Error::Color(s.to_string())),
+                };
+
+                Ok(color)
+            }
+        }
+
+        impl std::fmt::Display for Color {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let s = match self {
+                    $( Self::$variant => $name, )*
+                };
+
+                f.write_str(s)
+            }
+        }
+    };
+}
+
+colors! {
+    AliceBlue => "aliceblue",
+    AntiqueWhite => "antiquewhite",
+    Aqua => "aqua",
+    Aquamarine => "aquamarine",
+    Azure => "azure",
+    Beige => "beige",
+    Bisque => "bisque",
+    Black => "black",
+    BlanchedAlmond => "blanchedalmond",
+    Blue => "blue",
+    BlueViolet => "blueviolet",
+    Brown => "brown",
+    BurlyWood => "burlywood",
+    CadetBlue => "cadetblue",
+    Chartreuse => "chartreuse",
+    Chocolate => "chocolate",
+    Coral => "coral",
+    CornflowerBlue => "cornflowerblue",
+    Cornsilk => "cornsilk",
+    Crimson => "crimson",
+    Cyan => "cyan",
+    DarkBlue => "darkblue",
+    DarkCyan => "darkcyan",
+    DarkGoldenrod => "darkgoldenrod",
+    DarkGray => "darkgray",
+    DarkGreen => "darkgreen",
+    DarkGrey => "darkgrey",
+    DarkKhaki => "darkkhaki",
+    DarkMagenta => "darkmagenta",
+    DarkOliveGreen => "darkolivegreen",
+    DarkOrange => "darkorange",
+    DarkOrchid => "darkorchid",
+    DarkRed => "darkred",
+    DarkSalmon => "darksalmon",
+    DarkSeaGreen => "darkseagreen",
+    DarkSlateBlue => "darkslateblue",
+    DarkSlateGray => "darkslategray",
+    DarkSlateGrey => "darkslategrey",
+    DarkTurquoise => "darkturquoise",
+    DarkViolet => "darkviolet",
+    DeepPink => "deeppink",
+    DeepSkyBlue => "deepskyblue",
+    DimGray => "dimgray",
+    DimGrey => "dimgrey",
+    DodgerBlue => "dodgerblue",
+    FireBrick => "firebrick",
+    FloralWhite => "floralwhite",
+    ForestGreen => "forestgreen",
+    Fuchsia => "fuchsia",
+    Gainsboro => "gainsboro",
+    GhostWhite => "ghostwhite",
+    Gold => "gold",
+    Goldenrod => "goldenrod",
+    Gray => "gray",
+    Green => "green",
+    GreenYellow => "greenyellow",
+    Grey => "grey",
+    Honeydew => "honeydew",
+    HotPink => "hotpink",
+    IndianRed => "indianred",
+    Indigo => "indigo",
+    Ivory => "ivory",
+    Khaki => "khaki",
+    Lavender => "lavender",
+    LavenderBlush => "lavenderblush",
+    LawnGreen => "lawngreen",
+    LemonChiffon => "lemonchiffon",
+    LightBlue => "lightblue",
+    LightCoral => "lightcoral",
+    LightCyan => "lightcyan",
+    LightGoldenrodYellow => "lightgoldenrodyellow",
+    LightGray => "lightgray",
+    LightGreen => "lightgreen",
+    LightGrey => "lightgrey",
+    LightPink => "lightpink",
+    LightSalmon => "lightsalmon",
+    LightSeaGreen => "lightseagreen",
+    LightSkyBlue => "lightskyblue",
+    LightSlateGray => "lightslategray",
+    LightSlateGrey => "lightslategrey",
+    LightSteelBlue => "lightsteelblue",
+    LightYellow => "lightyellow",
+    Lime => "lime",
+    LimeGreen => "limegreen",
+    Linen => "linen",
+    Magenta => "magenta",
+    Maroon => "maroon",
+    MediumAquamarine => "mediumaquamarine",
+    MediumBlue => "mediumblue",
+    MediumOrchid => "mediumorchid",
+    MediumPurple => "mediumpurple",
+    MediumSeaGreen => "mediumseagreen",
+    MediumSlateBlue => "mediumslateblue",
+    MediumSpringGreen => "mediumspringgreen",
+    MediumTurquoise => "mediumturquoise",
+    MediumVioletRed => "mediumvioletred",
+    MidnightBlue => "midnightblue",
+    MintCream => "mintcream",
+    MistyRose => "mistyrose",
+    Moccasin => "moccasin",
+    NavajoWhite => "navajowhite",
+    Navy => "navy",
+    OldLace => "oldlace",
+    Olive => "olive",
+    OliveDrab => "olivedrab",
+    Orange => "orange",
+    OrangeRed => "orangered",
+    Orchid => "orchid",
+    PaleGoldenrod => "palegoldenrod",
+    PaleGreen => "palegreen",
+    PaleTurquoise => "paleturquoise",
+    PaleVioletRed => "palevioletred",
+    PapayaWhip => "papayawhip",
+    PeachPuff => "peachpuff",
+    Peru => "peru",
+    Pink => "pink",
+    Plum => "plum",
+    PowderBlue => "powderblue",
+    Purple => "purple",
+    Red => "red",
+    RosyBrown => "rosybrown",
+    RoyalBlue => "royalblue",
+    SaddleBrown => "saddlebrown",
+    Salmon => "salmon",
+    SandyBrown => "sandybrown",
+    SeaGreen => "seagreen",
+    Seashell => "seashell",
+    Sienna => "sienna",
+    Silver => "silver",
+    SkyBlue => "skyblue",
+    SlateBlue => "slateblue",
+    SlateGray => "slategray",
+    SlateGrey => "slategrey",
+    Snow => "snow",
+    SpringGreen => "springgreen",
+    SteelBlue => "steelblue",
+    Tan => "tan",
+    Teal => "teal",
+    Thistle => "thistle",
+    Tomato => "tomato",
+    Turquoise => "turquoise",
+    Violet => "violet",
+    Wheat => "wheat",
+    White => "white",
+    WhiteSmoke => "whitesmoke",
+    Yellow => "yellow",
+    YellowGreen => "yellowgreen",
+}
+
+impl TryFrom<String> for Color {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+crate::ser::ical_for_tostring!(Color);
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn parse() {
+        assert_eq!("turquoise".parse(), Ok(crate::Color::Turquoise));
+        assert_eq!("DarkSlateGrey".parse(), Ok(crate::Color::DarkSlateGrey));
+        assert!("#ff0000".parse::<crate::Color>().is_err());
+    }
+
+    #[test]
+    fn ser() {
+        assert_eq!(
+            crate::ser::ical(&crate::Color::LightGoldenrodYellow),
+            "lightgoldenrodyellow"
+        );
+    }
+}
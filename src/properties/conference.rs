@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+
+/**
+ * See [5.11. CONFERENCE Property](https://datatracker.ietf.org/doc/html/rfc7986#section-5.11)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Conference {
+    pub params: BTreeMap<String, String>,
+    pub feature: Vec<Feature>,
+    pub label: Option<String>,
+    pub uri: String,
+}
+
+impl From<String> for Conference {
+    fn from(value: String) -> Self {
+        Self {
+            uri: value,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for Conference {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl crate::ser::Serialize for Conference {
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(&self.uri)
+    }
+
+    fn attr(&self) -> Option<String> {
+        let mut params = self.params.clone();
+        params.insert("VALUE".to_string(), "URI".to_string());
+
+        if let Some(label) = &self.label {
+            params.insert("LABEL".to_string(), label.clone());
+        }
+
+        let mut attr = Vec::new();
+
+        if !self.feature.is_empty() {
+            attr.push(crate::ser::param_list("FEATURE", &self.feature));
+        }
+
+        attr.push(crate::ser::params(&params));
+
+        Some(attr.join(";"))
+    }
+}
+
+/**
+ * See [6.3. Feature](https://datatracker.ietf.org/doc/html/rfc7986#section-6.3)
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Feature {
+    /** Audio capability */
+    Audio,
+    /** Chat or instant messaging */
+    Chat,
+    /** Blog or Atom feed */
+    Feed,
+    /** Moderator dial-in code */
+    Moderator,
+    /** Phone conference */
+    Phone,
+    /** Screen sharing */
+    Screen,
+    /** Video capability */
+    Video,
+    Custom(String),
+}
+
+impl TryFrom<String> for Feature {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Feature {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for Feature {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let feature = match s {
+            "AUDIO" => Self::Audio,
+            "CHAT" => Self::Chat,
+            "FEED" => Self::Feed,
+            "MODERATOR" => Self::Moderator,
+            "PHONE" => Self::Phone,
+            "SCREEN" => Self::Screen,
+            "VIDEO" => Self::Video,
+            f => Self::Custom(f.to_string()),
+        };
+
+        Ok(feature)
+    }
+}
+
+impl std::fmt::Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Audio => "AUDIO".to_string(),
+            Self::Chat => "CHAT".to_string(),
+            Self::Feed => "FEED".to_string(),
+            Self::Moderator => "MODERATOR".to_string(),
+            Self::Phone => "PHONE".to_string(),
+            Self::Screen => "SCREEN".to_string(),
+            Self::Video => "VIDEO".to_string(),
+            Self::Custom(s) => s.to_uppercase(),
+        };
+
+        f.write_str(&s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn ser() {
+        let conference = crate::Conference {
+            feature: vec![
+                crate::conference::Feature::Phone,
+                crate::conference::Feature::Moderator,
+            ],
+            label: Some("Moderator dial-in".to_string()),
+            ..crate::Conference::from("tel:+1-412-555-0123,,,654321")
+        };
+
+        assert_eq!(
+            crate::ser::ical(&conference),
+            "FEATURE=PHONE,MODERATOR;LABEL=Moderator dial-in;VALUE=URI:tel:+1-412-555-0123,,,654321"
+        );
+    }
+}
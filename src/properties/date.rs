@@ -1,14 +1,20 @@
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DateTime {
     Naive(chrono::NaiveDateTime),
     Local(chrono::DateTime<chrono::Local>),
+    /**
+     * Local time of the time zone identified by the `TZID` parameter.
+     *
+     * Until the time zone is resolved, it behaves as a floating time.
+     */
+    Zoned(chrono::NaiveDateTime, Tzid),
 }
 
 impl DateTime {
     #[must_use]
     pub fn date_naive(&self) -> chrono::NaiveDate {
         match self {
-            Self::Naive(date) => date.date(),
+            Self::Naive(date) | Self::Zoned(date, _) => date.date(),
             Self::Local(date) => date.date_naive(),
         }
     }
@@ -19,7 +25,7 @@ impl DateTime {
         fmt: &'a str,
     ) -> chrono::format::DelayedFormat<chrono::format::StrftimeItems<'a>> {
         match self {
-            Self::Naive(date) => date.format(fmt),
+            Self::Naive(date) | Self::Zoned(date, _) => date.format(fmt),
             Self::Local(date) => date.format(fmt),
         }
     }
@@ -27,10 +33,84 @@ impl DateTime {
     #[must_use]
     pub fn naive(&self) -> chrono::NaiveDateTime {
         match self {
-            Self::Naive(date) => *date,
+            Self::Naive(date) | Self::Zoned(date, _) => *date,
             Self::Local(date) => date.naive_local(),
         }
     }
+
+    /**
+     * Value of the `TZID` parameter.
+     */
+    #[must_use]
+    pub fn tzid(&self) -> Option<&str> {
+        match self {
+            Self::Zoned(_, tzid) => Some(tzid.as_str()),
+            Self::Naive(_) | Self::Local(_) => None,
+        }
+    }
+
+    /**
+     * Attaches a `TZID` to a floating time, others are returned unchanged.
+     */
+    #[must_use]
+    pub fn with_tzid(self, tzid: &str) -> Self {
+        match self {
+            Self::Naive(naive) => Self::Zoned(naive, Tzid::new(tzid)),
+            _ => self,
+        }
+    }
+}
+
+/**
+ * Value of a `TZID` parameter.
+ *
+ * The identifier is shared between the copies of a date-time.
+ */
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Tzid(std::sync::Arc<str>);
+
+impl Tzid {
+    #[must_use]
+    pub fn new(tzid: &str) -> Self {
+        Self(tzid.into())
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Tzid {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl std::ops::Deref for Tzid {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Tzid {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl std::fmt::Debug for Tzid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Display for Tzid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
 }
 
 impl Default for DateTime {
@@ -62,7 +142,7 @@ impl From<chrono::DateTime<chrono::Local>> for DateTime {
 impl From<DateTime> for chrono::NaiveDateTime {
     fn from(value: DateTime) -> Self {
         match value {
-            DateTime::Naive(naive) => naive,
+            DateTime::Naive(naive) | DateTime::Zoned(naive, _) => naive,
             DateTime::Local(local) => local.naive_local(),
         }
     }
@@ -73,7 +153,7 @@ impl TryFrom<DateTime> for chrono::DateTime<chrono::Local> {
 
     fn try_from(value: DateTime) -> Result<Self, Self::Error> {
         match value {
-            DateTime::Naive(naive) => naive
+            DateTime::Naive(naive) | DateTime::Zoned(naive, _) => naive
                 .and_local_timezone(chrono::Local)
                 .earliest()
                 .ok_or(crate::Error::Local(value)),
@@ -94,15 +174,33 @@ impl std::ops::Sub<chrono::Duration> for DateTime {
     type Output = chrono::NaiveDateTime;
 
     fn sub(self, rhs: chrono::Duration) -> Self::Output {
-        self.naive().sub(rhs)
+        saturating_sub(self.naive(), rhs)
+    }
+}
+
+impl std::ops::Sub for &DateTime {
+    type Output = chrono::Duration;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.naive().sub(rhs.naive())
+    }
+}
+
+impl std::ops::Sub<chrono::Duration> for &DateTime {
+    type Output = chrono::NaiveDateTime;
+
+    fn sub(self, rhs: chrono::Duration) -> Self::Output {
+        saturating_sub(self.naive(), rhs)
     }
 }
 
 impl std::fmt::Display for DateTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            DateTime::Naive(naive) => naive.format("%Y%m%dT%H%M%S").fmt(f),
-            DateTime::Local(local) => local.format("%Y%m%dT%H%M%SZ").fmt(f),
+            DateTime::Naive(naive) | DateTime::Zoned(naive, _) => {
+                naive.format("%Y%m%dT%H%M%S").fmt(f)
+            }
+            DateTime::Local(local) => local.to_utc().format("%Y%m%dT%H%M%SZ").fmt(f),
         }
     }
 }
@@ -116,9 +214,7 @@ impl std::str::FromStr for DateTime {
         } else {
             let naive = chrono::NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%SZ")?;
 
-            Ok(Self::Local(
-                naive.and_local_timezone(chrono::Local).unwrap(),
-            ))
+            Ok(Self::Local(naive.and_utc().with_timezone(&chrono::Local)))
         }
     }
 }
@@ -129,26 +225,90 @@ impl std::cmp::PartialOrd for DateTime {
     }
 }
 
+/**
+ * Date-times are ordered by wall clock, then floating before UTC before zoned ones.
+ */
 impl std::cmp::Ord for DateTime {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.naive().cmp(&other.naive())
+        fn tie(date_time: &DateTime) -> (u8, Option<chrono::NaiveDateTime>, Option<&Tzid>) {
+            match date_time {
+                DateTime::Naive(_) => (0, None, None),
+                DateTime::Local(local) => (1, Some(local.naive_utc()), None),
+                DateTime::Zoned(_, tzid) => (2, None, Some(tzid)),
+            }
+        }
+
+        self.naive()
+            .cmp(&other.naive())
+            .then_with(|| tie(self).cmp(&tie(other)))
     }
 }
 
+/**
+ * Saturates at [`chrono::NaiveDateTime::MIN`] and [`chrono::NaiveDateTime::MAX`].
+ */
 impl std::ops::Add<chrono::TimeDelta> for DateTime {
     type Output = Self;
 
     fn add(self, rhs: chrono::TimeDelta) -> Self::Output {
         match self {
-            Self::Naive(naive) => Self::Naive(naive + rhs),
-            Self::Local(local) => Self::Local(local + rhs),
+            Self::Naive(naive) => Self::Naive(saturating_add(naive, rhs)),
+            Self::Local(local) => Self::Local(
+                saturating_add(local.naive_utc(), rhs)
+                    .and_utc()
+                    .with_timezone(&chrono::Local),
+            ),
+            Self::Zoned(naive, tzid) => Self::Zoned(saturating_add(naive, rhs), tzid),
         }
     }
 }
 
-crate::ser::ical_for_tostring!(DateTime);
+fn saturating_add(naive: chrono::NaiveDateTime, rhs: chrono::TimeDelta) -> chrono::NaiveDateTime {
+    naive
+        .checked_add_signed(rhs)
+        .unwrap_or(if rhs < chrono::TimeDelta::zero() {
+            chrono::NaiveDateTime::MIN
+        } else {
+            chrono::NaiveDateTime::MAX
+        })
+}
+
+fn saturating_sub(naive: chrono::NaiveDateTime, rhs: chrono::TimeDelta) -> chrono::NaiveDateTime {
+    naive
+        .checked_sub_signed(rhs)
+        .unwrap_or(if rhs < chrono::TimeDelta::zero() {
+            chrono::NaiveDateTime::MAX
+        } else {
+            chrono::NaiveDateTime::MIN
+        })
+}
+
+/**
+ * Nominal days move the wall clock of a floating or zoned time, then the exact time is added.
+ * A UTC time has no wall clock and its days last 24 hours.
+ *
+ * Without its `VTIMEZONE`, a zoned time behaves as a floating one: the exact part ignores the
+ * daylight saving time transitions in between.
+ */
+impl std::ops::Add<crate::Duration> for DateTime {
+    type Output = Self;
+
+    fn add(self, rhs: crate::Duration) -> Self::Output {
+        self + chrono::TimeDelta::from(rhs)
+    }
+}
+
+impl crate::ser::Serialize for DateTime {
+    fn attr(&self) -> Option<String> {
+        self.tzid().map(|x| crate::ser::param("TZID", x))
+    }
+
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{self}")
+    }
+}
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Date {
     Date(chrono::NaiveDate),
     DateTime(DateTime),
@@ -178,6 +338,28 @@ impl Date {
     pub fn has_time(&self) -> bool {
         matches!(self, Self::DateTime(_))
     }
+
+    /**
+     * Value of the `TZID` parameter.
+     */
+    #[must_use]
+    pub fn tzid(&self) -> Option<&str> {
+        match self {
+            Self::Date(_) => None,
+            Self::DateTime(date_time) => date_time.tzid(),
+        }
+    }
+
+    /**
+     * Attaches a `TZID` to a floating date-time, others are returned unchanged.
+     */
+    #[must_use]
+    pub fn with_tzid(self, tzid: &str) -> Self {
+        match self {
+            Self::Date(_) => self,
+            Self::DateTime(date_time) => Self::DateTime(date_time.with_tzid(tzid)),
+        }
+    }
 }
 
 impl Default for Date {
@@ -251,8 +433,29 @@ impl std::cmp::Ord for Date {
         match (self, other) {
             (Self::Date(a), Self::Date(b)) => a.cmp(b),
             (Self::DateTime(a), Self::DateTime(b)) => a.cmp(b),
-            (Self::Date(a), Self::DateTime(b)) => a.cmp(&b.date_naive()),
-            (Self::DateTime(a), Self::Date(b)) => a.date_naive().cmp(b),
+            (Self::Date(a), Self::DateTime(b)) => {
+                a.cmp(&b.date_naive()).then(std::cmp::Ordering::Less)
+            }
+            (Self::DateTime(a), Self::Date(b)) => {
+                a.date_naive().cmp(b).then(std::cmp::Ordering::Greater)
+            }
+        }
+    }
+}
+
+/**
+ * A date stays a date as long as the duration has no time part.
+ */
+impl std::ops::Add<crate::Duration> for Date {
+    type Output = Self;
+
+    fn add(self, rhs: crate::Duration) -> Self::Output {
+        match self {
+            Self::Date(_) if rhs.seconds == 0 => self + chrono::TimeDelta::days(rhs.nominal_days()),
+            Self::Date(date) => {
+                Self::DateTime(DateTime::from(date.and_time(chrono::NaiveTime::MIN)) + rhs)
+            }
+            Self::DateTime(dt) => Self::DateTime(dt + rhs),
         }
     }
 }
@@ -262,7 +465,13 @@ impl std::ops::Add<chrono::TimeDelta> for Date {
 
     fn add(self, rhs: chrono::TimeDelta) -> Self::Output {
         match self {
-            Self::Date(date) => Self::Date(date + rhs),
+            Self::Date(date) => Self::Date(date.checked_add_signed(rhs).unwrap_or(
+                if rhs < chrono::TimeDelta::zero() {
+                    chrono::NaiveDate::MIN
+                } else {
+                    chrono::NaiveDate::MAX
+                },
+            )),
             Self::DateTime(dt) => Self::DateTime(dt + rhs),
         }
     }
@@ -272,12 +481,12 @@ impl crate::ser::Serialize for Date {
     fn attr(&self) -> Option<String> {
         match self {
             Date::Date(_) => "VALUE=DATE".to_string().into(),
-            Date::DateTime(_) => None,
+            Date::DateTime(date_time) => date_time.attr(),
         }
     }
 
-    fn ical(&self) -> String {
-        self.to_string()
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{self}")
     }
 }
 
@@ -295,6 +504,26 @@ mod test {
         assert_eq!(crate::ser::ical(&date_time), "19700101T000000");
 
         let date_time = crate::DateTime::Local(chrono::DateTime::default());
-        assert_eq!(crate::ser::ical(&date_time), "19700101T010000Z");
+        assert_eq!(crate::ser::ical(&date_time), "19700101T000000Z");
+    }
+
+    #[test]
+    fn ord() -> crate::Result {
+        let floating: crate::DateTime = "20240330T120000".parse()?;
+        let zoned = floating.clone().with_tzid("Europe/Paris");
+
+        assert_ne!(floating, zoned);
+        assert!(floating < zoned);
+        assert_eq!(
+            zoned.cmp(&floating.clone().with_tzid("Europe/Paris")),
+            std::cmp::Ordering::Equal
+        );
+        assert!(zoned < floating.clone().with_tzid("Europe/Rome"));
+
+        let date: crate::Date = "20240330".parse()?;
+        assert!(date < crate::Date::from(floating.clone()));
+        assert!(crate::Date::from(floating) > date);
+
+        Ok(())
     }
 }
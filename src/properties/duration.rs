@@ -0,0 +1,340 @@
+/**
+ * See [3.3.6. Duration](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6)
+ *
+ * Weeks and days are nominal: added to a zoned [`crate::DateTime`] they move
+ * the local date, whatever the daylight saving time transitions in between,
+ * while the time part is an exact number of seconds. `P1D` and `PT24H` are
+ * therefore different durations, a day lasts exactly 24 hours in UTC.
+ *
+ * The constructors and the multiplication saturate at [`u32::MAX`] of each
+ * part, adding a duration to a date-time saturates at its bounds.
+ */
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Duration {
+    pub negative: bool,
+    pub weeks: u32,
+    pub days: u32,
+    pub seconds: u32,
+}
+
+impl Duration {
+    #[must_use]
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn weeks(weeks: u32) -> Self {
+        Self {
+            weeks,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn days(days: u32) -> Self {
+        Self {
+            days,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn hours(hours: u32) -> Self {
+        Self::seconds(hours.saturating_mul(3_600))
+    }
+
+    #[must_use]
+    pub fn minutes(minutes: u32) -> Self {
+        Self::seconds(minutes.saturating_mul(60))
+    }
+
+    #[must_use]
+    pub fn seconds(seconds: u32) -> Self {
+        Self {
+            seconds,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.weeks == 0 && self.days == 0 && self.seconds == 0
+    }
+
+    /**
+     * Nominal part in days, weeks included.
+     */
+    #[must_use]
+    pub fn nominal_days(&self) -> i64 {
+        let days = i64::from(self.weeks) * 7 + i64::from(self.days);
+
+        if self.negative { -days } else { days }
+    }
+
+    /**
+     * Exact part.
+     */
+    #[must_use]
+    pub fn exact(&self) -> chrono::TimeDelta {
+        let seconds = chrono::TimeDelta::seconds(self.seconds.into());
+
+        if self.negative { -seconds } else { seconds }
+    }
+}
+
+impl TryFrom<String> for Duration {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Duration {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for Duration {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match crate::parser::datatype::duration(s) {
+            Ok(("", duration)) => Ok(duration),
+            _ => Err(crate::Error::ParseDuration(format!(
+                "Invalid duration: {s}"
+            ))),
+        }
+    }
+}
+
+/**
+ * Writes the canonical form, `P1W`, `P1DT2H` or `-PT15M`.
+ */
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative && !self.is_zero() {
+            f.write_str("-")?;
+        }
+
+        f.write_str("P")?;
+
+        if self.is_zero() {
+            return f.write_str("T0S");
+        }
+
+        // dur-week can’t be mixed with other parts
+        if self.days == 0 && self.seconds == 0 {
+            return write!(f, "{}W", self.weeks);
+        }
+
+        let days = u64::from(self.weeks) * 7 + u64::from(self.days);
+
+        if days > 0 {
+            write!(f, "{days}D")?;
+        }
+
+        if self.seconds > 0 {
+            let hours = self.seconds / 3_600;
+            let minutes = self.seconds % 3_600 / 60;
+            let seconds = self.seconds % 60;
+
+            f.write_str("T")?;
+
+            if hours > 0 {
+                write!(f, "{hours}H")?;
+            }
+            if minutes > 0 || (hours > 0 && seconds > 0) {
+                write!(f, "{minutes}M")?;
+            }
+            if seconds > 0 {
+                write!(f, "{seconds}S")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+crate::ser::ical_for_tostring!(Duration);
+
+impl std::ops::Neg for Duration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            negative: !self.negative,
+            ..self
+        }
+    }
+}
+
+impl std::ops::Mul<u32> for Duration {
+    type Output = Self;
+
+    fn mul(self, rhs: u32) -> Self::Output {
+        Self {
+            negative: self.negative,
+            weeks: self.weeks.saturating_mul(rhs),
+            days: self.days.saturating_mul(rhs),
+            seconds: self.seconds.saturating_mul(rhs),
+        }
+    }
+}
+
+/**
+ * Exact conversion, the sub-second part is dropped.
+ */
+impl From<chrono::TimeDelta> for Duration {
+    fn from(value: chrono::TimeDelta) -> Self {
+        let seconds = value.num_seconds();
+
+        Self {
+            negative: seconds < 0,
+            seconds: seconds.unsigned_abs().try_into().unwrap_or(u32::MAX),
+            ..Self::default()
+        }
+    }
+}
+
+/**
+ * Approximate conversion, nominal days last 24 hours.
+ */
+impl From<Duration> for chrono::TimeDelta {
+    fn from(value: Duration) -> Self {
+        chrono::TimeDelta::days(value.nominal_days()) + value.exact()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn parse() -> crate::Result {
+        let duration: crate::Duration = "P1W".parse()?;
+        assert_eq!(duration, crate::Duration::weeks(1));
+
+        let duration: crate::Duration = "-P1DT2H3M4S".parse()?;
+        assert_eq!(
+            duration,
+            crate::Duration {
+                negative: true,
+                weeks: 0,
+                days: 1,
+                seconds: 7_384,
+            }
+        );
+
+        assert_ne!(
+            "P1D".parse::<crate::Duration>()?,
+            "PT24H".parse::<crate::Duration>()?
+        );
+        assert!("P1Y".parse::<crate::Duration>().is_err());
+        assert!("P1M".parse::<crate::Duration>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ser() {
+        let tests = [
+            (crate::Duration::zero(), "PT0S"),
+            (crate::Duration::weeks(2), "P2W"),
+            (crate::Duration::days(1), "P1D"),
+            (crate::Duration::hours(24), "PT24H"),
+            (-crate::Duration::minutes(15), "-PT15M"),
+            (crate::Duration::seconds(3_605), "PT1H0M5S"),
+            (
+                crate::Duration {
+                    negative: false,
+                    weeks: 0,
+                    days: 8,
+                    seconds: 7_200,
+                },
+                "P8DT2H",
+            ),
+        ];
+
+        for (duration, expected) in tests {
+            assert_eq!(crate::ser::ical(&duration), expected);
+            assert_eq!(expected.parse::<crate::Duration>(), Ok(duration));
+        }
+
+        let duration = crate::Duration {
+            negative: false,
+            weeks: 1,
+            days: 1,
+            seconds: 0,
+        };
+        assert_eq!(crate::ser::ical(&duration), "P8D");
+
+        let duration = crate::Duration {
+            negative: false,
+            weeks: u32::MAX,
+            days: 1,
+            seconds: 0,
+        };
+        assert_eq!(crate::ser::ical(&duration), "P30064771066D");
+    }
+
+    #[test]
+    fn days() -> crate::Result {
+        let utc: crate::DateTime = "20240330T110000Z".parse()?;
+        assert_eq!(
+            (utc.clone() + crate::Duration::days(1)).to_string(),
+            "20240331T110000Z"
+        );
+        assert_eq!(
+            utc.clone() + crate::Duration::days(1),
+            utc + crate::Duration::hours(24)
+        );
+
+        let floating: crate::DateTime = "20240330T120000".parse()?;
+        assert_eq!(
+            (floating.clone() + crate::Duration::days(1)).to_string(),
+            "20240331T120000"
+        );
+        assert_eq!(
+            (floating + -crate::Duration::weeks(1)).to_string(),
+            "20240323T120000"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn saturate() -> crate::Result {
+        assert_eq!(crate::Duration::hours(u32::MAX).seconds, u32::MAX);
+        assert_eq!(crate::Duration::minutes(u32::MAX).seconds, u32::MAX);
+        assert_eq!((crate::Duration::days(2) * u32::MAX).days, u32::MAX);
+
+        let date_time: crate::DateTime = "20240330T120000".parse()?;
+        assert_eq!(
+            (date_time.clone() + crate::Duration::weeks(u32::MAX)).naive(),
+            chrono::NaiveDateTime::MAX
+        );
+        assert_eq!(
+            (date_time + -crate::Duration::weeks(u32::MAX)).naive(),
+            chrono::NaiveDateTime::MIN
+        );
+
+        let utc: crate::DateTime = "20240330T120000Z".parse()?;
+        assert_eq!(
+            (utc + crate::Duration::weeks(u32::MAX)).to_string(),
+            chrono::NaiveDateTime::MAX
+                .format("%Y%m%dT%H%M%SZ")
+                .to_string()
+        );
+
+        let date: crate::Date = "20240330".parse()?;
+        assert_eq!(
+            date + crate::Duration::weeks(u32::MAX),
+            crate::Date::Date(chrono::NaiveDate::MAX)
+        );
+
+        Ok(())
+    }
+}
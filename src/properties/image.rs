@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+
+/**
+ * See [5.10. IMAGE Property](https://datatracker.ietf.org/doc/html/rfc7986#section-5.10)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Image {
+    pub params: BTreeMap<String, String>,
+    pub altrep: Option<String>,
+    pub display: Vec<Display>,
+    pub fmttype: Option<String>,
+    pub value: Value,
+}
+
+impl From<String> for Image {
+    fn from(value: String) -> Self {
+        Self {
+            value: Value::Uri(value),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for Image {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl crate::ser::Serialize for Image {
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{}", self.value)
+    }
+
+    fn attr(&self) -> Option<String> {
+        let mut params = self.params.clone();
+
+        match self.value {
+            Value::Uri(_) => {
+                params.insert("VALUE".to_string(), "URI".to_string());
+            }
+            Value::Binary(_) => {
+                params.insert("VALUE".to_string(), "BINARY".to_string());
+                params.insert("ENCODING".to_string(), "BASE64".to_string());
+            }
+        }
+
+        if let Some(altrep) = &self.altrep {
+            params.insert("ALTREP".to_string(), altrep.clone());
+        }
+
+        if let Some(fmttype) = &self.fmttype {
+            params.insert("FMTTYPE".to_string(), fmttype.clone());
+        }
+
+        let mut attr = Vec::new();
+
+        if !self.display.is_empty() {
+            attr.push(crate::ser::param_list("DISPLAY", &self.display));
+        }
+
+        attr.push(crate::ser::params(&params));
+
+        Some(attr.join(";"))
+    }
+}
+
+/**
+ * Image data, either referenced by an URI or inlined as base64.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Value {
+    Uri(String),
+    Binary(String),
+}
+
+impl Default for Value {
+    fn default() -> Self {
+        Self::Uri(String::new())
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Uri(uri) => f.write_str(uri),
+            Self::Binary(data) => f.write_str(data),
+        }
+    }
+}
+
+/**
+ * See [6.1. Display](https://datatracker.ietf.org/doc/html/rfc7986#section-6.1)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum Display {
+    /** Image that is displayed inline with the title of the event */
+    #[default]
+    Badge,
+    /** Full image replacement for the event itself */
+    Graphic,
+    /** Image that is used to enhance the event */
+    Fullsize,
+    /** A smaller variant of "FULLSIZE" to be used when space for the image is constrained */
+    Thumbnail,
+    Custom(String),
+}
+
+impl TryFrom<String> for Display {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Display {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for Display {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let display = match s {
+            "BADGE" => Self::Badge,
+            "GRAPHIC" => Self::Graphic,
+            "FULLSIZE" => Self::Fullsize,
+            "THUMBNAIL" => Self::Thumbnail,
+            d => Self::Custom(d.to_string()),
+        };
+
+        Ok(display)
+    }
+}
+
+impl std::fmt::Display for Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Badge => "BADGE".to_string(),
+            Self::Graphic => "GRAPHIC".to_string(),
+            Self::Fullsize => "FULLSIZE".to_string(),
+            Self::Thumbnail => "THUMBNAIL".to_string(),
+            Self::Custom(s) => s.to_uppercase(),
+        };
+
+        f.write_str(&s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn ser() {
+        let image = crate::Image {
+            display: vec![crate::image::Display::Badge],
+            fmttype: Some("image/png".to_string()),
+            ..crate::Image::from("http://example.com/images/party.png")
+        };
+        assert_eq!(
+            crate::ser::ical(&image),
+            "DISPLAY=BADGE;FMTTYPE=image/png;VALUE=URI:http://example.com/images/party.png"
+        );
+
+        let image = crate::Image {
+            altrep: Some("http://example.com/party.png".to_string()),
+            value: crate::image::Value::Binary("iVBORw0KGgo=".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            crate::ser::ical(&image),
+            "ALTREP=\"http://example.com/party.png\";ENCODING=BASE64;VALUE=BINARY:iVBORw0KGgo="
+        );
+    }
+}
@@ -0,0 +1,100 @@
+/**
+ * See [3.7.2. Method](https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.2) and
+ * [1.4. Methods](https://datatracker.ietf.org/doc/html/rfc5546#section-1.4)
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Method {
+    /** Post notification of an event/to-do */
+    Publish,
+    /** Make a request for an event/to-do */
+    Request,
+    /** Reply to an event/to-do request */
+    Reply,
+    /** Add one or more new instances to an existing recurring event/to-do */
+    Add,
+    /** Cancel one or more instances of an existing event/to-do */
+    Cancel,
+    /** Request the latest version of an event/to-do */
+    Refresh,
+    /** Propose changes to an existing event/to-do */
+    Counter,
+    /** Decline a counter proposal */
+    DeclineCounter,
+    Custom(String),
+}
+
+impl TryFrom<String> for Method {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Method {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for Method {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let method = match s.to_uppercase().as_str() {
+            "PUBLISH" => Self::Publish,
+            "REQUEST" => Self::Request,
+            "REPLY" => Self::Reply,
+            "ADD" => Self::Add,
+            "CANCEL" => Self::Cancel,
+            "REFRESH" => Self::Refresh,
+            "COUNTER" => Self::Counter,
+            "DECLINECOUNTER" => Self::DeclineCounter,
+            _ => Self::Custom(s.to_string()),
+        };
+
+        Ok(method)
+    }
+}
+
+impl std::fmt::Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Publish => "PUBLISH".to_string(),
+            Self::Request => "REQUEST".to_string(),
+            Self::Reply => "REPLY".to_string(),
+            Self::Add => "ADD".to_string(),
+            Self::Cancel => "CANCEL".to_string(),
+            Self::Refresh => "REFRESH".to_string(),
+            Self::Counter => "COUNTER".to_string(),
+            Self::DeclineCounter => "DECLINECOUNTER".to_string(),
+            Self::Custom(s) => s.to_uppercase(),
+        };
+
+        f.write_str(&s)
+    }
+}
+
+crate::ser::ical_for_tostring!(Method);
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn parse() {
+        assert_eq!("publish".parse(), Ok(crate::Method::Publish));
+        assert_eq!(
+            "X-FOO".parse(),
+            Ok(crate::Method::Custom("X-FOO".to_string()))
+        );
+    }
+
+    #[test]
+    fn ser() {
+        assert_eq!(
+            crate::ser::ical(&crate::Method::DeclineCounter),
+            "DECLINECOUNTER"
+        );
+    }
+}
@@ -2,23 +2,35 @@
  * See [3.8. Component Properties](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8)
  */
 mod class;
+mod color;
 mod date;
+mod duration;
 mod geo;
+mod method;
+mod proximity;
 mod rdate;
 mod recur;
 mod request_status;
 mod status;
 mod text;
 mod time_transparency;
-mod trigger;
 mod uri;
 
+pub mod conference;
+pub mod image;
 pub mod period;
+pub mod trigger;
 
 pub use class::*;
+pub use color::*;
+pub use conference::Conference;
 pub use date::*;
+pub use duration::Duration;
 pub use geo::*;
+pub use image::Image;
+pub use method::*;
 pub use period::Period;
+pub use proximity::*;
 pub use rdate::*;
 pub use recur::*;
 pub use request_status::*;
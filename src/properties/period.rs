@@ -2,17 +2,39 @@
  * See [3.3.9. Period of Time](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.9)
  */
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Period {
     StartEnd(StartEnd),
     StartDur(StartDur),
 }
 
 impl Period {
+    /**
+     * Exact duration, a nominal [`crate::Duration`] is resolved from the start.
+     */
     #[must_use]
     pub fn duration(&self) -> chrono::Duration {
         match self {
-            Self::StartEnd(StartEnd { start, end }) => *end - *start,
-            Self::StartDur(StartDur { duration, .. }) => *duration,
+            Self::StartEnd(StartEnd { start, end }) => end - start,
+            Self::StartDur(StartDur { start, duration }) => &(start.clone() + *duration) - start,
+        }
+    }
+
+    /**
+     * Attaches a `TZID` to floating date-times.
+     */
+    #[must_use]
+    pub fn with_tzid(self, tzid: &str) -> Self {
+        match self {
+            Self::StartEnd(StartEnd { start, end }) => Self::StartEnd(StartEnd {
+                start: start.with_tzid(tzid),
+                end: end.with_tzid(tzid),
+            }),
+            Self::StartDur(StartDur { start, duration }) => Self::StartDur(StartDur {
+                start: start.with_tzid(tzid),
+                duration,
+            }),
         }
     }
 }
@@ -67,6 +89,7 @@ impl std::fmt::Display for Period {
 crate::ser::ical_for_tostring!(Period);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct StartEnd {
     pub start: crate::DateTime,
     pub end: crate::DateTime,
@@ -80,8 +103,8 @@ impl std::cmp::PartialOrd for StartEnd {
 
 impl std::cmp::Ord for StartEnd {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let a = self.end - self.start;
-        let b = other.end - other.start;
+        let a = &self.end - &self.start;
+        let b = &other.end - &other.start;
 
         a.cmp(&b)
     }
@@ -96,9 +119,10 @@ impl std::fmt::Display for StartEnd {
 crate::ser::ical_for_tostring!(StartEnd);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct StartDur {
     pub start: crate::DateTime,
-    pub duration: chrono::Duration,
+    pub duration: crate::Duration,
 }
 
 impl std::cmp::PartialOrd for StartDur {
@@ -109,8 +133,8 @@ impl std::cmp::PartialOrd for StartDur {
 
 impl std::cmp::Ord for StartDur {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let a = self.start - self.duration;
-        let b = other.start - other.duration;
+        let a = &self.start - chrono::TimeDelta::from(self.duration);
+        let b = &other.start - chrono::TimeDelta::from(other.duration);
 
         a.cmp(&b)
     }
@@ -136,8 +160,8 @@ mod test {
 
         let period = crate::Period::StartDur(crate::period::StartDur {
             start: crate::DateTime::default(),
-            duration: chrono::TimeDelta::hours(5),
+            duration: crate::Duration::hours(5),
         });
-        assert_eq!(crate::ser::ical(&period), "19700101T000000/PT18000S");
+        assert_eq!(crate::ser::ical(&period), "19700101T000000/PT5H");
     }
 }
@@ -0,0 +1,71 @@
+/**
+ * See [8.1. Proximity Property](https://datatracker.ietf.org/doc/html/rfc9074#section-8.1)
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Proximity {
+    /** Triggers when the user arrives at the location */
+    Arrive,
+    /** Triggers when the user leaves the location */
+    Depart,
+    /** Triggers when the device connects to the location (e.g. a car bluetooth) */
+    Connect,
+    /** Triggers when the device disconnects from the location */
+    Disconnect,
+    Custom(String),
+}
+
+impl TryFrom<String> for Proximity {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Proximity {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for Proximity {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let proximity = match s {
+            "ARRIVE" => Self::Arrive,
+            "DEPART" => Self::Depart,
+            "CONNECT" => Self::Connect,
+            "DISCONNECT" => Self::Disconnect,
+            p => Self::Custom(p.to_string()),
+        };
+
+        Ok(proximity)
+    }
+}
+
+impl std::fmt::Display for Proximity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Arrive => "ARRIVE".to_string(),
+            Self::Depart => "DEPART".to_string(),
+            Self::Connect => "CONNECT".to_string(),
+            Self::Disconnect => "DISCONNECT".to_string(),
+            Self::Custom(s) => s.to_uppercase(),
+        };
+
+        f.write_str(&s)
+    }
+}
+
+crate::ser::ical_for_tostring!(Proximity);
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn ser() {
+        assert_eq!(crate::ser::ical(&crate::Proximity::Arrive), "ARRIVE");
+    }
+}
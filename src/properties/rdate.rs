@@ -1,4 +1,6 @@
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum RDate {
     Date(Vec<crate::Date>),
     Period(Vec<crate::Period>),
@@ -17,31 +19,56 @@ impl RDate {
     }
 }
 
+impl RDate {
+    fn value(&self) -> Option<&'static str> {
+        match self {
+            RDate::Date(date) if date.iter().all(|x| !x.has_time()) => Some("DATE"),
+            RDate::Date(_) => None,
+            RDate::Period(_) => Some("PERIOD"),
+        }
+    }
+
+    fn tzid(&self) -> Option<&str> {
+        match self {
+            RDate::Date(date) => date.iter().find_map(crate::Date::tzid),
+            RDate::Period(period) => period.iter().find_map(|x| match x {
+                crate::Period::StartEnd(x) => x.start.tzid(),
+                crate::Period::StartDur(x) => x.start.tzid(),
+            }),
+        }
+    }
+}
+
 impl std::fmt::Display for RDate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match self {
-            RDate::Date(date) => format!(
-                "VALUE=DATE:{}",
-                date.iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>()
-                    .join(",")
-            ),
-            RDate::Period(period) => format!(
-                "VALUE=PERIOD:{}",
-                period
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>()
-                    .join(",")
-            ),
-        };
-
-        f.write_str(&s)
+        f.write_str(&crate::ser::ical(self))
     }
 }
 
-crate::ser::ical_for_tostring!(RDate);
+impl crate::ser::Serialize for RDate {
+    fn attr(&self) -> Option<String> {
+        let attr = [
+            self.value().map(|x| format!("VALUE={x}")),
+            self.tzid().map(|x| crate::ser::param("TZID", x)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if attr.is_empty() {
+            None
+        } else {
+            Some(attr.join(";"))
+        }
+    }
+
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        match self {
+            RDate::Date(date) => date.write_ical(w),
+            RDate::Period(period) => period.write_ical(w),
+        }
+    }
+}
 
 #[cfg(test)]
 mod test {
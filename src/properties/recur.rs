@@ -4,6 +4,8 @@ use chrono::Datelike as _;
  * See [3.3.10. Recurrence Rule](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.5.3)
  */
 #[derive(Clone, Debug, Eq, PartialEq, crate::Serialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Recur {
     pub freq: Freq,
     pub until: Option<crate::Date>,
@@ -119,6 +121,7 @@ impl std::ops::Add<crate::DateTime> for crate::Recur {
         match rhs {
             crate::DateTime::Naive(date) => crate::DateTime::Naive(self + date),
             crate::DateTime::Local(date) => crate::DateTime::Local(self + date),
+            crate::DateTime::Zoned(date, tzid) => crate::DateTime::Zoned(self + date, tzid),
         }
     }
 }
@@ -324,6 +327,20 @@ impl std::fmt::Display for Weekday {
 
 crate::ser::ical_for_tostring!(Weekday);
 
+impl From<&Weekday> for chrono::Weekday {
+    fn from(value: &Weekday) -> Self {
+        match value {
+            Weekday::Sunday => Self::Sun,
+            Weekday::Monday => Self::Mon,
+            Weekday::Tuesday => Self::Tue,
+            Weekday::Wenesday => Self::Wed,
+            Weekday::Thurday => Self::Thu,
+            Weekday::Friday => Self::Fri,
+            Weekday::Saturday => Self::Sat,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -2,6 +2,7 @@
  * See [3.8.8.3. Request Status](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.8.3)
  */
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct RequestStatus {
     pub statcode: f32,
     pub statdesc: String,
@@ -33,19 +34,16 @@ impl std::str::FromStr for RequestStatus {
 }
 
 impl crate::ser::Serialize for RequestStatus {
-    fn ical(&self) -> String {
-        let mut s = format!(
-            "{:.1};{}",
-            self.statcode,
-            crate::ser::escape(&self.statdesc)
-        );
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{:.1};", self.statcode)?;
+        crate::ser::write_escaped(w, &self.statdesc)?;
 
         if let Some(extdata) = &self.extdata {
-            s.push(';');
-            s.push_str(&crate::ser::escape(extdata));
+            w.write_char(';')?;
+            crate::ser::write_escaped(w, extdata)?;
         }
 
-        s
+        Ok(())
     }
 }
 
@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
  * See [3.3.11. Text](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.11)
  */
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Text {
     pub params: BTreeMap<String, String>,
     pub text: String,
@@ -23,7 +24,7 @@ impl From<crate::ContentLine> for Text {
     fn from(value: crate::ContentLine) -> Self {
         Self {
             params: value.params,
-            text: value.value,
+            text: crate::ser::unescape(&value.value),
         }
     }
 }
@@ -58,15 +59,15 @@ impl From<&str> for Text {
 }
 
 impl crate::ser::Serialize for Text {
-    fn ical(&self) -> String {
-        self.to_string().ical()
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        crate::ser::write_escaped(w, &self.text)
     }
 
     fn attr(&self) -> Option<String> {
         if self.params.is_empty() {
             None
         } else {
-            Some(self.params.ical())
+            Some(crate::ser::params(&self.params))
         }
     }
 }
@@ -104,4 +105,20 @@ Come Prepared.",
             "TZID=Europe/Paris;VALUE=DATE-TIME:20150219T190000"
         );
     }
+
+    #[test]
+    fn unescape() {
+        let text: crate::Text =
+            crate::ContentLine::from("Project XYZ \\; Final Review\\nC:\\\\Documents\\, \\'B\\'")
+                .into();
+
+        assert_eq!(
+            text.text,
+            "Project XYZ ; Final Review\nC:\\Documents, \\'B\\'"
+        );
+        assert_eq!(
+            crate::ser::ical(&text),
+            "Project XYZ \\; Final Review\\nC:\\\\Documents\\, \\\\'B\\\\'"
+        );
+    }
 }
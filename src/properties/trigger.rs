@@ -5,12 +5,12 @@
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Trigger {
     DateTime(crate::DateTime),
-    Duration(chrono::Duration),
+    Duration(crate::Duration, Related),
 }
 
 impl Default for Trigger {
     fn default() -> Self {
-        Self::Duration(chrono::Duration::zero())
+        Self::Duration(crate::Duration::zero(), Related::default())
     }
 }
 
@@ -28,29 +28,84 @@ impl std::str::FromStr for Trigger {
         if let Ok(dt) = crate::DateTime::from_str(s) {
             Ok(Self::DateTime(dt))
         } else {
-            crate::parse_duration(s).map(Self::Duration)
+            s.parse().map(|x| Self::Duration(x, Related::default()))
         }
     }
 }
 
 impl crate::ser::Serialize for Trigger {
-    fn ical(&self) -> String {
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
         match self {
-            Self::DateTime(dt) => dt.ical(),
-            Self::Duration(duration) => duration.ical(),
+            Self::DateTime(dt) => dt.write_ical(w),
+            Self::Duration(duration, _) => duration.write_ical(w),
         }
     }
 
     fn attr(&self) -> Option<String> {
         let attr = match self {
             Self::DateTime(_) => "VALUE=DATE-TIME",
-            Self::Duration(_) => "VALUE=DURATION",
+            Self::Duration(_, Related::Start) => "VALUE=DURATION",
+            Self::Duration(_, Related::End) => "RELATED=END;VALUE=DURATION",
         };
 
         attr.to_string().into()
     }
 }
 
+/**
+ * See [3.2.14. Alarm Trigger Relationship](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.14)
+ */
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Related {
+    /** Trigger off of start */
+    #[default]
+    Start,
+    /** Trigger off of end */
+    End,
+}
+
+impl TryFrom<String> for Related {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Related {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for Related {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let related = match s {
+            "START" => Self::Start,
+            "END" => Self::End,
+
+            _ => return Err(crate::Error::Related(s.to_string())),
+        };
+
+        Ok(related)
+    }
+}
+
+impl std::fmt::Display for Related {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Start => "START",
+            Self::End => "END",
+        };
+
+        f.write_str(s)
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -61,8 +116,16 @@ mod test {
             "VALUE=DATE-TIME:19980101T050000Z"
         );
 
-        let trigger = crate::Trigger::Duration(chrono::Duration::days(-15));
-        assert_eq!(crate::ser::ical(&trigger), "VALUE=DURATION:-PT1296000S");
+        let trigger =
+            crate::Trigger::Duration(-crate::Duration::days(15), crate::trigger::Related::Start);
+        assert_eq!(crate::ser::ical(&trigger), "VALUE=DURATION:-P15D");
+
+        let trigger =
+            crate::Trigger::Duration(-crate::Duration::minutes(5), crate::trigger::Related::End);
+        assert_eq!(
+            crate::ser::ical(&trigger),
+            "RELATED=END;VALUE=DURATION:-PT5M"
+        );
 
         Ok(())
     }
@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
  * See [3.3.13. URI](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.13)
  */
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Uri {
     pub params: BTreeMap<String, String>,
     pub uri: String,
@@ -51,15 +52,15 @@ impl From<&str> for Uri {
 }
 
 impl crate::ser::Serialize for Uri {
-    fn ical(&self) -> String {
-        self.to_string().ical()
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(&self.uri)
     }
 
     fn attr(&self) -> Option<String> {
         if self.params.is_empty() {
             None
         } else {
-            Some(self.params.ical())
+            Some(crate::ser::params(&self.params))
         }
     }
 }
@@ -83,4 +84,21 @@ mod test {
             "RSVP=TRUE:mailto:someone@example.com"
         );
     }
+
+    #[test]
+    fn round_trip() -> crate::Result {
+        let ical = "BEGIN:VEVENT\r
+DTSTAMP:20210517T051025Z\r
+UID:1\r
+DTSTART:20210517T090000Z\r
+URL:http://x.com/?a=1,2;b\\c\r
+END:VEVENT\r
+";
+        let vevent: crate::VEvent = ical.parse()?;
+
+        assert_eq!(vevent.url.as_deref(), Some("http://x.com/?a=1,2;b\\c"));
+        assert_eq!(crate::ser::ical(&vevent), ical);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,538 @@
+/*!
+ * Alarm scheduling on top of [`crate::iter::Alarms`].
+ *
+ * Acknowledgements and snoozes are written back into the components, as described in
+ * [RFC 9074](https://datatracker.ietf.org/doc/html/rfc9074). The calendars have to be saved along
+ * with the scheduler [`State`] to avoid firing old reminders again after a restart.
+ */
+
+pub trait Clock {
+    fn now(&self) -> crate::DateTime;
+}
+
+/**
+ * Wall clock, truncated to the second.
+ */
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> crate::DateTime {
+        use chrono::Timelike as _;
+
+        let now = chrono::Local::now();
+
+        now.with_nanosecond(0).unwrap_or(now).into()
+    }
+}
+
+impl<F: Fn() -> crate::DateTime> Clock for F {
+    fn now(&self) -> crate::DateTime {
+        self()
+    }
+}
+
+/**
+ * Persistent part of an [`AlarmScheduler`].
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct State {
+    /** Alarms up to this instant have already been delivered */
+    pub checkpoint: Option<crate::DateTime>,
+}
+
+impl std::str::FromStr for State {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut state = Self::default();
+
+        for line in s.lines().filter(|x| !x.is_empty()) {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| crate::Error::Parser(format!("Invalid state line: {line}")))?;
+
+            match key {
+                "CHECKPOINT" => state.checkpoint = Some(value.parse()?),
+                key => return Err(crate::Error::Key(key.to_string())),
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(checkpoint) = &self.checkpoint {
+            write!(f, "CHECKPOINT:{checkpoint}\r\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Target {
+    Event(usize, usize),
+    Todo(usize, usize),
+}
+
+/**
+ * An alarm to deliver.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct Due {
+    pub instant: crate::DateTime,
+    pub alarm: crate::VAlarm,
+    /** Start of the occurrence the alarm belongs to */
+    pub occurrence: Option<crate::Date>,
+    /** UID of the event or to-do */
+    pub uid: crate::Text,
+    target: Target,
+    /** UTC time of `instant`, the alarms are compared with */
+    utc: chrono::NaiveDateTime,
+}
+
+impl Due {
+    fn from<T: crate::iter::Alarming>(
+        alarm: crate::iter::Alarm<T>,
+        uid: &crate::Text,
+        target: Target,
+        timezones: &[crate::VTimezone],
+    ) -> Self {
+        Self {
+            utc: crate::convert::instant(&alarm.instant, timezones),
+            instant: alarm.instant,
+            occurrence: alarm.occurrence.dtstart().cloned(),
+            alarm: alarm.alarm,
+            uid: uid.clone(),
+            target,
+        }
+    }
+
+    fn is_acknowledged(&self, timezones: &[crate::VTimezone]) -> bool {
+        self.alarm.acknowledged().is_some_and(|acknowledged| {
+            crate::convert::instant(acknowledged, timezones) >= self.utc
+        })
+    }
+}
+
+/**
+ * Watches the alarms of a set of calendars.
+ *
+ * ```
+ * let calendar: ikal::VCalendar = std::fs::read_to_string("tests/calendars/1.ics")?.try_into()?;
+ * let now: ikal::DateTime = "20240101T090000".parse()?;
+ *
+ * let mut scheduler = ikal::scheduler::AlarmScheduler::with_clock(vec![calendar], move || now.clone());
+ *
+ * for due in scheduler.missed() {
+ *     // notify the user
+ * }
+ * scheduler.checkpoint();
+ *
+ * let next = scheduler.next_due();
+ * # Ok::<(), Box<dyn std::error::Error>>(())
+ * ```
+ */
+pub struct AlarmScheduler<C: Clock = SystemClock> {
+    calendars: Vec<crate::VCalendar>,
+    state: State,
+    clock: C,
+}
+
+impl AlarmScheduler<SystemClock> {
+    #[must_use]
+    pub fn new(calendars: Vec<crate::VCalendar>) -> Self {
+        Self::with_clock(calendars, SystemClock)
+    }
+}
+
+impl<C: Clock> AlarmScheduler<C> {
+    #[must_use]
+    pub fn with_clock(calendars: Vec<crate::VCalendar>, clock: C) -> Self {
+        Self {
+            calendars,
+            state: State::default(),
+            clock,
+        }
+    }
+
+    #[must_use]
+    pub fn with_state(mut self, state: State) -> Self {
+        self.state = state;
+        self
+    }
+
+    #[must_use]
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    #[must_use]
+    pub fn calendars(&self) -> &[crate::VCalendar] {
+        &self.calendars
+    }
+
+    #[must_use]
+    pub fn into_calendars(self) -> Vec<crate::VCalendar> {
+        self.calendars
+    }
+
+    /**
+     * Returns the first alarm firing after now.
+     */
+    #[must_use]
+    pub fn next_due(&self) -> Option<Due> {
+        let now = crate::convert::instant(&self.clock.now(), &[]);
+
+        self.instants()
+            .into_iter()
+            .filter_map(|mut alarms| alarms.find(|x| x.utc > now))
+            .min_by_key(|x| x.utc)
+    }
+
+    /**
+     * Returns the alarms fired between the checkpoint and now.
+     *
+     * Nothing is missed until the first [`AlarmScheduler::checkpoint`].
+     */
+    #[must_use]
+    pub fn missed(&self) -> Vec<Due> {
+        let Some(checkpoint) = &self.state.checkpoint else {
+            return Vec::new();
+        };
+        let checkpoint = crate::convert::instant(checkpoint, &[]);
+        let now = crate::convert::instant(&self.clock.now(), &[]);
+
+        let mut missed = self
+            .instants()
+            .into_iter()
+            .flat_map(|alarms| {
+                alarms
+                    .skip_while(move |x| x.utc <= checkpoint)
+                    .take_while(move |x| x.utc <= now)
+            })
+            .collect::<Vec<_>>();
+
+        missed.sort_by_key(|a| a.utc);
+
+        missed
+    }
+
+    /**
+     * Marks every alarm fired until now as delivered.
+     */
+    pub fn checkpoint(&mut self) {
+        self.state.checkpoint = Some(self.clock.now());
+    }
+
+    /**
+     * Acknowledges the alarm and fires it again after `duration`.
+     */
+    pub fn snooze(&mut self, due: &Due, duration: chrono::Duration) -> crate::Result {
+        let now = self.clock.now();
+        let (alarms, uid) = self.acknowledge(due, now.clone())?;
+
+        let original = alarms
+            .iter()
+            .find(|x| x.uid().map(|x| x.as_ref()) == Some(uid.as_str()))
+            .cloned()
+            .ok_or_else(|| crate::Error::AlarmNotFound(uid.clone()))?;
+
+        let mut related_to = crate::Text::from(uid.as_str());
+        related_to
+            .params
+            .insert("RELTYPE".to_string(), "SNOOZE".to_string());
+
+        let mut snooze = original;
+        *snooze.trigger_mut() = crate::Trigger::DateTime(now.clone() + duration);
+        *snooze.uid_mut() = Some(format!("{uid}-snooze").into());
+        *snooze.related_to_mut() = vec![related_to];
+        *snooze.acknowledged_mut() = None;
+
+        alarms.push(snooze);
+
+        Ok(())
+    }
+
+    /**
+     * Acknowledges the alarm and removes its pending snoozes.
+     */
+    pub fn dismiss(&mut self, due: &Due) -> crate::Result {
+        let now = self.clock.now();
+        self.acknowledge(due, now)?;
+
+        Ok(())
+    }
+
+    /**
+     * Unacknowledged alarms of each component, ordered by their UTC time.
+     */
+    fn instants(&self) -> Vec<Box<dyn Iterator<Item = Due> + '_>> {
+        let mut instants = Vec::<Box<dyn Iterator<Item = Due>>>::new();
+
+        for (c, calendar) in self.calendars.iter().enumerate() {
+            let timezones = &calendar.timezones;
+
+            for (n, event) in calendar.events.iter().enumerate() {
+                let target = Target::Event(c, n);
+                let alarms = event.alarm_instants().with_timezones(timezones);
+
+                instants.push(Box::new(
+                    alarms
+                        .map(move |x| Due::from(x, &event.uid, target, timezones))
+                        .filter(|x| !x.is_acknowledged(timezones)),
+                ));
+            }
+
+            for (n, todo) in calendar.todo.iter().enumerate() {
+                let target = Target::Todo(c, n);
+                let alarms = todo.alarm_instants().with_timezones(timezones);
+
+                instants.push(Box::new(
+                    alarms
+                        .map(move |x| Due::from(x, &todo.uid, target, timezones))
+                        .filter(|x| !x.is_acknowledged(timezones)),
+                ));
+            }
+        }
+
+        instants
+    }
+
+    /**
+     * Acknowledges the original alarm of `due` and removes its snoozes. Returns the alarms of
+     * the component and the UID of the original alarm.
+     */
+    fn acknowledge(
+        &mut self,
+        due: &Due,
+        now: crate::DateTime,
+    ) -> crate::Result<(&mut Vec<crate::VAlarm>, String)> {
+        let (component_uid, alarms) = match due.target {
+            Target::Event(c, n) => self
+                .calendars
+                .get_mut(c)
+                .and_then(|x| x.events.get_mut(n))
+                .map(|x| (&x.uid, &mut x.alarms)),
+            Target::Todo(c, n) => self
+                .calendars
+                .get_mut(c)
+                .and_then(|x| x.todo.get_mut(n))
+                .map(|x| (&x.uid, &mut x.alarms)),
+        }
+        .ok_or_else(|| crate::Error::AlarmNotFound(due.uid.to_string()))?;
+
+        let mut index = alarms
+            .iter()
+            .position(|x| x == &due.alarm)
+            .ok_or_else(|| crate::Error::AlarmNotFound(due.uid.to_string()))?;
+
+        if let Some(original) = snoozed_from(&alarms[index]) {
+            let original = original.to_string();
+
+            if let Some(position) = alarms
+                .iter()
+                .position(|x| x.uid().map(|x| x.as_ref()) == Some(original.as_str()))
+            {
+                index = position;
+            }
+        }
+
+        let alarm = &mut alarms[index];
+
+        if alarm.uid().is_none() {
+            *alarm.uid_mut() = Some(format!("{component_uid}-{index}").into());
+        }
+        *alarm.acknowledged_mut() = Some(now);
+
+        let uid = alarm.uid().map(ToString::to_string).unwrap_or_default();
+        alarms.retain(|x| snoozed_from(x) != Some(uid.as_str()));
+
+        Ok((alarms, uid))
+    }
+}
+
+fn snoozed_from(alarm: &crate::VAlarm) -> Option<&str> {
+    alarm
+        .related_to()
+        .iter()
+        .find(|x| x.params.get("RELTYPE").map(String::as_str) == Some("SNOOZE"))
+        .map(|x| x.text.as_str())
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn scheduler(
+        now: &Rc<RefCell<crate::DateTime>>,
+    ) -> crate::Result<crate::scheduler::AlarmScheduler<impl crate::scheduler::Clock>> {
+        let mut event = crate::vevent! {
+            uid: "standup",
+            dtstart: "20240101T090000",
+            rrule: {
+                freq: Daily,
+                count: 3,
+            },
+        }?;
+        event.alarms.push(crate::valarm! {
+            @display,
+            trigger: "-PT15M",
+            description: "Standup",
+        }?);
+
+        let calendar = crate::vcalendar! {
+            events: [event],
+        }?;
+
+        let now = now.clone();
+
+        Ok(crate::scheduler::AlarmScheduler::with_clock(
+            vec![calendar],
+            move || now.borrow().clone(),
+        ))
+    }
+
+    #[test]
+    fn next_due() -> crate::Result {
+        let now = Rc::new(RefCell::new("20240101T080000".parse()?));
+        let scheduler = scheduler(&now)?;
+
+        let next = scheduler.next_due().unwrap();
+        assert_eq!(next.instant, "20240101T084500".parse()?);
+        assert_eq!(next.uid.as_ref(), "standup");
+
+        *now.borrow_mut() = "20240101T084500".parse()?;
+        let next = scheduler.next_due().unwrap();
+        assert_eq!(next.instant, "20240102T084500".parse()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn next_due_zoned() -> crate::Result {
+        let calendar: crate::VCalendar = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//EN\r
+BEGIN:VTIMEZONE\r
+TZID:America/New_York\r
+BEGIN:STANDARD\r
+DTSTART:19701101T020000\r
+TZOFFSETFROM:-0400\r
+TZOFFSETTO:-0500\r
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:19700308T020000\r
+TZOFFSETFROM:-0500\r
+TZOFFSETTO:-0400\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:standup\r
+DTSTAMP:20231201T000000Z\r
+DTSTART;TZID=America/New_York:20240101T090000\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+DESCRIPTION:Standup\r
+TRIGGER:-PT15M\r
+END:VALARM\r
+END:VEVENT\r
+END:VCALENDAR\r
+"
+        .parse()?;
+        let now: crate::DateTime = "20240101T080000Z".parse()?;
+        let scheduler =
+            crate::scheduler::AlarmScheduler::with_clock(vec![calendar], move || now.clone());
+
+        let next = scheduler.next_due().unwrap();
+        assert_eq!(
+            next.instant,
+            "20240101T084500"
+                .parse::<crate::DateTime>()?
+                .with_tzid("America/New_York")
+        );
+        assert_eq!(next.utc.to_string(), "2024-01-01 13:45:00");
+
+        Ok(())
+    }
+
+    #[test]
+    fn missed() -> crate::Result {
+        let now = Rc::new(RefCell::new("20231231T000000".parse()?));
+        let mut scheduler = scheduler(&now)?;
+
+        assert!(scheduler.missed().is_empty());
+        scheduler.checkpoint();
+
+        *now.borrow_mut() = "20240102T120000".parse()?;
+        let missed = scheduler.missed();
+        assert_eq!(missed.len(), 2);
+        assert_eq!(missed[0].occurrence, Some("20240101T090000".parse()?));
+        assert_eq!(missed[1].occurrence, Some("20240102T090000".parse()?));
+
+        scheduler.checkpoint();
+        assert!(scheduler.missed().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn snooze() -> crate::Result {
+        let now = Rc::new(RefCell::new("20240101T084600".parse()?));
+        let mut scheduler = scheduler(&now)?;
+
+        let due = scheduler.next_due();
+        assert_eq!(due.unwrap().instant, "20240102T084500".parse()?);
+
+        *now.borrow_mut() = "20240101T084400".parse()?;
+        let due = scheduler.next_due().unwrap();
+
+        *now.borrow_mut() = "20240101T084600".parse()?;
+        scheduler.snooze(&due, chrono::TimeDelta::minutes(5))?;
+
+        let snoozed = scheduler.next_due().unwrap();
+        assert_eq!(snoozed.instant, "20240101T085100".parse()?);
+
+        let ical = crate::ser::ical(&scheduler.calendars()[0].events[0]);
+        assert!(ical.contains("UID:standup-0\r\n"));
+        assert!(ical.contains("ACKNOWLEDGED:20240101T084600\r\n"));
+        assert!(ical.contains("RELATED-TO;RELTYPE=SNOOZE:standup-0\r\n"));
+
+        *now.borrow_mut() = "20240101T085200".parse()?;
+        scheduler.snooze(&snoozed, chrono::TimeDelta::minutes(5))?;
+        assert_eq!(scheduler.calendars()[0].events[0].alarms.len(), 2);
+        assert_eq!(
+            scheduler.next_due().unwrap().instant,
+            "20240101T085700".parse()?
+        );
+
+        let snoozed = scheduler.next_due().unwrap();
+        scheduler.dismiss(&snoozed)?;
+        assert_eq!(scheduler.calendars()[0].events[0].alarms.len(), 1);
+        assert_eq!(
+            scheduler.next_due().unwrap().instant,
+            "20240102T084500".parse()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn state() -> crate::Result {
+        let state = crate::scheduler::State {
+            checkpoint: Some("20240101T084500".parse()?),
+        };
+
+        assert_eq!(state.to_string(), "CHECKPOINT:20240101T084500\r\n");
+        assert_eq!(state.to_string().parse(), Ok(state));
+
+        Ok(())
+    }
+}
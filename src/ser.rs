@@ -3,8 +3,8 @@
 macro_rules! ical_for_tostring {
     ($ty: ty) => {
         impl $crate::ser::Serialize for $ty {
-            fn ical(&self) -> String {
-                self.to_string()
+            fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "{self}")
             }
         }
     };
@@ -22,7 +22,29 @@ pub trait Serialize {
         None
     }
 
-    fn ical(&self) -> String;
+    /**
+     * Writes the iCalendar representation of this value, content lines are
+     * not folded.
+     */
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result;
+
+    /**
+     * Components are folded with the default [`SerializerOptions`].
+     */
+    fn ical(&self) -> String
+    where
+        Self: Sized,
+    {
+        if Self::component().is_some() {
+            return SerializerOptions::default().ical(self);
+        }
+
+        let mut s = String::new();
+        // Writing into a `String` never fails
+        let _ = self.write_ical(&mut s);
+
+        s
+    }
 }
 
 pub fn ical<T: Serialize>(value: &T) -> String {
@@ -38,13 +60,185 @@ pub fn ical<T: Serialize>(value: &T) -> String {
     s
 }
 
+/**
+ * Streams a component into `w` with the default [`SerializerOptions`].
+ *
+ * ```
+ * let calendar: ikal::VCalendar = std::fs::read_to_string("tests/calendars/1.ics")?.try_into()?;
+ *
+ * let mut out = Vec::new();
+ * ikal::ser::write(&calendar, &mut out)?;
+ *
+ * assert_eq!(String::from_utf8(out)?, ikal::ser::ical(&calendar));
+ * # Ok::<(), Box<dyn std::error::Error>>(())
+ * ```
+ */
+pub fn write<T: Serialize, W: std::io::Write + ?Sized>(
+    value: &T,
+    w: &mut W,
+) -> std::io::Result<()> {
+    SerializerOptions::default().write(value, w)
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LineEnding {
+    #[default]
+    Crlf,
+    /**
+     * For legacy consumers, this isn’t valid iCalendar.
+     */
+    Lf,
+}
+
+impl LineEnding {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Crlf => "\r\n",
+            Self::Lf => "\n",
+        }
+    }
+}
+
+/**
+ * Controls the output of the serializer.
+ *
+ * By default, content lines end with CRLF and are folded at 75 octets as
+ * required by [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545#section-3.1).
+ *
+ * ```
+ * let calendar: ikal::VCalendar = std::fs::read_to_string("tests/calendars/1.ics")?.try_into()?;
+ *
+ * let ical = ikal::ser::SerializerOptions::new()
+ *     .line_ending(ikal::ser::LineEnding::Lf)
+ *     .fold(false)
+ *     .ical(&calendar);
+ *
+ * assert!(ical.starts_with("BEGIN:VCALENDAR\n"));
+ * assert!(!ical.contains('\r'));
+ * # Ok::<(), Box<dyn std::error::Error>>(())
+ * ```
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SerializerOptions {
+    line_length: usize,
+    line_ending: LineEnding,
+    fold: bool,
+}
+
+impl Default for SerializerOptions {
+    fn default() -> Self {
+        Self {
+            line_length: 75,
+            line_ending: LineEnding::default(),
+            fold: true,
+        }
+    }
+}
+
+impl SerializerOptions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Maximum length of a line in octets, line break excluded. Defaults to 75.
+     */
+    #[must_use]
+    pub fn line_length(mut self, line_length: usize) -> Self {
+        self.line_length = line_length;
+        self
+    }
+
+    #[must_use]
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /**
+     * Whether to fold long lines. Defaults to `true`.
+     */
+    #[must_use]
+    pub fn fold(mut self, fold: bool) -> Self {
+        self.fold = fold;
+        self
+    }
+
+    #[must_use]
+    pub fn ical<T: Serialize>(&self, value: &T) -> String {
+        let mut s = String::new();
+        // Writing into a `String` never fails
+        let _ = value.write_ical(&mut Folder::new(&mut s, self));
+
+        s
+    }
+
+    pub fn write<T: Serialize, W: std::io::Write + ?Sized>(
+        &self,
+        value: &T,
+        w: &mut W,
+    ) -> std::io::Result<()> {
+        use std::fmt::Write as _;
+
+        let adapter = IoWriter {
+            inner: w,
+            error: None,
+        };
+        let mut folder = Folder::new(adapter, self);
+
+        if let Some(attr) = value.attr() {
+            folder
+                .write_str(&attr)
+                .and_then(|()| folder.write_char(':'))
+                .map_err(|_| folder.inner.error())?;
+        }
+
+        value
+            .write_ical(&mut folder)
+            .map_err(|_| folder.inner.error())
+    }
+}
+
+/**
+ * Bridges [`std::fmt::Write`] to [`std::io::Write`], keeping the I/O error.
+ */
+struct IoWriter<'a, W: ?Sized> {
+    inner: &'a mut W,
+    error: Option<std::io::Error>,
+}
+
+impl<W: std::io::Write + ?Sized> IoWriter<'_, W> {
+    fn error(&mut self) -> std::io::Error {
+        self.error
+            .take()
+            .unwrap_or_else(|| std::io::Error::other("formatter error"))
+    }
+}
+
+impl<W: std::io::Write + ?Sized> std::fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            std::fmt::Error
+        })
+    }
+}
+
 ical_for_tostring!(i8);
 ical_for_tostring!(u8);
 ical_for_tostring!(u32);
 ical_for_tostring!(chrono::TimeDelta);
 
+impl Serialize for bool {
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(if *self { "TRUE" } else { "FALSE" })
+    }
+}
+
 impl Serialize for chrono::FixedOffset {
-    fn ical(&self) -> String {
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
         let offset = self.local_minus_utc();
         let (sign, offset) = if offset < 0 {
             ('-', -offset)
@@ -57,30 +251,46 @@ impl Serialize for chrono::FixedOffset {
         let hour = mins.div_euclid(60);
 
         if sec == 0 {
-            format!("{sign}{hour:02}{min:02}")
+            write!(w, "{sign}{hour:02}{min:02}")
         } else {
-            format!("{sign}{hour:02}{min:02}{sec:02}")
+            write!(w, "{sign}{hour:02}{min:02}{sec:02}")
         }
     }
 }
 
 impl Serialize for String {
-    fn ical(&self) -> String {
-        escape(self)
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        write_escaped(w, self)
     }
 }
 
 impl<T: Serialize> Serialize for Vec<T> {
-    fn ical(&self) -> String {
-        let mut s = String::new();
+    fn component() -> Option<String> {
+        T::component()
+    }
 
-        for x in self {
-            s.push_str(&x.ical());
-            s.push(',');
+    /**
+     * Common attributes of the items, like the `TZID` of a list of date-times.
+     */
+    fn attr(&self) -> Option<String> {
+        if same_attr(self) {
+            self.first()?.attr()
+        } else {
+            None
         }
-        s.pop();
+    }
 
-        s
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        let separator = if T::component().is_some() { "" } else { "," };
+
+        for (n, x) in self.iter().enumerate() {
+            if n > 0 {
+                w.write_str(separator)?;
+            }
+            x.write_ical(w)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -93,93 +303,383 @@ impl<T: Serialize> Serialize for Option<T> {
         }
     }
 
-    fn ical(&self) -> String {
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
         if let Some(value) = &self {
-            value.ical()
+            value.write_ical(w)
         } else {
-            String::new()
+            Ok(())
         }
     }
 }
 
+/**
+ * A value with an explicit `VALUE` parameter, for the properties whose grammar requires it.
+ */
+pub(crate) struct Typed<'a, T>(pub &'a T, pub &'static str);
+
+impl<T: Serialize> Serialize for Typed<'_, T> {
+    fn attr(&self) -> Option<String> {
+        let value = format!("VALUE={}", self.1);
+
+        match self.0.attr() {
+            Some(attr) => Some(format!("{value};{attr}")),
+            None => Some(value),
+        }
+    }
+
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        self.0.write_ical(w)
+    }
+}
+
 impl<K: ToString, V: Serialize> Serialize for std::collections::BTreeMap<K, V> {
-    fn ical(&self) -> String {
-        let mut s = String::new();
+    fn write_ical<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        for (n, (k, v)) in self.iter().enumerate() {
+            if n > 0 {
+                w.write_char(';')?;
+            }
+            w.write_str(&k.to_string())?;
+            w.write_char('=')?;
+            v.write_ical(w)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "jcal", feature = "serde", feature = "xcal"))]
+pub(crate) fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    // Writing into a `String` never fails
+    let _ = write_escaped(&mut escaped, s);
+
+    escaped
+}
+
+pub(crate) fn write_escaped<W: std::fmt::Write + ?Sized>(w: &mut W, s: &str) -> std::fmt::Result {
+    let mut start = 0;
+
+    for (n, c) in s.char_indices() {
+        let escaped = match c {
+            '\\' => "\\\\",
+            ';' => "\\;",
+            ',' => "\\,",
+            '\n' => "\\n",
+            _ => continue,
+        };
 
-        for (k, v) in self {
-            s.push_str(&k.to_string());
-            s.push('=');
-            s.push_str(&v.ical());
-            s.push(';');
+        w.write_str(&s[start..n])?;
+        w.write_str(escaped)?;
+        start = n + 1;
+    }
+
+    w.write_str(&s[start..])
+}
+
+/**
+ * Reverts [`escape`], unknown escape sequences are kept as is.
+ */
+pub(crate) fn unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
         }
-        s.pop();
 
+        match chars.next() {
+            Some('n' | 'N') => unescaped.push('\n'),
+            Some(c @ (',' | ';' | '\\')) => unescaped.push(c),
+            Some(c) => {
+                unescaped.push('\\');
+                unescaped.push(c);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+/**
+ * Quotes a parameter value containing `:`, `;` or `,`. A DQUOTE can't be quoted, it's written
+ * `^'` as defined by [RFC 6868](https://datatracker.ietf.org/doc/html/rfc6868).
+ */
+pub(crate) fn quote(s: &str) -> String {
+    let s = s.replace('"', "^'");
+
+    if s.contains([':', ';', ',']) {
+        format!("\"{s}\"")
+    } else {
         s
     }
 }
 
-pub(crate) fn escape(s: &str) -> String {
-    s.replace(';', "\\;")
-        .replace(',', "\\,")
-        .replace('\n', "\\n")
+pub(crate) fn params(params: &std::collections::BTreeMap<String, String>) -> String {
+    params
+        .iter()
+        .map(|(k, v)| param(k, v))
+        .collect::<Vec<_>>()
+        .join(";")
 }
 
-pub(crate) fn field<S: Serialize>(name: &str, value: &S) -> String {
-    let mut s = String::new();
+/**
+ * Whether the items can share a single content line.
+ */
+pub(crate) fn same_attr<T: Serialize>(items: &[T]) -> bool {
+    let mut attrs = items.iter().map(Serialize::attr);
+    let first = attrs.next();
+
+    attrs.all(|x| Some(x) == first)
+}
 
-    let ical = value.ical();
+/**
+ * Formats a parameter, quoting the value when needed.
+ */
+pub(crate) fn param(name: &str, value: &str) -> String {
+    format!("{name}={}", quote(value))
+}
+
+/**
+ * Formats a multi-valued parameter, quoting each value when needed.
+ */
+pub(crate) fn param_list<T: ToString>(name: &str, values: &[T]) -> String {
+    let values = values
+        .iter()
+        .map(|value| quote(&value.to_string()))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{name}={values}")
+}
 
+/**
+ * The property `name` with the parameters and the value it's written with.
+ */
+#[cfg(feature = "caldav")]
+pub(crate) fn content_line<S: Serialize>(name: &str, value: &S) -> crate::ContentLine {
+    let params = value
+        .attr()
+        .and_then(|attr| {
+            crate::parser::params(&format!(";{attr}"))
+                .ok()
+                .map(|(_, params)| params)
+        })
+        .unwrap_or_default();
+
+    crate::ContentLine {
+        key: name.to_string(),
+        params,
+        value: value.ical(),
+    }
+}
+
+/**
+ * Same as [`content_line`], `None` for an empty value like [`write_field`].
+ */
+#[cfg(feature = "caldav")]
+pub(crate) fn field_line<S: Serialize>(name: &str, value: &S) -> Option<crate::ContentLine> {
+    Some(content_line(name, value)).filter(|x| !x.value.is_empty())
+}
+
+/**
+ * Writes the property `name`, unless its value is empty.
+ */
+pub(crate) fn write_field<S: Serialize, W: std::fmt::Write + ?Sized>(
+    w: &mut W,
+    name: &str,
+    value: &S,
+) -> std::fmt::Result {
+    write_line(w, name, value, true)
+}
+
+/**
+ * Same as [`write_field`] but also writes properties with an empty value.
+ */
+pub(crate) fn write_property<S: Serialize, W: std::fmt::Write + ?Sized>(
+    w: &mut W,
+    name: &str,
+    value: &S,
+) -> std::fmt::Result {
+    write_line(w, name, value, false)
+}
+
+fn write_line<S: Serialize, W: std::fmt::Write + ?Sized>(
+    w: &mut W,
+    name: &str,
+    value: &S,
+    skip_empty: bool,
+) -> std::fmt::Result {
     if S::component().is_some() {
-        s.push_str(&ical);
-    } else {
-        if !ical.is_empty() {
-            s.push_str(name);
+        return value.write_ical(w);
+    }
 
-            if let Some(attr) = value.attr() {
-                s.push(';');
-                s.push_str(&attr);
-            }
-            s.push(':');
-            s.push_str(&ical);
-            s.push_str("\r\n");
+    let attr = value.attr();
+    let prefix = match &attr {
+        Some(attr) => [name, ";", attr, ":"],
+        None => [name, ":", "", ""],
+    };
+
+    let mut line = Prefixed::new(&mut *w, &prefix);
+
+    if !skip_empty {
+        line.flush()?;
+    }
+
+    value.write_ical(&mut line)?;
+
+    if line.is_written() {
+        w.write_str("\r\n")?;
+    }
+
+    Ok(())
+}
+
+/**
+ * Writes `prefix` before the first non empty string, nothing at all is written
+ * for an empty value.
+ */
+pub(crate) struct Prefixed<'p, W> {
+    inner: W,
+    prefix: &'p [&'p str],
+    written: bool,
+}
+
+impl<'p, W: std::fmt::Write> Prefixed<'p, W> {
+    pub fn new(inner: W, prefix: &'p [&'p str]) -> Self {
+        Self {
+            inner,
+            prefix,
+            written: false,
         }
+    }
+
+    pub fn is_written(&self) -> bool {
+        self.written
+    }
 
-        if s.len() > 75 {
-            let lines = split(&s, 75);
-            s = lines.join("\r\n ");
+    fn flush(&mut self) -> std::fmt::Result {
+        if !self.written {
+            self.written = true;
+
+            for s in self.prefix {
+                self.inner.write_str(s)?;
+            }
         }
+
+        Ok(())
     }
+}
 
-    s
+impl<W: std::fmt::Write> std::fmt::Write for Prefixed<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+
+        self.flush()?;
+        self.inner.write_str(s)
+    }
 }
 
-fn split(s: &str, sub_size: usize) -> Vec<&str> {
-    let mut v = Vec::with_capacity(s.len() / sub_size);
-    let mut cur = s;
+/**
+ * Folds content lines on the fly and applies the line ending of
+ * [`SerializerOptions`].
+ *
+ * Lines are split before the character that would exceed the line length, so
+ * an UTF-8 sequence is never split. The leading space of a continuation line
+ * counts in its length.
+ */
+struct Folder<'a, W> {
+    inner: W,
+    options: &'a SerializerOptions,
+    len: usize,
+}
 
-    while !cur.is_empty() {
-        let mut pos = std::cmp::min(sub_size, cur.len());
+impl<'a, W: std::fmt::Write> Folder<'a, W> {
+    fn new(inner: W, options: &'a SerializerOptions) -> Self {
+        Self {
+            inner,
+            options,
+            len: 0,
+        }
+    }
+}
 
-        while !cur.is_char_boundary(pos) {
-            pos -= 1;
+impl<W: std::fmt::Write> std::fmt::Write for Folder<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let mut start = 0;
+
+        for (n, c) in s.char_indices() {
+            match c {
+                '\r' => {
+                    self.inner.write_str(&s[start..n])?;
+                    start = n + 1;
+                }
+                '\n' => {
+                    self.inner.write_str(&s[start..n])?;
+                    self.inner.write_str(self.options.line_ending.as_str())?;
+                    start = n + 1;
+                    self.len = 0;
+                }
+                _ => {
+                    // A continuation line must contain at least one character
+                    if self.options.fold
+                        && self.len > 1
+                        && self.len + c.len_utf8() > self.options.line_length
+                    {
+                        self.inner.write_str(&s[start..n])?;
+                        self.inner.write_str(self.options.line_ending.as_str())?;
+                        self.inner.write_char(' ')?;
+                        start = n;
+                        self.len = 1;
+                    }
+
+                    self.len += c.len_utf8();
+                }
+            }
         }
 
-        let (chunk, rest) = cur.split_at(pos);
-        v.push(chunk);
-        cur = rest;
+        self.inner.write_str(&s[start..])
     }
-
-    v
 }
 
 #[cfg(test)]
 mod test {
+    fn field(options: &crate::ser::SerializerOptions, text: &str) -> String {
+        let mut ical = String::new();
+        let mut folder = crate::ser::Folder::new(&mut ical, options);
+        crate::ser::write_field(&mut folder, "DESCRIPTION", &crate::Text::from(text)).unwrap();
+
+        ical
+    }
+
+    #[test]
+    fn param() {
+        assert_eq!(crate::ser::param("CN", "John Doe"), "CN=John Doe");
+        assert_eq!(crate::ser::param("CN", "Doe, John"), "CN=\"Doe, John\"");
+        assert_eq!(
+            crate::ser::param("DIR", "ldap://example.com:6666"),
+            "DIR=\"ldap://example.com:6666\""
+        );
+        assert_eq!(
+            crate::ser::param("CN", "John \"Johnny\" Doe"),
+            "CN=John ^'Johnny^' Doe"
+        );
+        assert_eq!(
+            crate::ser::param_list("MEMBER", &["mailto:a@example.com", "mailto:b@example.com"]),
+            "MEMBER=\"mailto:a@example.com\",\"mailto:b@example.com\""
+        );
+    }
+
     #[test]
     fn long_line() {
-        let text = crate::Text::from(
+        let ical = field(
+            &crate::ser::SerializerOptions::default(),
             "This is a long description with more than 75 characteres that exists on a long line.",
         );
-        let ical = crate::ser::field("DESCRIPTION", &text);
 
         similar_asserts::assert_eq!(
             ical,
@@ -189,6 +689,66 @@ mod test {
         );
     }
 
+    #[test]
+    fn octets() {
+        let ical = field(
+            &crate::ser::SerializerOptions::default(),
+            &"é€𝄞a".repeat(50),
+        );
+
+        for line in ical.split("\r\n") {
+            assert!(line.len() <= 75, "{line:?}");
+        }
+        assert_eq!(
+            ical.replace("\r\n ", ""),
+            format!("DESCRIPTION:{}\r\n", "é€𝄞a".repeat(50))
+        );
+    }
+
+    #[test]
+    fn options() {
+        let text =
+            "This is a long description with more than 75 characteres that exists on a long line.";
+
+        let options = crate::ser::SerializerOptions::new()
+            .line_ending(crate::ser::LineEnding::Lf)
+            .line_length(40);
+        similar_asserts::assert_eq!(
+            field(&options, text),
+            "DESCRIPTION:This is a long description w
+ ith more than 75 characteres that exist
+ s on a long line.
+"
+        );
+
+        let options = crate::ser::SerializerOptions::new().fold(false);
+        similar_asserts::assert_eq!(field(&options, text), format!("DESCRIPTION:{text}\r\n"));
+    }
+
+    #[test]
+    fn write() {
+        struct Full;
+
+        impl std::io::Write for Full {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::StorageFull.into())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let vevent = crate::VEvent::new();
+        let mut out = Vec::new();
+
+        crate::ser::write(&vevent, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), crate::ser::ical(&vevent));
+
+        let err = crate::ser::write(&vevent, &mut Full).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::StorageFull);
+    }
+
     #[test]
     fn fixed_offset() {
         use crate::ser::Serialize as _;
@@ -197,11 +757,22 @@ mod test {
         assert_eq!(offset.ical(), "+0100");
     }
 
+    #[test]
+    fn round_trip() -> crate::Result {
+        let ical = std::fs::read_to_string("tests/calendars/1.ics").unwrap();
+        let vcalendar: crate::VCalendar = ical.parse()?;
+
+        assert_eq!(crate::ser::ical(&vcalendar).parse(), Ok(vcalendar));
+
+        Ok(())
+    }
+
     #[test]
     fn split() {
-        let text =
-            crate::Text::from("Durant les vacances scolaires (du 18/10 au 03/11)        Bottière");
-        let ical = crate::ser::field("DESCRIPTION", &text);
+        let ical = field(
+            &crate::ser::SerializerOptions::default(),
+            "Durant les vacances scolaires (du 18/10 au 03/11)        Bottière",
+        );
 
         similar_asserts::assert_eq!(
             ical,
@@ -0,0 +1,644 @@
+use ::serde::de::{self, IntoDeserializer as _, Visitor};
+use ::serde::forward_to_deserialize_any;
+
+type Result<T> = crate::Result<T>;
+
+/**
+ * Deserializes a component from its iCalendar representation.
+ */
+pub fn from_str<T: de::DeserializeOwned>(s: &str) -> Result<T> {
+    let ical = s
+        .replace("\r\n ", "")
+        .replace("\r\n\t", "")
+        .replace("\n ", "")
+        .replace("\n\t", "");
+    let component = crate::tree::Component::parse(&ical)?;
+
+    T::deserialize(Component(&component))
+}
+
+/**
+ * Converts a property or component name to a field name, `LAST-MODIFIED` becomes
+ * `last_modified`.
+ */
+fn field(name: &str) -> String {
+    name.to_lowercase().replace('-', "_")
+}
+
+struct Component<'a>(&'a crate::tree::Component);
+
+impl<'de> de::Deserializer<'de> for Component<'_> {
+    type Error = crate::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let mut entries = Vec::<(String, Vec<Item>)>::new();
+
+        let items = self
+            .0
+            .properties
+            .iter()
+            .map(|x| (field(&x.key), Item::Property(x)))
+            .chain(
+                self.0
+                    .components
+                    .iter()
+                    .map(|x| (field(&x.name), Item::Component(x))),
+            );
+
+        for (key, item) in items {
+            match entries.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, items)) => items.push(item),
+                None => entries.push((key, vec![item])),
+            }
+        }
+
+        visitor.visit_map(de::value::MapDeserializer::new(
+            entries.into_iter().map(|(k, v)| (k, Items(v))),
+        ))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct enum identifier
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Item<'a> {
+    Property(&'a crate::ContentLine),
+    Component(&'a crate::tree::Component),
+}
+
+/**
+ * All the properties or components with the same name.
+ */
+struct Items<'a>(Vec<Item<'a>>);
+
+impl<'de> de::IntoDeserializer<'de, crate::Error> for Items<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'a> Items<'a> {
+    fn first(&self) -> Value<'a> {
+        match self.0[0] {
+            Item::Property(property) => Value::Property(property, property.value.clone()),
+            Item::Component(component) => Value::Component(component),
+        }
+    }
+}
+
+/**
+ * Forwards to the first item, only sequences use all of them.
+ */
+macro_rules! first {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                self.first().$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Items<'_> {
+    type Error = crate::Error;
+
+    first! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_option deserialize_unit deserialize_map deserialize_identifier
+        deserialize_ignored_any
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let mut values = Vec::new();
+
+        for item in self.0 {
+            match item {
+                Item::Property(property) if crate::tree::is_multiple(property) => values.extend(
+                    crate::tree::split(&property.value, ',')
+                        .map(|x| Value::Property(property, x.to_string())),
+                ),
+                Item::Property(property) => {
+                    values.push(Value::Property(property, property.value.clone()));
+                }
+                Item::Component(component) => values.push(Value::Component(component)),
+            }
+        }
+
+        visitor.visit_seq(de::value::SeqDeserializer::new(values.into_iter()))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.first().deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.first().deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.first().deserialize_enum(name, variants, visitor)
+    }
+}
+
+/**
+ * A component, or a property with one of its values.
+ */
+enum Value<'a> {
+    Property(&'a crate::ContentLine, String),
+    Component(&'a crate::tree::Component),
+}
+
+impl<'de> de::IntoDeserializer<'de, crate::Error> for Value<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl Value<'_> {
+    /**
+     * Value in its ISO 8601 like form, the same as the serde implementations of this crate.
+     */
+    fn scalar(&self) -> Result<Scalar> {
+        let Self::Property(property, value) = self else {
+            return Err(crate::Error::Serde(
+                "expected a property, found a component".to_string(),
+            ));
+        };
+
+        let value = match crate::tree::value_type(property).as_str() {
+            "date" | "date-time" => {
+                let (_, date) = crate::parser::datatype::date_or_dt(value)?;
+                super::iso(&date)
+            }
+            "duration" => {
+                let (_, duration) = crate::parser::datatype::duration(value)?;
+                duration.to_string()
+            }
+            ty => crate::tree::to_iso(ty, value),
+        };
+
+        Ok(Scalar(value))
+    }
+}
+
+macro_rules! scalar {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                match self {
+                    Self::Component(component) => Component(component).$method(visitor),
+                    Self::Property(..) => self.scalar()?.$method(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Value<'_> {
+    type Error = crate::Error;
+
+    scalar! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_unit deserialize_seq deserialize_identifier
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    /**
+     * A property is a map of its value and parameters, a recurrence rule a map of its parts.
+     */
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let (property, value) = match self {
+            Self::Component(component) => return Component(component).deserialize_map(visitor),
+            Self::Property(property, ref value) => (property, value),
+        };
+
+        if crate::tree::value_type(property) == "recur" {
+            let parts = crate::tree::recur(value).into_iter().map(|(k, v)| {
+                let k = k
+                    .strip_prefix("by")
+                    .map_or(k.clone(), |x| format!("by_{x}"));
+                (k, Scalars(v))
+            });
+
+            return visitor.visit_map(de::value::MapDeserializer::new(parts));
+        }
+
+        let params = property
+            .params
+            .iter()
+            .filter(|(k, _)| k.as_str() != "VALUE")
+            .map(|(k, v)| (field(k), Scalars(vec![v.trim_matches('"').to_string()])));
+        let entries = std::iter::once(("value".to_string(), Scalars(vec![self.scalar()?.0])))
+            .chain(params)
+            .collect::<Vec<_>>();
+
+        visitor.visit_map(de::value::MapDeserializer::new(entries.into_iter()))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.scalar()?.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+/**
+ * Values of a parameter or a recurrence rule part.
+ */
+struct Scalars(Vec<String>);
+
+impl<'de> de::IntoDeserializer<'de, crate::Error> for Scalars {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/**
+ * Forwards to the values joined, only sequences use them one by one.
+ */
+macro_rules! joined {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                Scalar(self.0.join(",")).$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Scalars {
+    type Error = crate::Error;
+
+    joined! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_unit deserialize_map deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(de::value::SeqDeserializer::new(
+            self.0.into_iter().map(Scalar),
+        ))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        Scalar(self.0.join(",")).deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        Scalar(self.0.join(",")).deserialize_enum(name, variants, visitor)
+    }
+}
+
+/**
+ * A single value, numbers and booleans are parsed on demand.
+ */
+struct Scalar(String);
+
+impl<'de> de::IntoDeserializer<'de, crate::Error> for Scalar {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! parse {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                let value = self
+                    .0
+                    .parse()
+                    .map_err(|_| crate::Error::Serde(format!("invalid number: {}", self.0)))?;
+
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Scalar {
+    type Error = crate::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_string(self.0)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(crate::parser::datatype::boolean(&self.0)?)
+    }
+
+    parse! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let values = crate::tree::split(&self.0, ',')
+            .map(|x| Scalar(x.to_string()))
+            .collect::<Vec<_>>();
+
+        visitor.visit_seq(de::value::SeqDeserializer::new(values.into_iter()))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        char str string bytes byte_buf unit_struct tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Attendee {
+        value: String,
+        cn: Option<String>,
+        partstat: Option<String>,
+        rsvp: Option<bool>,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Alarm {
+        action: String,
+        trigger: String,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Meeting {
+        summary: String,
+        dtstart: crate::Date,
+        duration: Option<String>,
+        sequence: u32,
+        categories: Vec<String>,
+        attendee: Vec<Attendee>,
+        rrule: crate::Recur,
+        x_room: Option<String>,
+        #[serde(rename = "valarm")]
+        alarms: Vec<Alarm>,
+    }
+
+    #[test]
+    fn from_str() -> crate::Result {
+        let ical = "BEGIN:VEVENT\r
+SUMMARY:Planning\\, room 2\r
+DTSTART:20200102T110000Z\r
+DURATION:PT1H\r
+SEQUENCE:2\r
+CATEGORIES:Work,Meeting\r
+ATTENDEE;CN=Jane Doe;PARTSTAT=ACCEPTED;RSVP=TRUE:mailto:jane@exa\r
+ mple.com\r
+ATTENDEE:mailto:john@example.com\r
+RRULE:FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20200301T000000Z\r
+X-ROOM:3\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+TRIGGER:-PT15M\r
+END:VALARM\r
+END:VEVENT\r
+";
+
+        let meeting = crate::serde::from_str::<Meeting>(ical)?;
+
+        similar_asserts::assert_eq!(
+            meeting,
+            Meeting {
+                summary: "Planning, room 2".to_string(),
+                dtstart: "20200102T110000Z".parse()?,
+                duration: Some("PT1H".to_string()),
+                sequence: 2,
+                categories: vec!["Work".to_string(), "Meeting".to_string()],
+                attendee: vec![
+                    Attendee {
+                        value: "mailto:jane@example.com".to_string(),
+                        cn: Some("Jane Doe".to_string()),
+                        partstat: Some("ACCEPTED".to_string()),
+                        rsvp: Some(true),
+                    },
+                    Attendee {
+                        value: "mailto:john@example.com".to_string(),
+                        cn: None,
+                        partstat: None,
+                        rsvp: None,
+                    },
+                ],
+                rrule: "FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20200301T000000Z".parse()?,
+                x_room: Some("3".to_string()),
+                alarms: vec![Alarm {
+                    action: "DISPLAY".to_string(),
+                    trigger: "-PT15M".to_string(),
+                }],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Todo {
+            #[expect(dead_code)]
+            priority: u8,
+        }
+
+        assert!(matches!(
+            crate::serde::from_str::<Todo>("BEGIN:VTODO\r\nPRIORITY:high\r\nEND:VTODO\r\n"),
+            Err(crate::Error::Serde(_))
+        ));
+    }
+}
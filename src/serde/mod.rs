@@ -0,0 +1,310 @@
+/*!
+ * [serde](https://serde.rs) support, enabled by the `serde` feature.
+ *
+ * Components and properties are serialized as structs with their field names, with a stable
+ * representation for values:
+ *
+ * - dates are ISO 8601 `2020-01-02`, date-times are `2020-01-02T11:00:00` when floating,
+ *   `2020-01-02T10:00:00Z` in UTC and `2020-01-02T11:00:00[Europe/Paris]` with a `TZID`;
+ * - durations are in their canonical iCalendar form `P1D` or `PT1H`, UTC offsets `+01:00`;
+ * - enumerations are their iCalendar token, like `CONFIRMED` or `1MO`;
+ * - a trigger is either a date-time or `{"duration": "-PT15M", "related": "START"}`;
+ * - a period is either `{"start", "end"}` or `{"start", "duration"}`;
+ * - an alarm is keyed by its action, like `{"DISPLAY": {…}}`.
+ *
+ * Missing fields are deserialized as their default value.
+ *
+ * ```
+ * let event = ikal::VEvent {
+ *     uid: "a8df6573-0474-496d-8496-033ad45d7fea".into(),
+ *     dtstart: "20200102".parse()?,
+ *     status: Some(ikal::Status::Confirmed),
+ *
+ *     ..Default::default()
+ * };
+ *
+ * let json = serde_json::to_value(&event).unwrap();
+ * assert_eq!(json["dtstart"], "2020-01-02");
+ * assert_eq!(json["status"], "CONFIRMED");
+ *
+ * assert_eq!(serde_json::from_value::<ikal::VEvent>(json).unwrap(), event);
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ *
+ * The same representation is used by [`from_str`] and [`to_string`] to map any struct to an
+ * iCalendar component: `LAST-MODIFIED` becomes the field `last_modified`, `X-ROOM` the field
+ * `x_room` and nested components are fields named after them, like `valarm`. A property read as a
+ * struct has its value in the `value` field and its parameters in the other fields.
+ *
+ * ```
+ * #[derive(serde::Deserialize, serde::Serialize)]
+ * struct Meeting {
+ *     summary: String,
+ *     dtstart: ikal::Date,
+ *     x_room: Option<String>,
+ * }
+ *
+ * let meeting: Meeting = ikal::serde::from_str(
+ *     "BEGIN:VEVENT\r\nSUMMARY:Planning\r\nDTSTART:20200102\r\nX-ROOM:3\r\nEND:VEVENT\r\n",
+ * )?;
+ * assert_eq!(meeting.x_room.as_deref(), Some("3"));
+ *
+ * let ical = ikal::serde::to_string("VEVENT", &meeting)?;
+ * assert!(ical.contains("DTSTART:20200102\r\n"));
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+
+mod de;
+mod ser;
+
+pub use de::from_str;
+pub use ser::to_string;
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _};
+
+const DATE: &str = "%Y-%m-%d";
+const NAIVE: &str = "%Y-%m-%dT%H:%M:%S";
+const UTC: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+/**
+ * Implements serde using the iCalendar representation of the type.
+ */
+macro_rules! token {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    String::deserialize(deserializer)?
+                        .parse()
+                        .map_err(D::Error::custom)
+                }
+            }
+        )+
+    };
+}
+
+token!(
+    crate::Class,
+    crate::Color,
+    crate::Duration,
+    crate::Freq,
+    crate::Method,
+    crate::Proximity,
+    crate::Status,
+    crate::TimeTransparency,
+    crate::Weekday,
+    crate::WeekdayNum,
+    crate::conference::Feature,
+    crate::image::Display,
+    crate::trigger::Related,
+);
+
+impl Serialize for crate::DateTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&iso(&self.clone().into()))
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::DateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        date_time(&s).map_err(D::Error::custom)
+    }
+}
+
+fn date_time(s: &str) -> crate::Result<crate::DateTime> {
+    if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(s, NAIVE) {
+        return Ok(crate::DateTime::Naive(naive));
+    }
+
+    if let Some((naive, tzid)) = s.strip_suffix(']').and_then(|x| x.split_once('[')) {
+        let naive = chrono::NaiveDateTime::parse_from_str(naive, NAIVE)?;
+
+        return Ok(crate::DateTime::Zoned(naive, tzid.into()));
+    }
+
+    let date_time = chrono::DateTime::parse_from_rfc3339(s)?;
+
+    Ok(crate::DateTime::Local(
+        date_time.with_timezone(&chrono::Local),
+    ))
+}
+
+impl Serialize for crate::Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&iso(self))
+    }
+}
+
+fn iso(date: &crate::Date) -> String {
+    match date {
+        crate::Date::Date(date) => date.format(DATE).to_string(),
+        crate::Date::DateTime(crate::DateTime::Naive(naive)) => naive.format(NAIVE).to_string(),
+        crate::Date::DateTime(crate::DateTime::Local(local)) => {
+            local.to_utc().format(UTC).to_string()
+        }
+        crate::Date::DateTime(crate::DateTime::Zoned(naive, tzid)) => {
+            format!("{}[{tzid}]", naive.format(NAIVE))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(&s, DATE) {
+            return Ok(Self::Date(date));
+        }
+
+        date_time(&s).map(Into::into).map_err(D::Error::custom)
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum Trigger {
+    DateTime(crate::DateTime),
+    Duration {
+        duration: crate::Duration,
+        #[serde(default)]
+        related: crate::trigger::Related,
+    },
+}
+
+impl Serialize for crate::Trigger {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let trigger = match self {
+            Self::DateTime(date_time) => Trigger::DateTime(date_time.clone()),
+            Self::Duration(duration, related) => Trigger::Duration {
+                duration: *duration,
+                related: *related,
+            },
+        };
+
+        trigger.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::Trigger {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let trigger = match Trigger::deserialize(deserializer)? {
+            Trigger::DateTime(date_time) => Self::DateTime(date_time),
+            Trigger::Duration { duration, related } => Self::Duration(duration, related),
+        };
+
+        Ok(trigger)
+    }
+}
+
+/**
+ * UTC offset like `+01:00`, for `#[serde(with)]`.
+ */
+pub(crate) mod utc_offset {
+    use ::serde::{Deserialize as _, Deserializer, Serializer, de::Error as _};
+
+    pub(crate) fn serialize<S: Serializer>(
+        offset: &chrono::FixedOffset,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(offset)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<chrono::FixedOffset, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn shape() -> crate::Result {
+        let mut event = crate::vevent! {
+            uid: "a8df6573-0474-496d-8496-033ad45d7fea",
+            dtstamp: "20200102T182304Z",
+            dtstart: "20200102T110000",
+            duration: "PT1H",
+            class: Private,
+            rrule: {
+                freq: Monthly,
+            },
+        }?;
+        event.rrule.as_mut().unwrap().by_day = vec!["1MO".parse()?];
+        event.rdate = vec![crate::RDate::Period(vec!["20200110T110000/PT2H".parse()?])];
+
+        let json = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(json["dtstamp"], "2020-01-02T18:23:04Z");
+        assert_eq!(json["dtstart"], "2020-01-02T11:00:00");
+        assert_eq!(json["duration"], "PT1H");
+        assert_eq!(json["class"], "PRIVATE");
+        assert_eq!(json["rrule"]["freq"], "MONTHLY");
+        assert_eq!(json["rrule"]["by_day"], serde_json::json!(["1MO"]));
+        assert_eq!(
+            json["rdate"],
+            serde_json::json!([[{ "start": "2020-01-10T11:00:00", "duration": "PT2H" }]])
+        );
+        assert_eq!(json["uid"]["text"], "a8df6573-0474-496d-8496-033ad45d7fea");
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() {
+        let tests = std::path::Path::new("tests");
+
+        for dir in tests.read_dir().unwrap() {
+            for file in dir.unwrap().path().read_dir().unwrap() {
+                let file = file.unwrap().path();
+
+                if file.extension() != Some(std::ffi::OsStr::new("ics")) {
+                    continue;
+                }
+
+                let input = std::fs::read_to_string(&file).unwrap();
+                let Ok(calendar) = crate::VCalendar::try_from(input) else {
+                    continue;
+                };
+
+                let json = serde_json::to_string(&calendar).unwrap();
+                let parsed = serde_json::from_str::<crate::VCalendar>(&json).unwrap();
+
+                similar_asserts::assert_eq!(parsed, calendar, "{file:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn trigger() {
+        let trigger =
+            crate::Trigger::Duration(-crate::Duration::minutes(15), crate::trigger::Related::End);
+        let json = serde_json::to_value(&trigger).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({ "duration": "-PT15M", "related": "END" })
+        );
+        assert_eq!(
+            serde_json::from_value::<crate::Trigger>(json).unwrap(),
+            trigger
+        );
+
+        let json = serde_json::json!("2020-01-02T10:00:00+01:00");
+        assert_eq!(
+            serde_json::from_value::<crate::Trigger>(json).unwrap(),
+            crate::Trigger::DateTime("20200102T090000Z".parse().unwrap())
+        );
+    }
+}
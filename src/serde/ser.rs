@@ -0,0 +1,454 @@
+use ::serde::ser::{self, Serialize};
+
+type Result<T> = crate::Result<T>;
+
+/**
+ * Serializes a struct or a map as the iCalendar component `name`.
+ */
+pub fn to_string<T: Serialize + ?Sized>(name: &str, value: &T) -> Result<String> {
+    let Node::Map(entries) = value.serialize(Serializer)? else {
+        return Err(crate::Error::Serde(format!(
+            "{name} must be serialized from a struct or a map"
+        )));
+    };
+
+    let component = component(name, entries)?;
+    let mut ical = String::new();
+    // Writing into a `String` never fails
+    let _ = write(&component, &mut ical);
+
+    Ok(ical)
+}
+
+/**
+ * Converts a field name to a property or component name, `last_modified` becomes
+ * `LAST-MODIFIED`.
+ */
+fn name(field: &str) -> String {
+    field.to_uppercase().replace('_', "-")
+}
+
+fn component(name: &str, entries: Vec<(String, Node)>) -> Result<crate::tree::Component> {
+    let mut component = crate::tree::Component {
+        name: name.to_string(),
+        ..Default::default()
+    };
+
+    for (key, node) in entries {
+        push(&mut component, &self::name(&key), node)?;
+    }
+
+    Ok(component)
+}
+
+fn push(component: &mut crate::tree::Component, key: &str, node: Node) -> Result<()> {
+    match node {
+        Node::None => (),
+        Node::Seq(nodes) => {
+            for node in nodes {
+                push(component, key, node)?;
+            }
+        }
+        Node::Map(entries) if crate::tree::default_type(key) == "recur" => {
+            let mut parts = Vec::new();
+
+            for (k, node) in entries {
+                let values = match node {
+                    Node::None => continue,
+                    Node::Scalar(value) => vec![value],
+                    Node::Seq(nodes) => nodes
+                        .into_iter()
+                        .map(Node::scalar)
+                        .collect::<Result<Vec<_>>>()?,
+                    Node::Map(_) => return Err(unsupported(key)),
+                };
+
+                if !values.is_empty() {
+                    parts.push((k.replace('_', ""), values));
+                }
+            }
+
+            component.properties.push(property(
+                key,
+                crate::tree::join_recur(parts),
+                Default::default(),
+            ));
+        }
+        Node::Map(entries) if entries.iter().any(|(k, _)| k == "value") => {
+            let mut value = String::new();
+            let mut params = std::collections::BTreeMap::new();
+
+            for (k, node) in entries {
+                let v = match node {
+                    Node::None => continue,
+                    Node::Scalar(v) => v,
+                    Node::Seq(nodes) => nodes
+                        .into_iter()
+                        .map(Node::scalar)
+                        .collect::<Result<Vec<_>>>()?
+                        .join(","),
+                    Node::Map(_) => return Err(unsupported(key)),
+                };
+
+                if k == "value" {
+                    value = v;
+                } else {
+                    params.insert(name(&k), v);
+                }
+            }
+
+            component.properties.push(property(key, value, params));
+        }
+        Node::Map(entries) => component.components.push(self::component(key, entries)?),
+        Node::Scalar(value) => {
+            component
+                .properties
+                .push(property(key, value, Default::default()));
+        }
+    }
+
+    Ok(())
+}
+
+/**
+ * Builds a property from a value in its ISO 8601 like form.
+ */
+fn property(
+    key: &str,
+    value: String,
+    params: std::collections::BTreeMap<String, String>,
+) -> crate::ContentLine {
+    let mut property = crate::ContentLine {
+        key: key.to_string(),
+        params,
+        value,
+    };
+
+    let ty = crate::tree::value_type(&property);
+    property.value = crate::tree::from_iso(&ty, &property.value);
+
+    property
+}
+
+fn write(component: &crate::tree::Component, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+    write!(w, "BEGIN:{}\r\n", component.name)?;
+
+    for property in &component.properties {
+        crate::ser::write_property(w, &property.key, property)?;
+    }
+
+    for component in &component.components {
+        write(component, w)?;
+    }
+
+    write!(w, "END:{}\r\n", component.name)
+}
+
+fn unsupported(what: &str) -> crate::Error {
+    crate::Error::Serde(format!("unsupported value for {what}"))
+}
+
+enum Node {
+    None,
+    Scalar(String),
+    Seq(Vec<Node>),
+    Map(Vec<(String, Node)>),
+}
+
+impl Node {
+    fn scalar(self) -> Result<String> {
+        match self {
+            Self::Scalar(value) => Ok(value),
+            _ => Err(crate::Error::Serde("expected a scalar value".to_string())),
+        }
+    }
+}
+
+struct Serializer;
+
+macro_rules! scalar {
+    ($($method:ident: $ty:ty,)*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Node> {
+                Ok(Node::Scalar(v.to_string()))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Node;
+    type Error = crate::Error;
+
+    type SerializeSeq = Seq;
+    type SerializeTuple = Seq;
+    type SerializeTupleStruct = Seq;
+    type SerializeTupleVariant = ser::Impossible<Node, crate::Error>;
+    type SerializeMap = Map;
+    type SerializeStruct = Map;
+    type SerializeStructVariant = ser::Impossible<Node, crate::Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<Node> {
+        Ok(Node::Scalar(if v { "TRUE" } else { "FALSE" }.to_string()))
+    }
+
+    scalar! {
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str,
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Node> {
+        Err(unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<Node> {
+        Ok(Node::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Node> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Node> {
+        Ok(Node::None)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Node> {
+        Ok(Node::None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Node> {
+        Ok(Node::Scalar(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Node> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Node> {
+        Ok(Node::Map(vec![(
+            variant.to_string(),
+            value.serialize(self)?,
+        )]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Seq> {
+        Ok(Seq(Vec::with_capacity(len.unwrap_or_default())))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Seq> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<Seq> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(unsupported(variant))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Map> {
+        Ok(Map::default())
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Map> {
+        Ok(Map::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(unsupported(variant))
+    }
+}
+
+struct Seq(Vec<Node>);
+
+impl ser::SerializeSeq for Seq {
+    type Ok = Node;
+    type Error = crate::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.0.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node> {
+        Ok(Node::Seq(self.0))
+    }
+}
+
+impl ser::SerializeTuple for Seq {
+    type Ok = Node;
+    type Error = crate::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Seq {
+    type Ok = Node;
+    type Error = crate::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+#[derive(Default)]
+struct Map {
+    entries: Vec<(String, Node)>,
+    key: Option<String>,
+}
+
+impl ser::SerializeMap for Map {
+    type Ok = Node;
+    type Error = crate::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(key.serialize(Serializer)?.scalar()?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| crate::Error::Serde("value without key".to_string()))?;
+
+        self.entries.push((key, value.serialize(Serializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node> {
+        Ok(Node::Map(self.entries))
+    }
+}
+
+impl ser::SerializeStruct for Map {
+    type Ok = Node;
+    type Error = crate::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.entries
+            .push((key.to_string(), value.serialize(Serializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node> {
+        Ok(Node::Map(self.entries))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Attendee {
+        value: String,
+        cn: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Meeting {
+        summary: String,
+        dtstart: crate::Date,
+        last_modified: Option<crate::DateTime>,
+        transp: bool,
+        attendee: Vec<Attendee>,
+        rrule: Option<crate::Recur>,
+        x_room: Option<String>,
+    }
+
+    #[test]
+    fn to_string() -> crate::Result {
+        let meeting = Meeting {
+            summary: "Planning, room 2".to_string(),
+            dtstart: "20200102".parse()?,
+            last_modified: Some("20200101T100000Z".parse()?),
+            transp: false,
+            attendee: vec![Attendee {
+                value: "mailto:jane@example.com".to_string(),
+                cn: Some("Jane Doe".to_string()),
+            }],
+            rrule: Some("FREQ=WEEKLY;COUNT=4".parse()?),
+            x_room: None,
+        };
+
+        let ical = crate::serde::to_string("VEVENT", &meeting)?;
+
+        similar_asserts::assert_eq!(
+            ical,
+            "BEGIN:VEVENT\r
+SUMMARY:Planning\\, room 2\r
+DTSTART:20200102\r
+LAST-MODIFIED:20200101T100000Z\r
+TRANSP:FALSE\r
+ATTENDEE;CN=Jane Doe:mailto:jane@example.com\r
+RRULE:FREQ=WEEKLY;COUNT=4;INTERVAL=1\r
+END:VEVENT\r
+"
+        );
+        assert_eq!(crate::serde::from_str::<Meeting>(&ical)?, meeting);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            crate::serde::to_string("VEVENT", "Planning"),
+            Err(crate::Error::Serde(_))
+        ));
+    }
+}
@@ -0,0 +1,1042 @@
+/*!
+ * Minimal CalDAV server over a directory of `.ics` files, a deterministic stand-in for the
+ * integration tests of CalDAV clients. It isn’t meant for production.
+ *
+ * The directory is a single calendar collection at `/`, each `.ics` file is a calendar object
+ * resource named after the file. The server supports:
+ *
+ * - `OPTIONS`, `GET` and `HEAD`;
+ * - `PUT` and `DELETE` with the `If-Match` and `If-None-Match` preconditions;
+ * - `PROPFIND` with a depth of 0 or 1;
+ * - the `calendar-query`, `calendar-multiget` and `sync-collection` reports, see
+ *   [RFC 4791](https://datatracker.ietf.org/doc/html/rfc4791) and
+ *   [RFC 6578](https://datatracker.ietf.org/doc/html/rfc6578).
+ *
+ * The dates and floating date-times of a `calendar-query` are in the time zone of its
+ * `timezone` element, in UTC without one.
+ *
+ * The requests are handled one at a time. The files changed behind the server are noticed on
+ * the next request. There is no authentication, the calendar data is always returned whole and
+ * the unknown properties are left out of the responses.
+ *
+ * ```no_run
+ * let server = ikal::server::Server::new("tests/calendars")?.spawn();
+ *
+ * println!("CalDAV collection at {}", server.url());
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+
+const DAV: &str = "DAV:";
+const CALDAV: &str = "urn:ietf:params:xml:ns:caldav";
+const CALENDARSERVER: &str = "http://calendarserver.org/ns/";
+const CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+const SYNC_TOKEN: &str = "urn:ikal:sync:";
+
+type Writer = quick_xml::Writer<Vec<u8>>;
+
+/**
+ * A server listening on a local port.
+ */
+pub struct Server {
+    http: std::sync::Arc<tiny_http::Server>,
+    collection: Collection,
+}
+
+impl Server {
+    /**
+     * Serves `root` on a free port of `127.0.0.1`.
+     */
+    pub fn new<P: Into<std::path::PathBuf>>(root: P) -> crate::Result<Self> {
+        let root = root.into();
+
+        if !root.is_dir() {
+            return Err(crate::Error::Server(format!(
+                "{} isn’t a directory",
+                root.display()
+            )));
+        }
+
+        let http = tiny_http::Server::http("127.0.0.1:0").map_err(error)?;
+
+        let mut collection = Collection {
+            root,
+            etags: std::collections::BTreeMap::new(),
+            changes: Vec::new(),
+        };
+        collection.scan();
+        collection.changes.clear();
+
+        Ok(Self {
+            http: std::sync::Arc::new(http),
+            collection,
+        })
+    }
+
+    /**
+     * URL of the calendar collection.
+     */
+    #[must_use]
+    pub fn url(&self) -> String {
+        match self.http.server_addr().to_ip() {
+            Some(addr) => format!("http://{addr}/"),
+            None => String::new(),
+        }
+    }
+
+    /**
+     * Handles the requests until the process ends.
+     */
+    pub fn run(mut self) {
+        let http = self.http.clone();
+
+        for mut request in http.incoming_requests() {
+            let reply = self.collection.handle(&mut request);
+            let _ = request.respond(reply.into_response());
+        }
+    }
+
+    /**
+     * Handles the requests in a thread, until the returned handle is dropped.
+     */
+    #[must_use]
+    pub fn spawn(self) -> Handle {
+        let url = self.url();
+        let http = self.http.clone();
+        let thread = std::thread::spawn(move || self.run());
+
+        Handle {
+            url,
+            http,
+            thread: Some(thread),
+        }
+    }
+}
+
+/**
+ * A server running in a thread, stopped on drop.
+ */
+pub struct Handle {
+    url: String,
+    http: std::sync::Arc<tiny_http::Server>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Handle {
+    /**
+     * URL of the calendar collection.
+     */
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        self.http.unblock();
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+struct Reply {
+    status: u16,
+    headers: Vec<(&'static str, String)>,
+    body: String,
+}
+
+impl Reply {
+    fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: String::new(),
+        }
+    }
+
+    fn header(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.headers.push((name, value.to_string()));
+        self
+    }
+
+    fn xml(status: u16, body: std::io::Result<String>) -> Self {
+        match body {
+            Ok(body) => Self {
+                body,
+                ..Self::new(status).header("Content-Type", "application/xml; charset=utf-8")
+            },
+            Err(_) => Self::new(500),
+        }
+    }
+
+    fn into_response(self) -> tiny_http::Response<std::io::Cursor<Vec<u8>>> {
+        let mut response =
+            tiny_http::Response::from_string(self.body).with_status_code(self.status);
+
+        for (name, value) in self.headers {
+            if let Ok(header) = tiny_http::Header::from_bytes(name, value) {
+                response.add_header(header);
+            }
+        }
+
+        response
+    }
+}
+
+/**
+ * The served directory, with the changes seen since the server started.
+ */
+struct Collection {
+    root: std::path::PathBuf,
+    etags: std::collections::BTreeMap<String, String>,
+    /** Resource changed by each sync token */
+    changes: Vec<String>,
+}
+
+impl Collection {
+    /**
+     * Records the resources added, changed or removed since the last scan.
+     */
+    fn scan(&mut self) {
+        let mut etags = std::collections::BTreeMap::new();
+
+        for entry in std::fs::read_dir(&self.root)
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            if !name.ends_with(".ics") {
+                continue;
+            }
+
+            if let Ok(data) = std::fs::read_to_string(entry.path()) {
+                etags.insert(name, etag(&data));
+            }
+        }
+
+        for (name, etag) in &etags {
+            if self.etags.get(name) != Some(etag) {
+                self.changes.push(name.clone());
+            }
+        }
+
+        for name in self.etags.keys() {
+            if !etags.contains_key(name) {
+                self.changes.push(name.clone());
+            }
+        }
+
+        self.etags = etags;
+    }
+
+    fn sync_token(&self) -> String {
+        format!("{SYNC_TOKEN}{}", self.changes.len())
+    }
+
+    fn read(&self, name: &str) -> Option<String> {
+        self.etags.get(name)?;
+
+        std::fs::read_to_string(self.root.join(name)).ok()
+    }
+
+    fn handle(&mut self, request: &mut tiny_http::Request) -> Reply {
+        self.scan();
+
+        let mut body = String::new();
+        if std::io::Read::read_to_string(request.as_reader(), &mut body).is_err() {
+            return Reply::new(400);
+        }
+
+        let path = request.url().split('?').next().unwrap_or_default();
+        let Some(name) = resource(path) else {
+            return Reply::new(404);
+        };
+
+        let header = |name: &'static str| {
+            request
+                .headers()
+                .iter()
+                .find(|x| x.field.equiv(name))
+                .map(|x| x.value.to_string())
+        };
+
+        match (request.method().as_str(), name) {
+            ("OPTIONS", _) => Reply::new(200)
+                .header("DAV", "1, 3, calendar-access")
+                .header("Allow", "OPTIONS, GET, HEAD, PUT, DELETE, PROPFIND, REPORT"),
+            ("GET" | "HEAD", Some(name)) => self.get(name),
+            ("PUT", Some(name)) => {
+                let (if_match, if_none_match) = (header("If-Match"), header("If-None-Match"));
+                self.put(name, if_match.as_deref(), if_none_match.as_deref(), body)
+            }
+            ("DELETE", Some(name)) => self.delete(name, header("If-Match").as_deref()),
+            ("PROPFIND", name) => self.propfind(name, header("Depth").as_deref(), &body),
+            ("REPORT", None) => self.report(&body),
+            _ => Reply::new(405),
+        }
+    }
+
+    fn get(&self, name: &str) -> Reply {
+        match self.read(name) {
+            Some(data) => Reply {
+                body: data,
+                ..Reply::new(200)
+                    .header("Content-Type", CONTENT_TYPE)
+                    .header("ETag", &self.etags[name])
+            },
+            None => Reply::new(404),
+        }
+    }
+
+    fn put(
+        &mut self,
+        name: &str,
+        if_match: Option<&str>,
+        if_none_match: Option<&str>,
+        data: String,
+    ) -> Reply {
+        let current = self.etags.get(name);
+
+        if !precondition(current, if_match, if_none_match) {
+            return Reply::new(412);
+        }
+
+        if data.parse::<crate::VCalendar>().is_err() {
+            return Reply::xml(403, error_body(CALDAV, "valid-calendar-data"));
+        }
+
+        let status = if current.is_some() { 204 } else { 201 };
+
+        if std::fs::write(self.root.join(name), &data).is_err() {
+            return Reply::new(500);
+        }
+
+        self.scan();
+
+        Reply::new(status).header("ETag", etag(&data))
+    }
+
+    fn delete(&mut self, name: &str, if_match: Option<&str>) -> Reply {
+        let Some(current) = self.etags.get(name) else {
+            return Reply::new(404);
+        };
+
+        if !precondition(Some(current), if_match, None) {
+            return Reply::new(412);
+        }
+
+        if std::fs::remove_file(self.root.join(name)).is_err() {
+            return Reply::new(500);
+        }
+
+        self.scan();
+
+        Reply::new(204)
+    }
+
+    fn propfind(&self, name: Option<&str>, depth: Option<&str>, body: &str) -> Reply {
+        let requested = match body.trim() {
+            "" => Requested::All,
+            body => match crate::xml::Element::parse(body) {
+                Ok(propfind) => Requested::new(&propfind),
+                Err(_) => return Reply::new(400),
+            },
+        };
+
+        let mut responses = Vec::new();
+
+        match name {
+            Some(name) if self.etags.contains_key(name) => {
+                responses.push(self.resource_response(name, &requested));
+            }
+            Some(_) => return Reply::new(404),
+            None => {
+                responses.push(self.collection_response(&requested));
+
+                if depth != Some("0") {
+                    for name in self.etags.keys() {
+                        responses.push(self.resource_response(name, &requested));
+                    }
+                }
+            }
+        }
+
+        Reply::xml(207, multistatus(&responses, None))
+    }
+
+    fn report(&self, body: &str) -> Reply {
+        let Ok(report) = crate::xml::Element::parse(body) else {
+            return Reply::new(400);
+        };
+
+        let requested = Requested::new(&report);
+
+        match report.name.as_str() {
+            "calendar-query" => {
+                let Ok(filter) = crate::filter::Filter::from_element(&report) else {
+                    return Reply::xml(403, error_body(CALDAV, "valid-filter"));
+                };
+
+                let timezone = match report.child("timezone") {
+                    Some(timezone) => match crlf(&timezone.text).parse::<crate::VCalendar>() {
+                        Ok(vcalendar) if vcalendar.timezones.len() == 1 => {
+                            vcalendar.timezones.into_iter().next()
+                        }
+                        _ => return Reply::xml(403, error_body(CALDAV, "valid-calendar-data")),
+                    },
+                    None => None,
+                };
+
+                let responses = self
+                    .etags
+                    .keys()
+                    .filter(|name| {
+                        self.read(name)
+                            .and_then(|data| data.parse::<crate::VCalendar>().ok())
+                            .is_some_and(|vcalendar| filter.matches(&vcalendar, timezone.as_ref()))
+                    })
+                    .map(|name| self.resource_response(name, &requested))
+                    .collect::<Vec<_>>();
+
+                Reply::xml(207, multistatus(&responses, None))
+            }
+            "calendar-multiget" => {
+                let responses = report
+                    .children
+                    .iter()
+                    .filter(|x| x.name == "href")
+                    .map(|href| match resource(href.text.trim()) {
+                        Some(Some(name)) if self.etags.contains_key(name) => {
+                            self.resource_response(name, &requested)
+                        }
+                        _ => Response::status(href.text.trim(), "404 Not Found"),
+                    })
+                    .collect::<Vec<_>>();
+
+                Reply::xml(207, multistatus(&responses, None))
+            }
+            "sync-collection" => {
+                let token = report
+                    .child("sync-token")
+                    .map(|x| x.text.trim())
+                    .unwrap_or_default();
+
+                let names = if token.is_empty() {
+                    self.etags.keys().cloned().collect()
+                } else {
+                    let since = token
+                        .strip_prefix(SYNC_TOKEN)
+                        .and_then(|x| x.parse::<usize>().ok())
+                        .filter(|x| *x <= self.changes.len());
+
+                    let Some(since) = since else {
+                        return Reply::xml(403, error_body(DAV, "valid-sync-token"));
+                    };
+
+                    self.changes[since..]
+                        .iter()
+                        .cloned()
+                        .collect::<std::collections::BTreeSet<_>>()
+                };
+
+                let responses = names
+                    .iter()
+                    .map(|name| {
+                        if self.etags.contains_key(name) {
+                            self.resource_response(name, &requested)
+                        } else {
+                            Response::status(&format!("/{name}"), "404 Not Found")
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                Reply::xml(207, multistatus(&responses, Some(&self.sync_token())))
+            }
+            _ => Reply::xml(403, error_body(DAV, "supported-report")),
+        }
+    }
+
+    fn collection_response(&self, requested: &Requested) -> Response {
+        let displayname = self
+            .root
+            .file_name()
+            .map(|x| x.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let components = ["VEVENT", "VJOURNAL", "VTODO"]
+            .into_iter()
+            .map(|name| ("C:comp", Some(("name", name))))
+            .collect();
+
+        let props = vec![
+            (
+                "D:resourcetype",
+                Content::Elements(vec![("D:collection", None), ("C:calendar", None)]),
+            ),
+            ("D:displayname", Content::Text(displayname)),
+            ("D:sync-token", Content::Text(self.sync_token())),
+            ("CS:getctag", Content::Text(self.sync_token())),
+            (
+                "C:supported-calendar-component-set",
+                Content::Elements(components),
+            ),
+        ];
+
+        Response::props("/", requested.filter(props))
+    }
+
+    fn resource_response(&self, name: &str, requested: &Requested) -> Response {
+        let data = self.read(name).unwrap_or_default();
+
+        let mut props = vec![
+            ("D:resourcetype", Content::Elements(Vec::new())),
+            ("D:getetag", Content::Text(self.etags[name].clone())),
+            ("D:getcontenttype", Content::Text(CONTENT_TYPE.to_string())),
+            ("D:getcontentlength", Content::Text(data.len().to_string())),
+        ];
+
+        // Only returned when requested
+        if matches!(requested, Requested::Props(_)) {
+            props.push(("C:calendar-data", Content::Text(data)));
+        }
+
+        Response::props(&format!("/{name}"), requested.filter(props))
+    }
+}
+
+/**
+ * Resource name of a path or URL, `None` for the collection.
+ */
+fn resource(href: &str) -> Option<Option<&str>> {
+    let path = match href.split_once("://") {
+        Some((_, url)) => url.find('/').map_or("/", |x| &url[x..]),
+        None => href,
+    };
+
+    match path.trim_start_matches('/') {
+        "" => Some(None),
+        name if name.ends_with(".ics") && !name.contains('/') => Some(Some(name)),
+        _ => None,
+    }
+}
+
+/**
+ * See [RFC 9110 section 13.1](https://datatracker.ietf.org/doc/html/rfc9110#section-13.1)
+ */
+fn precondition(
+    current: Option<&String>,
+    if_match: Option<&str>,
+    if_none_match: Option<&str>,
+) -> bool {
+    let matches = |header: &str| {
+        header.split(',').map(str::trim).any(|x| match current {
+            Some(current) => x == "*" || x == current,
+            None => false,
+        })
+    };
+
+    if_match.is_none_or(matches) && !if_none_match.is_some_and(matches)
+}
+
+fn etag(data: &str) -> String {
+    format!("\"{}\"", crate::vdir::etag(data))
+}
+
+/**
+ * Calendar data with CRLF line endings, whatever the ones of the XML body.
+ */
+fn crlf(data: &str) -> String {
+    data.trim()
+        .lines()
+        .map(|line| format!("{line}\r\n"))
+        .collect()
+}
+
+fn error(err: impl ToString) -> crate::Error {
+    crate::Error::Server(err.to_string())
+}
+
+/**
+ * Properties asked by a `PROPFIND` or a report, local names as the namespaces are ignored.
+ */
+enum Requested {
+    All,
+    Props(Vec<String>),
+}
+
+impl Requested {
+    fn new(element: &crate::xml::Element) -> Self {
+        match element.child("prop") {
+            Some(prop) => Self::Props(prop.children.iter().map(|x| x.name.clone()).collect()),
+            None => Self::All,
+        }
+    }
+
+    fn filter(&self, props: Vec<(&'static str, Content)>) -> Vec<(&'static str, Content)> {
+        match self {
+            Self::All => props,
+            Self::Props(names) => props
+                .into_iter()
+                .filter(|(name, _)| {
+                    let local = name.split_once(':').map_or(*name, |(_, x)| x);
+                    names.iter().any(|x| x == local)
+                })
+                .collect(),
+        }
+    }
+}
+
+enum Content {
+    Text(String),
+    /** Empty elements, with an attribute */
+    Elements(Vec<(&'static str, Option<(&'static str, &'static str)>)>),
+}
+
+/**
+ * See [14.24. response XML Element](https://datatracker.ietf.org/doc/html/rfc4918#section-14.24)
+ */
+struct Response {
+    href: String,
+    props: Vec<(&'static str, Content)>,
+    status: Option<&'static str>,
+}
+
+impl Response {
+    fn props(href: &str, props: Vec<(&'static str, Content)>) -> Self {
+        Self {
+            href: href.to_string(),
+            props,
+            status: None,
+        }
+    }
+
+    fn status(href: &str, status: &'static str) -> Self {
+        Self {
+            href: href.to_string(),
+            props: Vec::new(),
+            status: Some(status),
+        }
+    }
+}
+
+fn multistatus(responses: &[Response], sync_token: Option<&str>) -> std::io::Result<String> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
+    writer.write_event(Event::Start(
+        BytesStart::new("D:multistatus").with_attributes([
+            ("xmlns:D", DAV),
+            ("xmlns:C", CALDAV),
+            ("xmlns:CS", CALENDARSERVER),
+        ]),
+    ))?;
+
+    for response in responses {
+        start(&mut writer, "D:response")?;
+        element(&mut writer, "D:href", &response.href)?;
+
+        match response.status {
+            Some(status) => element(&mut writer, "D:status", &format!("HTTP/1.1 {status}"))?,
+            None => {
+                start(&mut writer, "D:propstat")?;
+                start(&mut writer, "D:prop")?;
+                for (name, content) in &response.props {
+                    write_prop(&mut writer, name, content)?;
+                }
+                end(&mut writer, "D:prop")?;
+                element(&mut writer, "D:status", "HTTP/1.1 200 OK")?;
+                end(&mut writer, "D:propstat")?;
+            }
+        }
+
+        end(&mut writer, "D:response")?;
+    }
+
+    if let Some(sync_token) = sync_token {
+        element(&mut writer, "D:sync-token", sync_token)?;
+    }
+
+    end(&mut writer, "D:multistatus")?;
+
+    String::from_utf8(writer.into_inner()).map_err(std::io::Error::other)
+}
+
+fn write_prop(writer: &mut Writer, name: &str, content: &Content) -> std::io::Result<()> {
+    match content {
+        Content::Text(text) => element(writer, name, text),
+        Content::Elements(elements) if elements.is_empty() => {
+            writer.write_event(Event::Empty(BytesStart::new(name)))
+        }
+        Content::Elements(elements) => {
+            start(writer, name)?;
+            for (child, attribute) in elements {
+                let child = BytesStart::new(*child).with_attributes(*attribute);
+                writer.write_event(Event::Empty(child))?;
+            }
+            end(writer, name)
+        }
+    }
+}
+
+/**
+ * See [RFC 4918 section 16](https://datatracker.ietf.org/doc/html/rfc4918#section-16)
+ */
+fn error_body(namespace: &str, precondition: &str) -> std::io::Result<String> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
+    writer.write_event(Event::Start(
+        BytesStart::new("D:error").with_attributes([("xmlns:D", DAV), ("xmlns:C", CALDAV)]),
+    ))?;
+
+    let prefix = if namespace == DAV { "D" } else { "C" };
+    writer.write_event(Event::Empty(BytesStart::new(format!(
+        "{prefix}:{precondition}"
+    ))))?;
+
+    end(&mut writer, "D:error")?;
+
+    String::from_utf8(writer.into_inner()).map_err(std::io::Error::other)
+}
+
+fn start(writer: &mut Writer, name: &str) -> std::io::Result<()> {
+    writer.write_event(Event::Start(BytesStart::new(name)))
+}
+
+fn end(writer: &mut Writer, name: &str) -> std::io::Result<()> {
+    writer.write_event(Event::End(BytesEnd::new(name)))
+}
+
+fn element(writer: &mut Writer, name: &str, text: &str) -> std::io::Result<()> {
+    writer
+        .create_element(name)
+        .write_text_content(BytesText::new(text))
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod test {
+    const EVENT: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//EN\r
+BEGIN:VEVENT\r
+UID:{uid}\r
+DTSTAMP:20210517T051025Z\r
+DTSTART:{dtstart}\r
+DURATION:PT1H\r
+SUMMARY:Event {uid}\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    struct Reply {
+        status: u16,
+        headers: Vec<(String, String)>,
+        body: String,
+    }
+
+    impl Reply {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+    }
+
+    fn event(uid: &str, dtstart: &str) -> String {
+        EVENT.replace("{uid}", uid).replace("{dtstart}", dtstart)
+    }
+
+    /**
+     * A server over a new directory with two events.
+     */
+    fn server(name: &str) -> (super::Handle, std::path::PathBuf) {
+        let root = std::env::temp_dir().join(format!("ikal-server-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+
+        std::fs::write(root.join("1.ics"), event("1", "20210301T100000Z")).unwrap();
+        std::fs::write(root.join("2.ics"), event("2", "20210401T100000Z")).unwrap();
+
+        let server = super::Server::new(&root).unwrap().spawn();
+
+        (server, root)
+    }
+
+    fn request(
+        server: &super::Handle,
+        method: &str,
+        path: &str,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> Reply {
+        use std::io::{Read as _, Write as _};
+
+        let addr = server
+            .url()
+            .trim_start_matches("http://")
+            .trim_end_matches('/');
+        let mut stream = std::net::TcpStream::connect(addr).unwrap();
+
+        let mut request = format!(
+            "{method} {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\nContent-Length: {}\r\n",
+            body.len()
+        );
+        for (name, value) in headers {
+            request.push_str(&format!("{name}: {value}\r\n"));
+        }
+        request.push_str("\r\n");
+        request.push_str(body);
+        stream.write_all(request.as_bytes()).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let mut lines = head.lines();
+        let status = lines.next().unwrap()[9..12].parse().unwrap();
+        let headers = lines
+            .filter_map(|x| x.split_once(": "))
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        Reply {
+            status,
+            headers,
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn put_delete() {
+        let (server, root) = server("put_delete");
+
+        let reply = request(&server, "GET", "/1.ics", &[], "");
+        assert_eq!(reply.status, 200);
+        assert_eq!(reply.body, event("1", "20210301T100000Z"));
+        let etag = reply.header("ETag").unwrap().to_string();
+
+        let body = event("1", "20210302T100000Z");
+        let reply = request(&server, "PUT", "/1.ics", &[("If-Match", "\"0\"")], &body);
+        assert_eq!(reply.status, 412);
+        let reply = request(&server, "PUT", "/1.ics", &[("If-None-Match", "*")], &body);
+        assert_eq!(reply.status, 412);
+
+        let reply = request(&server, "PUT", "/1.ics", &[("If-Match", &etag)], &body);
+        assert_eq!(reply.status, 204);
+        assert_ne!(reply.header("ETag"), Some(etag.as_str()));
+        assert_eq!(std::fs::read_to_string(root.join("1.ics")).unwrap(), body);
+
+        let reply = request(
+            &server,
+            "PUT",
+            "/3.ics",
+            &[("If-None-Match", "*")],
+            "invalid",
+        );
+        assert_eq!(reply.status, 403);
+        assert!(reply.body.contains("valid-calendar-data"));
+
+        let body = event("3", "20210501T100000Z");
+        let reply = request(&server, "PUT", "/3.ics", &[("If-None-Match", "*")], &body);
+        assert_eq!(reply.status, 201);
+
+        let reply = request(&server, "DELETE", "/3.ics", &[("If-Match", "\"0\"")], "");
+        assert_eq!(reply.status, 412);
+        let reply = request(&server, "DELETE", "/3.ics", &[], "");
+        assert_eq!(reply.status, 204);
+        let reply = request(&server, "GET", "/3.ics", &[], "");
+        assert_eq!(reply.status, 404);
+
+        drop(server);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn propfind() {
+        let (server, root) = server("propfind");
+
+        let reply = request(&server, "OPTIONS", "/", &[], "");
+        assert!(reply.header("DAV").unwrap().contains("calendar-access"));
+
+        let body = r#"<?xml version="1.0" encoding="utf-8" ?>
+<D:propfind xmlns:D="DAV:">
+  <D:prop>
+    <D:resourcetype/>
+    <D:getetag/>
+  </D:prop>
+</D:propfind>"#;
+
+        let reply = request(&server, "PROPFIND", "/", &[("Depth", "0")], body);
+        assert_eq!(reply.status, 207);
+        assert!(reply.body.contains("<C:calendar/>"));
+        assert!(!reply.body.contains("/1.ics"));
+
+        let reply = request(&server, "PROPFIND", "/", &[("Depth", "1")], body);
+        assert_eq!(reply.body.matches("<D:response>").count(), 3);
+        assert_eq!(reply.body.matches("<D:getetag>").count(), 2);
+        assert!(!reply.body.contains("displayname"));
+
+        drop(server);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn report() {
+        let (server, root) = server("report");
+
+        let body = r#"<?xml version="1.0" encoding="utf-8" ?>
+<C:calendar-query xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <D:prop>
+    <D:getetag/>
+    <C:calendar-data/>
+  </D:prop>
+  <C:filter>
+    <C:comp-filter name="VCALENDAR">
+      <C:comp-filter name="VEVENT">
+        <C:time-range start="20210301T000000Z" end="20210302T000000Z"/>
+      </C:comp-filter>
+    </C:comp-filter>
+  </C:filter>
+</C:calendar-query>"#;
+
+        let reply = request(&server, "REPORT", "/", &[("Depth", "1")], body);
+        assert_eq!(reply.status, 207);
+        assert!(reply.body.contains("<D:href>/1.ics</D:href>"));
+        assert!(reply.body.contains("SUMMARY:Event 1"));
+        assert!(!reply.body.contains("/2.ics"));
+
+        std::fs::write(root.join("3.ics"), event("3", "20210310T100000")).unwrap();
+
+        let floating = |timezone: &str| {
+            let body = format!(
+                r#"<?xml version="1.0" encoding="utf-8" ?>
+<C:calendar-query xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <D:prop>
+    <D:getetag/>
+  </D:prop>
+  <C:filter>
+    <C:comp-filter name="VCALENDAR">
+      <C:comp-filter name="VEVENT">
+        <C:time-range start="20210310T083000Z" end="20210310T093000Z"/>
+      </C:comp-filter>
+    </C:comp-filter>
+  </C:filter>
+  {timezone}
+</C:calendar-query>"#
+            );
+
+            request(&server, "REPORT", "/", &[("Depth", "1")], &body)
+        };
+
+        let reply = floating("");
+        assert!(!reply.body.contains("/3.ics"), "floating times are in UTC");
+
+        let reply = floating(
+            "<C:timezone>BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//ikal//EN
+BEGIN:VTIMEZONE
+TZID:Europe/Paris
+BEGIN:STANDARD
+DTSTART:19701025T030000
+TZOFFSETFROM:+0200
+TZOFFSETTO:+0100
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU
+END:STANDARD
+BEGIN:DAYLIGHT
+DTSTART:19700329T020000
+TZOFFSETFROM:+0100
+TZOFFSETTO:+0200
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU
+END:DAYLIGHT
+END:VTIMEZONE
+END:VCALENDAR
+</C:timezone>",
+        );
+        assert!(reply.body.contains("<D:href>/3.ics</D:href>"));
+
+        let reply = floating("<C:timezone>invalid</C:timezone>");
+        assert_eq!(reply.status, 403);
+        assert!(reply.body.contains("valid-calendar-data"));
+
+        let body = r#"<?xml version="1.0" encoding="utf-8" ?>
+<C:calendar-multiget xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <D:prop>
+    <D:getetag/>
+  </D:prop>
+  <D:href>/2.ics</D:href>
+  <D:href>/4.ics</D:href>
+</C:calendar-multiget>"#;
+
+        let reply = request(&server, "REPORT", "/", &[], body);
+        assert!(reply.body.contains("<D:href>/2.ics</D:href>"));
+        assert!(reply.body.contains("HTTP/1.1 404 Not Found"));
+
+        drop(server);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn sync_collection() {
+        let (server, root) = server("sync_collection");
+
+        let sync = |token: &str| {
+            request(
+                &server,
+                "REPORT",
+                "/",
+                &[],
+                &format!(
+                    r#"<?xml version="1.0" encoding="utf-8" ?>
+<D:sync-collection xmlns:D="DAV:">
+  <D:sync-token>{token}</D:sync-token>
+  <D:sync-level>1</D:sync-level>
+  <D:prop>
+    <D:getetag/>
+  </D:prop>
+</D:sync-collection>"#
+                ),
+            )
+        };
+
+        let token = |reply: &Reply| {
+            let start = reply.body.find("<D:sync-token>").unwrap() + 14;
+            let end = reply.body.find("</D:sync-token>").unwrap();
+            reply.body[start..end].to_string()
+        };
+
+        let reply = sync("");
+        assert_eq!(reply.body.matches("<D:getetag>").count(), 2);
+        let initial = token(&reply);
+
+        let reply = sync(&initial);
+        assert_eq!(reply.body.matches("<D:response>").count(), 0);
+        assert_eq!(token(&reply), initial);
+
+        request(&server, "DELETE", "/1.ics", &[], "");
+        // Changed behind the server
+        std::fs::write(root.join("2.ics"), event("2", "20210402T100000Z")).unwrap();
+
+        let reply = sync(&initial);
+        assert!(reply.body.contains("<D:href>/1.ics</D:href>"));
+        assert!(reply.body.contains("HTTP/1.1 404 Not Found"));
+        assert!(reply.body.contains("<D:href>/2.ics</D:href>"));
+        assert_ne!(token(&reply), initial);
+
+        let reply = sync("urn:ikal:sync:99");
+        assert_eq!(reply.status, 403);
+        assert!(reply.body.contains("valid-sync-token"));
+
+        drop(server);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
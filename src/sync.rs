@@ -0,0 +1,1259 @@
+/*!
+ * Two-way synchronisation of calendar collections, in the manner of
+ * [vdirsyncer](https://vdirsyncer.pimutils.org/).
+ *
+ * Items of both sides are paired by UID. The [`Status`] records the href and ETag of every pair
+ * as of the last synchronisation, to tell creations, updates and deletions apart, and the
+ * synchronised data, the common ancestor of a three-way merge. A change on one side is applied to
+ * the other one, changes on both sides are a conflict solved by a [`Strategy`]. An item modified
+ * on one side and deleted on the other one is restored.
+ *
+ * ```
+ * # let tmp = std::env::temp_dir().join(format!("ikal-sync-doc-{}", std::process::id()));
+ * use ikal::sync::Storage as _;
+ *
+ * let mut a = ikal::vdir::Vdir::init(tmp.join("a"))?;
+ * let mut b = ikal::vdir::Vdir::init(tmp.join("b"))?;
+ * let mut status = ikal::sync::Status::load(tmp.join("status"))?;
+ *
+ * let vcalendar: ikal::VCalendar = std::fs::read_to_string("tests/calendars/1.ics")?.parse()?;
+ * a.upload(&vcalendar)?;
+ *
+ * ikal::sync::sync(&mut a, &mut b, &mut status, ikal::sync::Strategy::Newest)?;
+ * status.save(tmp.join("status"))?;
+ *
+ * assert_eq!(b.list()?.len(), 1);
+ * # std::fs::remove_dir_all(tmp)?;
+ * # Ok::<(), Box<dyn std::error::Error>>(())
+ * ```
+ */
+
+/**
+ * A calendar collection to synchronise.
+ */
+pub trait Storage {
+    /**
+     * The href and ETag of every item.
+     */
+    fn list(&self) -> crate::Result<Vec<(String, String)>>;
+
+    fn get(&self, href: &str) -> crate::Result<crate::vdir::Item>;
+
+    /**
+     * Stores a new item, returns its href and ETag.
+     */
+    fn upload(&mut self, vcalendar: &crate::VCalendar) -> crate::Result<(String, String)>;
+
+    /**
+     * Replaces an item, if it wasn’t modified since `etag`, returns its new ETag.
+     */
+    fn update(
+        &mut self,
+        href: &str,
+        vcalendar: &crate::VCalendar,
+        etag: &str,
+    ) -> crate::Result<String>;
+
+    /**
+     * Deletes an item, if it wasn’t modified since `etag`.
+     */
+    fn delete(&mut self, href: &str, etag: &str) -> crate::Result;
+}
+
+impl Storage for crate::vdir::Vdir {
+    fn list(&self) -> crate::Result<Vec<(String, String)>> {
+        self.list()
+    }
+
+    fn get(&self, href: &str) -> crate::Result<crate::vdir::Item> {
+        self.get(href)
+    }
+
+    fn upload(&mut self, vcalendar: &crate::VCalendar) -> crate::Result<(String, String)> {
+        crate::vdir::Vdir::upload(self, vcalendar)
+    }
+
+    fn update(
+        &mut self,
+        href: &str,
+        vcalendar: &crate::VCalendar,
+        etag: &str,
+    ) -> crate::Result<String> {
+        crate::vdir::Vdir::update(self, href, vcalendar, etag)
+    }
+
+    fn delete(&mut self, href: &str, etag: &str) -> crate::Result {
+        crate::vdir::Vdir::delete(self, href, etag)
+    }
+}
+
+/**
+ * An in-memory collection, with the hrefs and ETags of a [`crate::vdir::Vdir`].
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Memory {
+    items: std::collections::BTreeMap<String, String>,
+}
+
+impl Memory {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn check(&self, href: &str, etag: &str) -> crate::Result {
+        match self.items.get(href) {
+            Some(data) if crate::vdir::etag(data) == etag => Ok(()),
+            Some(_) => Err(crate::Error::Conflict(href.to_string())),
+            None => Err(crate::Error::NotFound(href.to_string())),
+        }
+    }
+}
+
+impl Storage for Memory {
+    fn list(&self) -> crate::Result<Vec<(String, String)>> {
+        let list = self
+            .items
+            .iter()
+            .map(|(href, data)| (href.clone(), crate::vdir::etag(data)))
+            .collect();
+
+        Ok(list)
+    }
+
+    fn get(&self, href: &str) -> crate::Result<crate::vdir::Item> {
+        let data = self
+            .items
+            .get(href)
+            .ok_or_else(|| crate::Error::NotFound(href.to_string()))?;
+
+        Ok(crate::vdir::Item {
+            href: href.to_string(),
+            etag: crate::vdir::etag(data),
+            vcalendar: data.parse()?,
+        })
+    }
+
+    fn upload(&mut self, vcalendar: &crate::VCalendar) -> crate::Result<(String, String)> {
+        let data = crate::ser::ical(vcalendar);
+        let href = crate::vdir::new_href(vcalendar, &data);
+
+        if self.items.contains_key(&href) {
+            return Err(crate::Error::Exists(href));
+        }
+
+        let etag = crate::vdir::etag(&data);
+        self.items.insert(href.clone(), data);
+
+        Ok((href, etag))
+    }
+
+    fn update(
+        &mut self,
+        href: &str,
+        vcalendar: &crate::VCalendar,
+        etag: &str,
+    ) -> crate::Result<String> {
+        self.check(href, etag)?;
+
+        let data = crate::ser::ical(vcalendar);
+        let etag = crate::vdir::etag(&data);
+        self.items.insert(href.to_string(), data);
+
+        Ok(etag)
+    }
+
+    fn delete(&mut self, href: &str, etag: &str) -> crate::Result {
+        self.check(href, etag)?;
+        self.items.remove(href);
+
+        Ok(())
+    }
+}
+
+/**
+ * How to solve an item modified on both sides.
+ */
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Strategy {
+    AWins,
+    BWins,
+    /**
+     * Keeps the item with the highest `SEQUENCE`, then the latest `LAST-MODIFIED`, `a` on a tie.
+     */
+    Newest,
+    /**
+     * Merges the properties changed on each side since the last synchronisation, a property
+     * changed on both sides comes from the newest item. The start, the end, the duration and
+     * the recurrence rule are merged as a single property.
+     *
+     * A merged component gets a higher `SEQUENCE`. The newest item is kept when the merge
+     * isn’t valid, see [`crate::validate`].
+     */
+    Merge,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Side {
+    A,
+    B,
+}
+
+impl Side {
+    fn other(self) -> Self {
+        match self {
+            Self::A => Self::B,
+            Self::B => Self::A,
+        }
+    }
+}
+
+/**
+ * A change made by [`sync`], with the UID of the item.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Action {
+    Conflict(String),
+    Delete(Side, String),
+    /**
+     * Several items of a side share the UID, they are left unsynchronised.
+     */
+    Duplicate(Side, String),
+    /**
+     * The item at this href can’t be read, it’s left unsynchronised.
+     */
+    Invalid(Side, String),
+    Update(Side, String),
+    Upload(Side, String),
+}
+
+/**
+ * State of a pair of items after the last synchronisation.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    /** Href and ETag on side `a`. */
+    pub a: (String, String),
+    /** Href and ETag on side `b`. */
+    pub b: (String, String),
+    /** The synchronised item, in iCalendar format. */
+    pub data: String,
+}
+
+impl Entry {
+    fn side(&self, side: Side) -> &(String, String) {
+        match side {
+            Side::A => &self.a,
+            Side::B => &self.b,
+        }
+    }
+}
+
+/**
+ * The status database, an [`Entry`] by UID.
+ *
+ * It’s saved as a text file, a line by entry with tab separated fields.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Status {
+    entries: std::collections::BTreeMap<String, Entry>,
+}
+
+impl Status {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Reads a status file, a missing one is empty.
+     */
+    pub fn load<P: AsRef<std::path::Path>>(path: P) -> crate::Result<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut entries = std::collections::BTreeMap::new();
+
+        for (n, line) in content.lines().enumerate() {
+            let fields = line.split('\t').map(unescape).collect::<Vec<_>>();
+
+            let [uid, href_a, etag_a, href_b, etag_b, data] = <[String; 6]>::try_from(fields)
+                .map_err(|_| crate::Error::Sync(format!("line {}", n + 1)))?;
+
+            let entry = Entry {
+                a: (href_a, etag_a),
+                b: (href_b, etag_b),
+                data,
+            };
+            entries.insert(uid, entry);
+        }
+
+        Ok(Self { entries })
+    }
+
+    /**
+     * Writes the status file, replacing it atomically.
+     */
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> crate::Result {
+        let path = path.as_ref();
+        let mut content = String::new();
+
+        for (uid, entry) in &self.entries {
+            let fields = [
+                uid,
+                &entry.a.0,
+                &entry.a.1,
+                &entry.b.0,
+                &entry.b.1,
+                &entry.data,
+            ];
+            let fields = fields.map(|x| escape(x));
+
+            content.push_str(&fields.join("\t"));
+            content.push('\n');
+        }
+
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(format!(".{}.tmp", std::process::id()));
+        std::fs::write(&tmp, content)?;
+        std::fs::rename(tmp, path)?;
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get(&self, uid: &str) -> Option<&Entry> {
+        self.entries.get(uid)
+    }
+
+    pub fn uids(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\r', "\\r")
+        .replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('n') => unescaped.push('\n'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+/**
+ * Synchronises two collections, updating `status`.
+ *
+ * The status is up to date for the items already synchronised when an error stops the
+ * synchronisation, it can be saved and the synchronisation run again. An unreadable item or a
+ * UID shared by several items of a side doesn’t stop it, they are reported and skipped.
+ */
+pub fn sync<A: Storage, B: Storage>(
+    a: &mut A,
+    b: &mut B,
+    status: &mut Status,
+    strategy: Strategy,
+) -> crate::Result<Vec<Action>> {
+    let mut actions = Vec::new();
+    let mut skipped = std::collections::BTreeSet::new();
+    let mut found_a = scan(a, status, Side::A, &mut actions, &mut skipped)?;
+    let mut found_b = scan(b, status, Side::B, &mut actions, &mut skipped)?;
+
+    let uids = status
+        .entries
+        .keys()
+        .chain(found_a.keys())
+        .chain(found_b.keys())
+        .filter(|uid| !skipped.contains(*uid))
+        .cloned()
+        .collect::<std::collections::BTreeSet<_>>();
+
+    let mut syncer = Syncer {
+        a,
+        b,
+        status,
+        strategy,
+        actions,
+    };
+
+    for uid in uids {
+        syncer.item(&uid, found_a.remove(&uid), found_b.remove(&uid))?;
+    }
+
+    Ok(syncer.actions)
+}
+
+/**
+ * An item of a side, only parsed when its ETag changed since the last synchronisation.
+ */
+struct Found {
+    href: String,
+    etag: String,
+    vcalendar: Option<crate::VCalendar>,
+}
+
+/**
+ * Items of a side by UID, the unreadable and duplicated ones are reported and their UID, when
+ * known, added to `skipped`.
+ */
+fn scan(
+    storage: &dyn Storage,
+    status: &Status,
+    side: Side,
+    actions: &mut Vec<Action>,
+    skipped: &mut std::collections::BTreeSet<String>,
+) -> crate::Result<std::collections::BTreeMap<String, Found>> {
+    let known = status
+        .entries
+        .iter()
+        .map(|(uid, entry)| (entry.side(side).clone(), uid))
+        .collect::<std::collections::HashMap<_, _>>();
+
+    let mut found = std::collections::BTreeMap::<_, Vec<_>>::new();
+
+    for (href, etag) in storage.list()? {
+        if let Some(uid) = known.get(&(href.clone(), etag.clone())) {
+            let item = Found {
+                href,
+                etag,
+                vcalendar: None,
+            };
+            found.entry((*uid).clone()).or_default().push(item);
+            continue;
+        }
+
+        let Ok(item) = storage.get(&href) else {
+            // A modified item mustn’t look deleted
+            if let Some((uid, _)) = status.entries.iter().find(|(_, x)| x.side(side).0 == href) {
+                skipped.insert(uid.clone());
+            }
+            actions.push(Action::Invalid(side, href));
+            continue;
+        };
+
+        // Items without UID can’t be paired
+        if let Some(uid) = crate::vdir::uid(&item.vcalendar) {
+            let item = Found {
+                href,
+                etag: item.etag,
+                vcalendar: Some(item.vcalendar.clone()),
+            };
+            found.entry(uid.to_string()).or_default().push(item);
+        }
+    }
+
+    let mut unique = std::collections::BTreeMap::new();
+
+    for (uid, mut items) in found {
+        if items.len() > 1 {
+            actions.push(Action::Duplicate(side, uid.clone()));
+            skipped.insert(uid);
+        } else if let Some(item) = items.pop() {
+            unique.insert(uid, item);
+        }
+    }
+
+    Ok(unique)
+}
+
+struct Syncer<'a> {
+    a: &'a mut dyn Storage,
+    b: &'a mut dyn Storage,
+    status: &'a mut Status,
+    strategy: Strategy,
+    actions: Vec<Action>,
+}
+
+impl Syncer<'_> {
+    fn item(&mut self, uid: &str, a: Option<Found>, b: Option<Found>) -> crate::Result {
+        let entry = self.status.entries.get(uid).cloned();
+
+        match (entry, a, b) {
+            (None, Some(a), None) => self.copy(uid, Side::A, a)?,
+            (None, None, Some(b)) => self.copy(uid, Side::B, b)?,
+            (None, Some(a), Some(b)) => self.both(uid, None, a, b)?,
+            (Some(entry), Some(a), Some(b)) => {
+                let changed_a = a.etag != entry.a.1;
+                let changed_b = b.etag != entry.b.1;
+
+                match (changed_a, changed_b) {
+                    (false, false) => (),
+                    (true, false) => self.apply(uid, Side::A, a, b)?,
+                    (false, true) => self.apply(uid, Side::B, b, a)?,
+                    (true, true) => self.both(uid, Some(&entry.data), a, b)?,
+                }
+            }
+            (Some(entry), Some(a), None) => self.deleted(uid, &entry, Side::A, a)?,
+            (Some(entry), None, Some(b)) => self.deleted(uid, &entry, Side::B, b)?,
+            (Some(_), None, None) => {
+                self.status.entries.remove(uid);
+            }
+            (None, None, None) => (),
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Uploads an item of `side` to the other side.
+     */
+    fn copy(&mut self, uid: &str, side: Side, found: Found) -> crate::Result {
+        let vcalendar = self.load(side, &found)?;
+        let other = self.storage(side.other()).upload(&vcalendar)?;
+        self.actions
+            .push(Action::Upload(side.other(), uid.to_string()));
+
+        self.record(uid, side, (found.href, found.etag), other, &vcalendar);
+
+        Ok(())
+    }
+
+    /**
+     * Applies the change of `side` to the other side.
+     */
+    fn apply(&mut self, uid: &str, side: Side, found: Found, other: Found) -> crate::Result {
+        let vcalendar = self.load(side, &found)?;
+        let etag = self
+            .storage(side.other())
+            .update(&other.href, &vcalendar, &other.etag)?;
+        self.actions
+            .push(Action::Update(side.other(), uid.to_string()));
+
+        self.record(
+            uid,
+            side,
+            (found.href, found.etag),
+            (other.href, etag),
+            &vcalendar,
+        );
+
+        Ok(())
+    }
+
+    /**
+     * The item of `side` was deleted on the other side: deletes it too, unless it was modified.
+     */
+    fn deleted(&mut self, uid: &str, entry: &Entry, side: Side, found: Found) -> crate::Result {
+        if found.etag != entry.side(side).1 {
+            return self.copy(uid, side, found);
+        }
+
+        self.storage(side).delete(&found.href, &found.etag)?;
+        self.actions.push(Action::Delete(side, uid.to_string()));
+        self.status.entries.remove(uid);
+
+        Ok(())
+    }
+
+    /**
+     * The item was created or modified on both sides.
+     */
+    fn both(&mut self, uid: &str, base: Option<&str>, a: Found, b: Found) -> crate::Result {
+        let vcalendar_a = self.load(Side::A, &a)?;
+        let vcalendar_b = self.load(Side::B, &b)?;
+
+        if vcalendar_a == vcalendar_b {
+            self.record(
+                uid,
+                Side::A,
+                (a.href, a.etag),
+                (b.href, b.etag),
+                &vcalendar_a,
+            );
+            return Ok(());
+        }
+
+        let resolved = match self.strategy {
+            Strategy::AWins => vcalendar_a.clone(),
+            Strategy::BWins => vcalendar_b.clone(),
+            Strategy::Newest if newer(&vcalendar_b, &vcalendar_a) => vcalendar_b.clone(),
+            Strategy::Newest => vcalendar_a.clone(),
+            Strategy::Merge => {
+                let base = base.map(str::parse).transpose()?;
+                let a_wins = !newer(&vcalendar_b, &vcalendar_a);
+
+                merge(base.as_ref(), &vcalendar_a, &vcalendar_b, a_wins)
+            }
+        };
+        self.actions.push(Action::Conflict(uid.to_string()));
+
+        let etag_a = if resolved == vcalendar_a {
+            a.etag
+        } else {
+            self.actions.push(Action::Update(Side::A, uid.to_string()));
+            self.a.update(&a.href, &resolved, &a.etag)?
+        };
+        let etag_b = if resolved == vcalendar_b {
+            b.etag
+        } else {
+            self.actions.push(Action::Update(Side::B, uid.to_string()));
+            self.b.update(&b.href, &resolved, &b.etag)?
+        };
+
+        self.record(uid, Side::A, (a.href, etag_a), (b.href, etag_b), &resolved);
+
+        Ok(())
+    }
+
+    fn load(&self, side: Side, found: &Found) -> crate::Result<crate::VCalendar> {
+        if let Some(vcalendar) = &found.vcalendar {
+            return Ok(vcalendar.clone());
+        }
+
+        let item = match side {
+            Side::A => self.a.get(&found.href)?,
+            Side::B => self.b.get(&found.href)?,
+        };
+
+        Ok(item.vcalendar)
+    }
+
+    fn storage(&mut self, side: Side) -> &mut dyn Storage {
+        match side {
+            Side::A => self.a,
+            Side::B => self.b,
+        }
+    }
+
+    fn record(
+        &mut self,
+        uid: &str,
+        side: Side,
+        this: (String, String),
+        other: (String, String),
+        vcalendar: &crate::VCalendar,
+    ) {
+        let (a, b) = match side {
+            Side::A => (this, other),
+            Side::B => (other, this),
+        };
+        let entry = Entry {
+            a,
+            b,
+            data: crate::ser::ical(vcalendar),
+        };
+
+        self.status.entries.insert(uid.to_string(), entry);
+    }
+}
+
+/**
+ * Whether `a` has a higher `SEQUENCE`, or the same and a later `LAST-MODIFIED`, than `b`.
+ */
+fn newer(a: &crate::VCalendar, b: &crate::VCalendar) -> bool {
+    version(a) > version(b)
+}
+
+fn version(vcalendar: &crate::VCalendar) -> (u32, Option<chrono::NaiveDateTime>) {
+    let versions = vcalendar
+        .events
+        .iter()
+        .map(|x| (x.sequence, x.last_modified.as_ref()))
+        .chain(
+            vcalendar
+                .todo
+                .iter()
+                .map(|x| (x.sequence, x.last_modified.as_ref())),
+        )
+        .chain(
+            vcalendar
+                .journals
+                .iter()
+                .map(|x| (x.sequence, x.last_modified.as_ref())),
+        );
+
+    let mut version = (0, None);
+
+    for (sequence, last_modified) in versions {
+        let last_modified = last_modified.map(|x| {
+            crate::convert::utc(x, &vcalendar.timezones)
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| x.naive())
+        });
+
+        version = version.max((sequence.unwrap_or_default(), last_modified));
+    }
+
+    version
+}
+
+/**
+ * Three-way merge of two calendar objects, the calendar properties come from `a` and the time
+ * zones of both are kept.
+ *
+ * The newest item, `a` if `a_wins`, is kept whole when the merge breaks a rule of RFC 5545 it
+ * doesn’t break itself.
+ */
+fn merge(
+    base: Option<&crate::VCalendar>,
+    a: &crate::VCalendar,
+    b: &crate::VCalendar,
+    a_wins: bool,
+) -> crate::VCalendar {
+    let empty = crate::VCalendar::default();
+    let base = base.unwrap_or(&empty);
+
+    let mut merged = a.clone();
+    merged.events = merge_components(&base.events, &a.events, &b.events, a_wins);
+    merged.todo = merge_components(&base.todo, &a.todo, &b.todo, a_wins);
+    merged.journals = merge_components(&base.journals, &a.journals, &b.journals, a_wins);
+    merged.timezones.extend(
+        b.timezones
+            .iter()
+            .filter(|x| !a.timezones.iter().any(|y| y.tzid == x.tzid))
+            .cloned(),
+    );
+
+    let errors = |vcalendar: &crate::VCalendar| {
+        vcalendar
+            .validate()
+            .into_iter()
+            .filter(|x| x.severity == crate::validate::Severity::Error)
+            .collect::<Vec<_>>()
+    };
+
+    let newest = if a_wins { a } else { b };
+    let known = errors(newest);
+
+    if errors(&merged).iter().any(|x| !known.contains(x)) {
+        return newest.clone();
+    }
+
+    merged
+}
+
+/**
+ * Three-way merge of components paired by UID and `RECURRENCE-ID`, a component modified on one
+ * side is kept even if deleted on the other one.
+ */
+fn merge_components<T: Merge>(base: &[T], a: &[T], b: &[T], a_wins: bool) -> Vec<T> {
+    fn find<'a, T: crate::iter::Override>(list: &'a [T], x: &T) -> Option<&'a T> {
+        list.iter()
+            .find(|y| y.uid() == x.uid() && y.recurid() == x.recurid())
+    }
+
+    let mut merged = Vec::new();
+
+    for x in a {
+        match (find(base, x), find(b, x)) {
+            (base, Some(y)) => merged.push(T::merge(base, x, y, a_wins)),
+            (Some(base), None) if base == x => (),
+            (_, None) => merged.push(x.clone()),
+        }
+    }
+
+    for y in b {
+        if find(a, y).is_some() || find(base, y).is_some_and(|base| base == y) {
+            continue;
+        }
+
+        merged.push(y.clone());
+    }
+
+    merged
+}
+
+/**
+ * Three-way merge of the properties of a component.
+ */
+trait Merge: crate::iter::Override + PartialEq {
+    /**
+     * A property changed on both sides comes from `a` if `a_wins`. The `SEQUENCE` is increased
+     * when the result is neither `a` nor `b`.
+     */
+    fn merge(base: Option<&Self>, a: &Self, b: &Self, a_wins: bool) -> Self;
+}
+
+macro_rules! merge {
+    ($ty:ty, [$($schedule:ident),*], [$($field:ident),*]) => {
+        impl Merge for $ty {
+            fn merge(base: Option<&Self>, a: &Self, b: &Self, a_wins: bool) -> Self {
+                // The start, the end and the recurrence rule change together
+                let schedule = |x: &Self| ($(x.$schedule.clone(),)*);
+
+                let mut merged = a.clone();
+
+                ($(merged.$schedule,)*) = pick(
+                    base.map(schedule).as_ref(),
+                    &schedule(a),
+                    &schedule(b),
+                    a_wins,
+                )
+                .clone();
+
+                $(
+                    merged.$field = pick(base.map(|x| &x.$field), &a.$field, &b.$field, a_wins)
+                        .clone();
+                )*
+
+                merged.x_prop = merge_map(base.map(|x| &x.x_prop), &a.x_prop, &b.x_prop, a_wins);
+                merged.iana_prop =
+                    merge_map(base.map(|x| &x.iana_prop), &a.iana_prop, &b.iana_prop, a_wins);
+
+                if merged != *a && merged != *b {
+                    let sequence = a.sequence.max(b.sequence).unwrap_or_default();
+                    merged.sequence = Some(sequence.saturating_add(1));
+                }
+
+                merged
+            }
+        }
+    };
+}
+
+merge!(
+    crate::VEvent,
+    [dtstart, dtend, duration, rrule],
+    [
+        dtstamp,
+        class,
+        created,
+        description,
+        geo,
+        last_modified,
+        location,
+        organizer,
+        priority,
+        status,
+        summary,
+        transp,
+        url,
+        color,
+        attach,
+        attendee,
+        categories,
+        comment,
+        contact,
+        exdate,
+        rstatus,
+        related_to,
+        resources,
+        rdate,
+        conference,
+        image,
+        alarms
+    ]
+);
+merge!(
+    crate::VTodo,
+    [dtstart, due, duration, rrule],
+    [
+        dtstamp,
+        class,
+        completed,
+        created,
+        geo,
+        last_modified,
+        location,
+        organizer,
+        percent_complete,
+        priority,
+        status,
+        summary,
+        url,
+        color,
+        attach,
+        attendee,
+        categories,
+        comment,
+        contact,
+        exdate,
+        rstatus,
+        related_to,
+        resources,
+        rdate,
+        conference,
+        image,
+        alarms
+    ]
+);
+merge!(
+    crate::VJournal,
+    [dtstart, rrule],
+    [
+        dtstamp,
+        class,
+        created,
+        last_modified,
+        organizer,
+        status,
+        summary,
+        url,
+        color,
+        attach,
+        attendee,
+        categories,
+        comment,
+        contact,
+        description,
+        exdate,
+        related_to,
+        rdate,
+        rstatus,
+        image
+    ]
+);
+
+/**
+ * The value changed since `base`, the one of `a` if `a_wins` when both changed.
+ */
+fn pick<'a, T: PartialEq>(base: Option<&T>, a: &'a T, b: &'a T, a_wins: bool) -> &'a T {
+    if a == b || Some(b) == base {
+        a
+    } else if Some(a) == base || !a_wins {
+        b
+    } else {
+        a
+    }
+}
+
+/**
+ * Three-way merge of the extension properties, by name.
+ */
+fn merge_map<V: Clone + PartialEq>(
+    base: Option<&std::collections::BTreeMap<String, V>>,
+    a: &std::collections::BTreeMap<String, V>,
+    b: &std::collections::BTreeMap<String, V>,
+    a_wins: bool,
+) -> std::collections::BTreeMap<String, V> {
+    a.keys()
+        .chain(b.keys())
+        .filter_map(|key| {
+            let base = base.map(|x| x.get(key));
+            let value = *pick(base.as_ref(), &a.get(key), &b.get(key), a_wins);
+
+            value.map(|value| (key.clone(), value.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::vcalendar;
+
+    fn vdirs(tmp: &crate::test::TempDir) -> (crate::vdir::Vdir, crate::vdir::Vdir) {
+        (
+            crate::vdir::Vdir::init(tmp.path().join("a")).unwrap(),
+            crate::vdir::Vdir::init(tmp.path().join("b")).unwrap(),
+        )
+    }
+
+    fn update<S: super::Storage>(storage: &mut S, href: &str, vcalendar: &crate::VCalendar) {
+        let item = storage.get(href).unwrap();
+        storage.update(href, vcalendar, &item.etag).unwrap();
+    }
+
+    fn summary<S: super::Storage>(storage: &S, href: &str) -> String {
+        let item = storage.get(href).unwrap();
+
+        item.vcalendar.events[0]
+            .summary
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn status() -> crate::Result {
+        let tmp = crate::test::TempDir::new("sync-status");
+        let path = tmp.path().join("status");
+
+        let mut status = super::Status::load(&path)?;
+        assert_eq!(status, super::Status::new());
+
+        status.entries.insert(
+            "a\tb".to_string(),
+            super::Entry {
+                a: ("1.ics".to_string(), "0011".to_string()),
+                b: ("2.ics".to_string(), "0022".to_string()),
+                data: "BEGIN:VCALENDAR\r\nSUMMARY:a\\nb\r\nEND:VCALENDAR\r\n".to_string(),
+            },
+        );
+        status.save(&path)?;
+
+        assert_eq!(super::Status::load(&path)?, status);
+        assert_eq!(status.uids().collect::<Vec<_>>(), ["a\tb"]);
+
+        std::fs::write(&path, "1\t2\n").unwrap();
+        assert_eq!(
+            super::Status::load(&path),
+            Err(crate::Error::Sync("line 1".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn create() -> crate::Result {
+        use super::{Action::*, Side::*};
+
+        let tmp = crate::test::TempDir::new("sync-create");
+        let (mut a, mut b) = vdirs(&tmp);
+        let mut status = super::Status::new();
+
+        a.upload(&vcalendar("1", "First"))?;
+        b.upload(&vcalendar("2", "Second"))?;
+        a.upload(&vcalendar("3", "Same"))?;
+        b.upload(&vcalendar("3", "Same"))?;
+
+        let actions = super::sync(&mut a, &mut b, &mut status, super::Strategy::AWins)?;
+        assert_eq!(
+            actions,
+            [Upload(B, "1".to_string()), Upload(A, "2".to_string())]
+        );
+        assert_eq!(a.list()?, b.list()?);
+        assert_eq!(status.uids().collect::<Vec<_>>(), ["1", "2", "3"]);
+
+        let actions = super::sync(&mut a, &mut b, &mut status, super::Strategy::AWins)?;
+        assert!(actions.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_delete() -> crate::Result {
+        use super::{Action::*, Side::*};
+
+        let tmp = crate::test::TempDir::new("sync-update_delete");
+        let (mut a, mut b) = vdirs(&tmp);
+        let mut status = super::Status::new();
+
+        let (href_1, _) = a.upload(&vcalendar("1", "First"))?;
+        let (href_2, _) = a.upload(&vcalendar("2", "Second"))?;
+        super::sync(&mut a, &mut b, &mut status, super::Strategy::AWins)?;
+
+        update(&mut b, &href_1, &vcalendar("1", "Updated"));
+        let etag = b.get(&href_2)?.etag;
+        b.delete(&href_2, &etag)?;
+
+        let actions = super::sync(&mut a, &mut b, &mut status, super::Strategy::AWins)?;
+        assert_eq!(
+            actions,
+            [Update(A, "1".to_string()), Delete(A, "2".to_string())]
+        );
+        assert_eq!(summary(&a, &href_1), "Updated");
+        assert_eq!(a.list()?, b.list()?);
+        assert!(status.get("2").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn modified_deleted() -> crate::Result {
+        use super::{Action::*, Side::*};
+
+        let tmp = crate::test::TempDir::new("sync-modified_deleted");
+        let (mut a, mut b) = vdirs(&tmp);
+        let mut status = super::Status::new();
+
+        let (href, _) = a.upload(&vcalendar("1", "First"))?;
+        super::sync(&mut a, &mut b, &mut status, super::Strategy::AWins)?;
+
+        update(&mut a, &href, &vcalendar("1", "Kept"));
+        let etag = b.get(&href)?.etag;
+        b.delete(&href, &etag)?;
+
+        let actions = super::sync(&mut a, &mut b, &mut status, super::Strategy::AWins)?;
+        assert_eq!(actions, [Upload(B, "1".to_string())]);
+        assert_eq!(summary(&b, &href), "Kept");
+
+        Ok(())
+    }
+
+    #[test]
+    fn conflict() -> crate::Result {
+        use super::{Action::*, Side::*, Storage as _};
+
+        for (strategy, expected) in [
+            (super::Strategy::AWins, "A"),
+            (super::Strategy::BWins, "B"),
+            (super::Strategy::Newest, "B"),
+        ] {
+            let mut a = super::Memory::new();
+            let mut b = super::Memory::new();
+            let mut status = super::Status::new();
+
+            let (href, _) = a.upload(&vcalendar("1", "First"))?;
+            super::sync(&mut a, &mut b, &mut status, strategy)?;
+
+            update(&mut a, &href, &vcalendar("1", "A"));
+            let mut newer = vcalendar("1", "B");
+            newer.events[0].sequence = Some(1);
+            update(&mut b, &href, &newer);
+
+            let actions = super::sync(&mut a, &mut b, &mut status, strategy)?;
+            let updated = if expected == "A" { B } else { A };
+            assert_eq!(
+                actions,
+                [Conflict("1".to_string()), Update(updated, "1".to_string())]
+            );
+            assert_eq!(summary(&a, &href), expected);
+            assert_eq!(a, b);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn skipped() -> crate::Result {
+        use super::{Action::*, Side::*, Storage as _};
+
+        let mut a = super::Memory::new();
+        let mut b = super::Memory::new();
+        let mut status = super::Status::new();
+
+        let (href, _) = a.upload(&vcalendar("1", "First"))?;
+        a.upload(&vcalendar("2", "Second"))?;
+        super::sync(&mut a, &mut b, &mut status, super::Strategy::AWins)?;
+
+        a.items
+            .insert(href.clone(), "BEGIN:VCALENDAR\r\n".to_string());
+        a.items
+            .insert("broken.ics".to_string(), "broken".to_string());
+        b.items.insert(
+            "copy.ics".to_string(),
+            crate::ser::ical(&vcalendar("2", "Copy")),
+        );
+        b.upload(&vcalendar("3", "Third"))?;
+
+        let actions = super::sync(&mut a, &mut b, &mut status, super::Strategy::AWins)?;
+        assert_eq!(
+            actions,
+            [
+                Invalid(A, href.clone()),
+                Invalid(A, "broken.ics".to_string()),
+                Duplicate(B, "2".to_string()),
+                Upload(A, "3".to_string()),
+            ]
+        );
+        assert_eq!(summary(&b, &href), "First");
+        assert!(status.get("1").is_some());
+        assert!(status.get("2").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge() -> crate::Result {
+        let tmp = crate::test::TempDir::new("sync-merge");
+        let (mut a, mut b) = vdirs(&tmp);
+        let mut status = super::Status::new();
+
+        let (href, _) = a.upload(&vcalendar("1", "First"))?;
+        super::sync(&mut a, &mut b, &mut status, super::Strategy::Merge)?;
+
+        let mut vcalendar_a = vcalendar("1", "Renamed");
+        vcalendar_a.events[0].last_modified = Some("20210518T080000Z".parse()?);
+        update(&mut a, &href, &vcalendar_a);
+
+        let mut vcalendar_b = vcalendar("1", "Conflicting");
+        vcalendar_b.events[0].location = Some("Paris".into());
+        vcalendar_b.events[0].attendee = vec!["mailto:a@example.com".to_string().into()];
+        update(&mut b, &href, &vcalendar_b);
+
+        super::sync(&mut a, &mut b, &mut status, super::Strategy::Merge)?;
+
+        let event = &a.get(&href)?.vcalendar.events[0];
+        assert_eq!(event.summary, Some("Renamed".into()));
+        assert_eq!(event.location, Some("Paris".into()));
+        assert_eq!(event.attendee.len(), 1);
+        assert_eq!(event.sequence, Some(1));
+        assert_eq!(a.get(&href)?.vcalendar, b.get(&href)?.vcalendar);
+
+        let actions = super::sync(&mut a, &mut b, &mut status, super::Strategy::Merge)?;
+        assert!(actions.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_components() -> crate::Result {
+        let base = vcalendar("1", "Base");
+
+        let mut a = base.clone();
+        let mut exception = a.events[0].clone();
+        exception.recurid = Some("20210308T100000Z".parse()?);
+        exception.summary = Some("Moved".into());
+        a.events.push(exception);
+
+        let b = vcalendar("1", "Renamed");
+
+        let merged = super::merge(Some(&base), &a, &b, true);
+        assert_eq!(merged.events.len(), 2);
+        assert_eq!(merged.events[0].summary, Some("Renamed".into()));
+        assert_eq!(merged.events[1].summary, Some("Moved".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_schedule() -> crate::Result {
+        let mut base = vcalendar("1", "Base");
+        base.events[0].dtend = Some("20210301T110000Z".parse()?);
+
+        let mut a = base.clone();
+        a.events[0].dtstart = "20210301T140000Z".parse()?;
+        a.events[0].dtend = Some("20210301T150000Z".parse()?);
+
+        let mut b = base.clone();
+        b.events[0].dtend = None;
+        b.events[0].duration = Some("PT2H".parse()?);
+        b.events[0].summary = Some("Renamed".into());
+
+        let merged = super::merge(Some(&base), &a, &b, true);
+        let event = &merged.events[0];
+        assert_eq!(event.dtstart, "20210301T140000Z".parse()?);
+        assert_eq!(event.dtend, Some("20210301T150000Z".parse()?));
+        assert_eq!(event.duration, None);
+        assert_eq!(event.summary, Some("Renamed".into()));
+        assert_eq!(event.sequence, Some(1));
+        assert!(merged.validate().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_invalid() -> crate::Result {
+        let mut base = vcalendar("1", "Base");
+        base.events[0].rrule = Some("FREQ=WEEKLY;COUNT=4".parse()?);
+
+        let mut a = base.clone();
+        let mut exception = a.events[0].clone();
+        exception.rrule = None;
+        exception.recurid = Some("20210315T100000Z".parse()?);
+        exception.summary = Some("Moved".into());
+        a.events.push(exception);
+
+        let mut b = base.clone();
+        b.events[0].rrule = Some("FREQ=WEEKLY;COUNT=2".parse()?);
+
+        // The exception of `a` isn’t an instance of the rule of `b`
+        assert_eq!(super::merge(Some(&base), &a, &b, true), a);
+        assert_eq!(super::merge(Some(&base), &a, &b, false), b);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,962 @@
+/*!
+ * Time-range overlap of components and extraction of a sub-calendar.
+ *
+ * The overlap follows [RFC 4791 section 9.9](https://datatracker.ietf.org/doc/html/rfc4791#section-9.9),
+ * every instance of a recurring component is tested. `TZID` parameters are resolved from the
+ * `VTIMEZONE` of the calendar, dates and floating date-times are in the local time zone, or in
+ * the time zone of the query for the [`crate::filter`].
+ *
+ * [`crate::VCalendar::slice`] keeps the components overlapping a time range: a recurring
+ * master if one of its instances does, with only the overrides in the range, and the time
+ * zones they need. [`crate::VCalendar::slice_trimmed`] also excludes the instances before the
+ * range with `EXDATE` and ends the recurrence rules with `UNTIL`.
+ *
+ * ```
+ * let vcalendar: ikal::VCalendar = "BEGIN:VCALENDAR\r
+ * VERSION:2.0\r
+ * PRODID:-//ikal//EN\r
+ * BEGIN:VEVENT\r
+ * UID:1\r
+ * DTSTAMP:20240101T000000Z\r
+ * DTSTART:20240110T100000Z\r
+ * DTEND:20240110T110000Z\r
+ * END:VEVENT\r
+ * BEGIN:VEVENT\r
+ * UID:2\r
+ * DTSTAMP:20240101T000000Z\r
+ * DTSTART:20240210T100000Z\r
+ * DTEND:20240210T110000Z\r
+ * END:VEVENT\r
+ * END:VCALENDAR\r
+ * ".parse()?;
+ *
+ * let january = vcalendar.slice(
+ *     "20240101T000000Z".parse::<ikal::DateTime>()?,
+ *     "20240201T000000Z".parse()?,
+ * );
+ * assert_eq!(january.events.len(), 1);
+ * assert_eq!(january.events[0].uid.text, "1");
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+
+use std::ops::ControlFlow;
+
+/**
+ * See [9.9. CALDAV:time-range XML Element](https://datatracker.ietf.org/doc/html/rfc4791#section-9.9)
+ *
+ * A missing bound is unlimited.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TimeRange {
+    pub start: Option<crate::DateTime>,
+    pub end: Option<crate::DateTime>,
+}
+
+impl TimeRange {
+    #[must_use]
+    pub fn new<D: Into<crate::DateTime>>(start: D, end: D) -> Self {
+        Self {
+            start: Some(start.into()),
+            end: Some(end.into()),
+        }
+    }
+}
+
+/**
+ * Components tested instance by instance.
+ */
+trait Instance: crate::iter::Override {
+    /**
+     * Overlap of this instance alone.
+     */
+    fn overlaps(&self, overlap: &Overlap) -> bool;
+}
+
+impl Instance for crate::VEvent {
+    fn overlaps(&self, overlap: &Overlap) -> bool {
+        overlap.event(self)
+    }
+}
+
+impl Instance for crate::VJournal {
+    fn overlaps(&self, overlap: &Overlap) -> bool {
+        overlap.journal(self)
+    }
+}
+
+impl Instance for crate::VTodo {
+    fn overlaps(&self, overlap: &Overlap) -> bool {
+        overlap.todo(self)
+    }
+}
+
+/**
+ * Time zone of the dates and floating date-times.
+ */
+#[derive(Clone, Copy)]
+// Only the CalDAV filters choose the time zone
+#[cfg_attr(not(feature = "caldav"), allow(dead_code))]
+enum Floating<'a> {
+    Local,
+    Utc,
+    Timezone(&'a crate::VTimezone),
+}
+
+/**
+ * A time range with its bounds in UTC.
+ */
+pub(crate) struct Overlap<'a> {
+    start: chrono::NaiveDateTime,
+    end: chrono::NaiveDateTime,
+    timezones: &'a [crate::VTimezone],
+    floating: Floating<'a>,
+}
+
+impl<'a> Overlap<'a> {
+    pub fn new(range: &TimeRange, timezones: &'a [crate::VTimezone]) -> Self {
+        Self::with_floating(range, timezones, Floating::Local)
+    }
+
+    /**
+     * Same as [`Self::new`] with the dates and floating date-times in `timezone`, in UTC
+     * without one.
+     */
+    #[cfg(feature = "caldav")]
+    pub fn floating(
+        range: &TimeRange,
+        timezones: &'a [crate::VTimezone],
+        timezone: Option<&'a crate::VTimezone>,
+    ) -> Self {
+        let floating = timezone.map_or(Floating::Utc, Floating::Timezone);
+
+        Self::with_floating(range, timezones, floating)
+    }
+
+    fn with_floating(
+        range: &TimeRange,
+        timezones: &'a [crate::VTimezone],
+        floating: Floating<'a>,
+    ) -> Self {
+        let mut overlap = Self {
+            start: chrono::NaiveDateTime::MIN,
+            end: chrono::NaiveDateTime::MAX,
+            timezones,
+            floating,
+        };
+
+        if let Some(start) = &range.start {
+            overlap.start = overlap.instant(start);
+        }
+
+        if let Some(end) = &range.end {
+            overlap.end = overlap.instant(end);
+        }
+
+        overlap
+    }
+
+    /**
+     * Whether an instance, except the `overridden` ones, overlaps the range.
+     */
+    pub fn vevent(&self, vevent: &crate::VEvent, overridden: &[crate::Date]) -> bool {
+        self.any(vevent, overridden)
+    }
+
+    pub fn vjournal(&self, vjournal: &crate::VJournal, overridden: &[crate::Date]) -> bool {
+        self.any(vjournal, overridden)
+    }
+
+    pub fn vtodo(&self, vtodo: &crate::VTodo, overridden: &[crate::Date]) -> bool {
+        self.any(vtodo, overridden)
+    }
+
+    pub fn vfreebusy(&self, vfreebusy: &crate::VFreebusy) -> bool {
+        if let (Some(dtstart), Some(dtend)) = (&vfreebusy.dtstart, &vfreebusy.dtend) {
+            return self.start <= self.utc(dtend) && self.end > self.utc(dtstart);
+        }
+
+        vfreebusy.freebusy.iter().any(|period| {
+            let start = match period {
+                crate::Period::StartEnd(crate::period::StartEnd { start, .. })
+                | crate::Period::StartDur(crate::period::StartDur { start, .. }) => {
+                    self.instant(start)
+                }
+            };
+
+            self.between(start, start + period.duration())
+        })
+    }
+
+    /**
+     * An alarm with an absolute trigger, outside of a component.
+     */
+    pub fn valarm(&self, valarm: &crate::VAlarm) -> bool {
+        match valarm.trigger() {
+            crate::Trigger::DateTime(trigger) => self.at(self.instant(trigger)),
+            crate::Trigger::Duration(..) => false,
+        }
+    }
+
+    /**
+     * Whether `valarm` triggers in the range for an instance of `item`, repetitions included.
+     */
+    #[cfg(feature = "caldav")]
+    pub fn valarm_of<T: crate::iter::Alarming>(&self, item: &T, valarm: &crate::VAlarm) -> bool {
+        for alarm in crate::iter::Alarms::from(item).with_timezones(self.timezones) {
+            let trigger = self.instant(&alarm.instant);
+
+            if trigger >= self.end {
+                break;
+            }
+
+            if alarm.alarm == *valarm && self.at(trigger) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /**
+     * A date or date-time property value.
+     */
+    #[cfg(feature = "caldav")]
+    pub fn date(&self, date: &crate::Date) -> bool {
+        self.at(self.utc(date))
+    }
+
+    fn utc(&self, date: &crate::Date) -> chrono::NaiveDateTime {
+        self.instant(&date.clone().into())
+    }
+
+    /**
+     * UTC time of a date-time, a floating one or a missing time zone is in the floating time
+     * zone.
+     */
+    fn instant(&self, date_time: &crate::DateTime) -> chrono::NaiveDateTime {
+        if let Ok(Some(utc)) = crate::convert::utc(date_time, self.timezones) {
+            return utc;
+        }
+
+        let naive = date_time.naive();
+
+        match self.floating {
+            Floating::Local => naive
+                .and_local_timezone(chrono::Local)
+                .earliest()
+                .map_or(naive, |local| local.naive_utc()),
+            Floating::Utc => naive,
+            Floating::Timezone(vtimezone) => vtimezone
+                .offset_at_local(naive)
+                .and_then(|offset| {
+                    naive.checked_sub_signed(chrono::TimeDelta::seconds(
+                        offset.local_minus_utc().into(),
+                    ))
+                })
+                .unwrap_or(naive),
+        }
+    }
+
+    /**
+     * `start + duration`, across the daylight saving time transitions of its time zone.
+     */
+    fn add(&self, start: &crate::DateTime, duration: crate::Duration) -> crate::DateTime {
+        crate::convert::add(start, duration, self.timezones)
+            .unwrap_or_else(|_| start.clone() + duration)
+    }
+
+    /**
+     * `(start < end_) AND (end > start_)`
+     */
+    fn between(&self, start: chrono::NaiveDateTime, end: chrono::NaiveDateTime) -> bool {
+        self.start < end && self.end > start
+    }
+
+    /**
+     * `(start <= instant) AND (end > instant)`
+     */
+    fn at(&self, instant: chrono::NaiveDateTime) -> bool {
+        self.start <= instant && self.end > instant
+    }
+
+    /**
+     * End of a day long instance starting at `date`.
+     */
+    fn next_day(&self, date: &crate::Date) -> chrono::NaiveDateTime {
+        self.utc(&(date.clone() + chrono::TimeDelta::days(1)))
+    }
+
+    fn event(&self, vevent: &crate::VEvent) -> bool {
+        let dtstart = self.utc(&vevent.dtstart);
+
+        if let Some(dtend) = &vevent.dtend {
+            return self.between(dtstart, self.utc(dtend));
+        }
+
+        if let Some(duration) = vevent.duration {
+            let end = self.instant(&self.add(&vevent.dtstart.clone().into(), duration));
+
+            return if end > dtstart {
+                self.between(dtstart, end)
+            } else {
+                self.at(dtstart)
+            };
+        }
+
+        if vevent.dtstart.has_time() {
+            self.at(dtstart)
+        } else {
+            self.between(dtstart, self.next_day(&vevent.dtstart))
+        }
+    }
+
+    fn journal(&self, vjournal: &crate::VJournal) -> bool {
+        let dtstart = self.utc(&vjournal.dtstart);
+
+        if vjournal.dtstart.has_time() {
+            self.at(dtstart)
+        } else {
+            self.between(dtstart, self.next_day(&vjournal.dtstart))
+        }
+    }
+
+    fn todo(&self, vtodo: &crate::VTodo) -> bool {
+        let due = vtodo.due.as_ref().map(|due| self.utc(due));
+
+        let Some(dtstart) = &vtodo.dtstart else {
+            if let Some(due) = due {
+                return self.start < due && self.end >= due;
+            }
+
+            let completed = vtodo.completed.as_ref().map(|x| self.instant(x));
+            let created = vtodo.created.as_ref().map(|x| self.instant(x));
+
+            return match (completed, created) {
+                (Some(completed), Some(created)) => {
+                    (self.start <= created || self.start <= completed)
+                        && (self.end >= created || self.end >= completed)
+                }
+                (Some(completed), None) => self.start <= completed && self.end >= completed,
+                (None, Some(created)) => self.end > created,
+                (None, None) => true,
+            };
+        };
+
+        let start = self.utc(dtstart);
+
+        if let Some(duration) = vtodo.duration {
+            let end = self.instant(&self.add(&dtstart.clone().into(), duration));
+
+            return self.start <= end && (self.end > start || self.end >= end);
+        }
+
+        match due {
+            Some(due) => {
+                (self.start < due || self.start <= start) && (self.end > start || self.end >= due)
+            }
+            None => self.at(start),
+        }
+    }
+
+    fn any<T: Instance>(&self, item: &T, overridden: &[crate::Date]) -> bool {
+        self.each(item, overridden, |_, overlaps| {
+            if overlaps {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_break()
+    }
+
+    /**
+     * Calls `f` with each instance of the recurrence rule and of the `RDATE`, skipping the
+     * excluded and `overridden` ones, until the instances start after the range.
+     */
+    fn each<T: Instance>(
+        &self,
+        item: &T,
+        overridden: &[crate::Date],
+        mut f: impl FnMut(&T, bool) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let skipped = |instance: &T| {
+            instance.dtstart().is_some_and(|dtstart| {
+                item.exdate().contains(dtstart) || overridden.contains(dtstart)
+            })
+        };
+
+        // Whether the following instances may still start in the range
+        let mut visit = |instance: &T| {
+            if !skipped(instance) {
+                f(instance, instance.overlaps(self))?;
+            }
+
+            ControlFlow::Continue(
+                instance
+                    .dtstart()
+                    .is_some_and(|dtstart| self.utc(dtstart) < self.end),
+            )
+        };
+
+        if item.rrule().is_some() && item.dtstart().is_some() {
+            for instance in crate::iter::Recur::from(item) {
+                if !visit(&instance)? {
+                    break;
+                }
+            }
+        } else {
+            visit(item)?;
+        }
+
+        for instance in rdates(item) {
+            visit(&instance)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    fn components<T: Instance>(&self, components: &[T], trim: bool) -> Vec<T> {
+        let mut kept = Vec::new();
+
+        for component in components {
+            if component.recurid().is_some() {
+                if self.any(component, &[]) {
+                    kept.push(component.clone());
+                }
+
+                continue;
+            }
+
+            let overrides = components
+                .iter()
+                .filter(|x| x.uid() == component.uid() && x.recurid().is_some());
+            let overridden = overrides
+                .clone()
+                .filter_map(|x| x.recurid().cloned())
+                .collect::<Vec<_>>();
+            let kept_overrides = overrides
+                .filter(|x| self.any(*x, &[]))
+                .filter_map(|x| x.recurid().cloned())
+                .collect::<Vec<_>>();
+
+            if !kept_overrides.is_empty() || self.any(component, &overridden) {
+                let mut component = component.clone();
+
+                if trim {
+                    self.trim(&mut component, &overridden, &kept_overrides);
+                }
+
+                kept.push(component);
+            }
+        }
+
+        kept
+    }
+
+    /**
+     * Starts the recurrence at its first instance kept, in the range or replaced by a kept
+     * override, and ends it at the last one in the range, removes the `RDATE` outside of the
+     * range.
+     *
+     * `COUNT` is decreased by the instances skipped, the ones left out between the first and
+     * the last instance kept are excluded.
+     */
+    fn trim<T: Instance>(&self, item: &mut T, overridden: &[crate::Date], kept: &[crate::Date]) {
+        let mut rdates = std::mem::take(item.rdate_mut());
+
+        for rdate in &mut rdates {
+            match rdate {
+                crate::RDate::Date(dates) => {
+                    dates.retain(|date| instance(item, date.clone(), None).overlaps(self));
+                }
+                crate::RDate::Period(periods) => periods.retain(|period| {
+                    let (start, end) = match period {
+                        crate::Period::StartEnd(crate::period::StartEnd { start, end }) => {
+                            (start.clone(), end.clone())
+                        }
+                        crate::Period::StartDur(crate::period::StartDur { start, duration }) => {
+                            (start.clone(), self.add(start, *duration))
+                        }
+                    };
+
+                    instance(item, start.into(), Some(end.into())).overlaps(self)
+                }),
+            }
+        }
+
+        rdates.retain(|rdate| match rdate {
+            crate::RDate::Date(dates) => !dates.is_empty(),
+            crate::RDate::Period(periods) => !periods.is_empty(),
+        });
+
+        *item.rdate_mut() = rdates;
+
+        let Some(dtstart) = item.dtstart().cloned() else {
+            return;
+        };
+
+        if item.rrule().is_none() {
+            return;
+        }
+
+        let mut first = None;
+        let mut previous = None;
+        let mut last = None;
+        let mut gaps = Vec::new();
+        let mut after = false;
+        let mut index = 0_usize;
+
+        // Every instance of the rule alone, counted like `COUNT` does
+        let mut rrule = item.clone();
+        rrule.rdate_mut().clear();
+        rrule.exdate_mut().clear();
+
+        let _ = self.each(&rrule, &[], |instance, overlaps| {
+            let Some(start) = instance.dtstart().cloned() else {
+                return ControlFlow::Break(());
+            };
+
+            if self.utc(&start) >= self.end {
+                after = true;
+                return ControlFlow::Break(());
+            }
+
+            let excluded = item.exdate().contains(&start) || overridden.contains(&start);
+
+            if (overlaps && !excluded) || kept.contains(&start) {
+                first.get_or_insert((index, start.clone()));
+
+                if overlaps && !excluded {
+                    last = Some(start.clone());
+
+                    // Every following instance overlaps an unlimited range
+                    if self.end == chrono::NaiveDateTime::MAX {
+                        return ControlFlow::Break(());
+                    }
+                }
+            } else if first.is_some() && !excluded {
+                gaps.push(start.clone());
+            }
+
+            previous = Some((index, start));
+            index += 1;
+
+            ControlFlow::Continue(())
+        });
+
+        // Only the rule instance before the range is left, still required by `DTSTART`
+        let Some((skipped, start)) = first.or_else(|| {
+            if let Some((_, start)) = &previous {
+                gaps.push(start.clone());
+            }
+
+            previous
+        }) else {
+            return;
+        };
+        let last = last.unwrap_or_else(|| start.clone());
+        let shift = crate::DateTime::from(start.clone()) - crate::DateTime::from(dtstart);
+
+        item.set_dtstart(start.clone());
+
+        if let Some(dtend) = item.dtend().cloned() {
+            item.set_dtend(dtend + shift);
+        }
+
+        if let Some(due) = item.due().cloned() {
+            item.set_due(due + shift);
+        }
+
+        item.exdate_mut().retain(|exdate| *exdate >= start);
+
+        for gap in gaps {
+            if gap <= last && !item.exdate().contains(&gap) {
+                item.exdate_mut().push(gap);
+            }
+        }
+
+        let until = after.then(|| self.until(&last));
+
+        if let Some(rrule) = item.rrule_mut() {
+            if let Some(until) = until {
+                rrule.until = Some(until);
+                rrule.count = None;
+            } else if let Some(count) = &mut rrule.count {
+                *count = count.saturating_sub(u8::try_from(skipped).unwrap_or(u8::MAX));
+            }
+        }
+    }
+
+    /**
+     * `UNTIL` matching an instance: a date, a floating date-time or in UTC.
+     */
+    fn until(&self, dtstart: &crate::Date) -> crate::Date {
+        match dtstart {
+            crate::Date::Date(_) | crate::Date::DateTime(crate::DateTime::Naive(_)) => {
+                dtstart.clone()
+            }
+            crate::Date::DateTime(_) => {
+                crate::DateTime::Local(self.utc(dtstart).and_utc().with_timezone(&chrono::Local))
+                    .into()
+            }
+        }
+    }
+}
+
+/**
+ * Instance of `item` starting at `start`, with the same length unless `end` is given.
+ */
+fn instance<T: crate::iter::Recurring>(
+    item: &T,
+    start: crate::Date,
+    end: Option<crate::Date>,
+) -> T {
+    let end = end.or_else(|| {
+        let dtstart = crate::DateTime::from(item.dtstart()?.clone());
+        let end = crate::DateTime::from(item.dtend().or(item.due())?.clone());
+
+        Some(start.clone() + (&end - &dtstart))
+    });
+
+    let mut instance = item.clone();
+
+    if let Some(end) = end {
+        if item.due().is_some() {
+            instance.set_due(end);
+        } else {
+            instance.set_dtend(end);
+        }
+    }
+
+    instance.set_dtstart(start);
+
+    instance
+}
+
+fn rdates<T: crate::iter::Override>(item: &T) -> Vec<T> {
+    let mut instances = Vec::new();
+
+    for rdate in item.rdate() {
+        match rdate {
+            crate::RDate::Date(dates) => {
+                instances.extend(dates.iter().map(|date| instance(item, date.clone(), None)))
+            }
+            crate::RDate::Period(periods) => {
+                instances.extend(periods.iter().map(|period| match period {
+                    crate::Period::StartEnd(crate::period::StartEnd { start, end }) => {
+                        instance(item, start.clone().into(), Some(end.clone().into()))
+                    }
+                    crate::Period::StartDur(crate::period::StartDur { start, duration }) => {
+                        instance(
+                            item,
+                            start.clone().into(),
+                            Some((start.clone() + *duration).into()),
+                        )
+                    }
+                }))
+            }
+        }
+    }
+
+    instances
+}
+
+pub(crate) fn slice(
+    vcalendar: &crate::VCalendar,
+    range: &TimeRange,
+    trim: bool,
+) -> crate::VCalendar {
+    let overlap = Overlap::new(range, &vcalendar.timezones);
+
+    let mut slice = crate::VCalendar {
+        alarms: vcalendar
+            .alarms
+            .iter()
+            .filter(|valarm| overlap.valarm(valarm))
+            .cloned()
+            .collect(),
+        events: overlap.components(&vcalendar.events, trim),
+        freebusy: vcalendar
+            .freebusy
+            .iter()
+            .filter(|vfreebusy| overlap.vfreebusy(vfreebusy))
+            .cloned()
+            .collect(),
+        journals: overlap.components(&vcalendar.journals, trim),
+        todo: overlap.components(&vcalendar.todo, trim),
+        timezones: Vec::new(),
+        ..vcalendar.clone()
+    };
+
+    let tzids = slice.tzids();
+    slice.timezones = vcalendar
+        .timezones
+        .iter()
+        .filter(|vtimezone| tzids.contains(vtimezone.tzid.text.as_str()))
+        .cloned()
+        .collect();
+
+    slice
+}
+
+#[cfg(test)]
+mod test {
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//EN\r
+BEGIN:VTIMEZONE\r
+TZID:Europe/Paris\r
+BEGIN:STANDARD\r
+DTSTART:19701025T030000\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:19700329T020000\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+BEGIN:VTIMEZONE\r
+TZID:Europe/London\r
+BEGIN:STANDARD\r
+DTSTART:19701025T020000\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0000\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:19700329T010000\r
+TZOFFSETFROM:+0000\r
+TZOFFSETTO:+0100\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20210517T051025Z\r
+DTSTART;TZID=Europe/Paris:20210301T100000\r
+DTEND;TZID=Europe/Paris:20210301T110000\r
+RRULE:FREQ=WEEKLY;COUNT=10\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20210517T051025Z\r
+DTSTART;TZID=Europe/Paris:20210316T100000\r
+DTEND;TZID=Europe/Paris:20210316T110000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20210517T051025Z\r
+DTSTART;TZID=Europe/Paris:20210405T140000\r
+DTEND;TZID=Europe/Paris:20210405T150000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:2\r
+DTSTAMP:20210517T051025Z\r
+DTSTART;TZID=Europe/London:20210601T090000\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    /**
+     * The second and third events override instances of the first one.
+     */
+    fn vcalendar() -> crate::VCalendar {
+        let mut vcalendar: crate::VCalendar = CALENDAR.parse().unwrap();
+        let paris = |s: &str| {
+            s.parse::<crate::DateTime>()
+                .unwrap()
+                .with_tzid("Europe/Paris")
+        };
+
+        vcalendar.events[1].recurid = Some(paris("20210315T100000").into());
+        vcalendar.events[2].recurid = Some(paris("20210405T100000").into());
+
+        vcalendar
+    }
+
+    fn range(start: &str, end: &str) -> super::TimeRange {
+        super::TimeRange::new(
+            start.parse::<crate::DateTime>().unwrap(),
+            end.parse().unwrap(),
+        )
+    }
+
+    fn vevent(properties: &str) -> crate::VEvent {
+        format!("BEGIN:VEVENT\r\nUID:1\r\nDTSTAMP:20210517T051025Z\r\n{properties}END:VEVENT\r\n")
+            .parse()
+            .unwrap()
+    }
+
+    fn vtodo(properties: &str) -> crate::VTodo {
+        format!("BEGIN:VTODO\r\nUID:1\r\nDTSTAMP:20210517T051025Z\r\n{properties}END:VTODO\r\n")
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn vevent_overlaps() {
+        let range = range("20210301T100000Z", "20210301T110000Z");
+
+        let vevent = self::vevent("DTSTART:20210301T090000Z\r\nDTEND:20210301T100000Z\r\n");
+        assert!(!vevent.overlaps(&range, &[]));
+
+        let vevent = self::vevent("DTSTART:20210301T090000Z\r\nDURATION:PT1H1M\r\n");
+        assert!(vevent.overlaps(&range, &[]));
+
+        let vevent = self::vevent("DTSTART:20210301T100000Z\r\nDURATION:PT0S\r\n");
+        assert!(vevent.overlaps(&range, &[]));
+
+        let vevent = self::vevent("DTSTART:20210301T110000Z\r\n");
+        assert!(!vevent.overlaps(&range, &[]));
+
+        let vevent = self::vevent("DTSTART:20210222T103000Z\r\nRRULE:FREQ=WEEKLY\r\n");
+        assert!(vevent.overlaps(&range, &[]));
+
+        let vevent = self::vevent(
+            "DTSTART:20210222T103000Z\r\nRRULE:FREQ=WEEKLY\r\nEXDATE:20210301T103000Z\r\n",
+        );
+        assert!(!vevent.overlaps(&range, &[]));
+
+        let vevent = self::vevent("DTSTART:20210201T103000Z\r\nRDATE:20210301T103000Z\r\n");
+        assert!(vevent.overlaps(&range, &[]));
+    }
+
+    #[test]
+    fn vtodo_overlaps() {
+        let range = range("20210301T100000Z", "20210301T110000Z");
+
+        let vtodo = self::vtodo("DUE:20210301T110000Z\r\n");
+        assert!(vtodo.overlaps(&range, &[]));
+
+        let vtodo = self::vtodo("DUE:20210301T100000Z\r\n");
+        assert!(!vtodo.overlaps(&range, &[]));
+
+        let vtodo = self::vtodo("DTSTART:20210201T100000Z\r\nDUE:20210401T100000Z\r\n");
+        assert!(vtodo.overlaps(&range, &[]));
+
+        let vtodo = self::vtodo("COMPLETED:20210301T120000Z\r\n");
+        assert!(!vtodo.overlaps(&range, &[]));
+
+        let vtodo = self::vtodo("CREATED:20210201T100000Z\r\n");
+        assert!(vtodo.overlaps(&range, &[]));
+
+        let vtodo = self::vtodo("");
+        assert!(vtodo.overlaps(&range, &[]));
+    }
+
+    #[test]
+    fn slice() -> crate::Result {
+        let vcalendar = self::vcalendar();
+        let slice = vcalendar.slice(
+            "20210310T000000Z".parse::<crate::DateTime>()?,
+            "20210320T000000Z".parse()?,
+        );
+
+        assert_eq!(slice.events.len(), 2);
+        assert_eq!(slice.events[0], vcalendar.events[0]);
+        assert_eq!(slice.events[1], vcalendar.events[1]);
+        assert_eq!(slice.timezones.len(), 1);
+        assert_eq!(slice.timezones[0].tzid.text, "Europe/Paris");
+
+        let slice = vcalendar.slice(
+            "20210601T000000Z".parse::<crate::DateTime>()?,
+            "20210602T000000Z".parse()?,
+        );
+
+        assert_eq!(slice.events.len(), 1);
+        assert_eq!(slice.events[0].uid.text, "2");
+        assert_eq!(slice.timezones.len(), 1);
+        assert_eq!(slice.timezones[0].tzid.text, "Europe/London");
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_overflow() -> crate::Result {
+        let mut vcalendar = self::vcalendar();
+        vcalendar.events = [
+            "DTSTART:20210301T100000\r\n",
+            "DTSTART:20210301T100000Z\r\n",
+            "DTSTART;TZID=Europe/Paris:20210301T100000\r\n",
+            "DTSTART;TZID=Europe/Paris:20210301T100000\r\nRRULE:FREQ=WEEKLY;COUNT=3\r\n",
+        ]
+        .iter()
+        .map(|dtstart| self::vevent(&format!("{dtstart}DURATION:P999999999W\r\n")))
+        .collect();
+
+        let slice = vcalendar.slice(
+            "20210310T000000Z".parse::<crate::DateTime>()?,
+            "20210320T000000Z".parse()?,
+        );
+
+        assert_eq!(slice.events.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_trimmed() -> crate::Result {
+        let vcalendar = self::vcalendar();
+        let slice = vcalendar.slice_trimmed(
+            "20210310T000000Z".parse::<crate::DateTime>()?,
+            "20210330T000000Z".parse()?,
+        );
+
+        assert_eq!(slice.events.len(), 2);
+
+        let vevent = &slice.events[0];
+        let paris = |s: &str| {
+            crate::Date::DateTime(
+                s.parse::<crate::DateTime>()
+                    .unwrap()
+                    .with_tzid("Europe/Paris"),
+            )
+        };
+        assert_eq!(vevent.dtstart, paris("20210315T100000"));
+        assert_eq!(vevent.dtend, Some(paris("20210315T110000")));
+        assert!(vevent.exdate.is_empty());
+
+        let rrule = vevent.rrule.as_ref().unwrap();
+        assert_eq!(rrule.count, None);
+        assert_eq!(
+            rrule.until.as_ref().unwrap().to_string(),
+            "20210329T080000Z"
+        );
+        assert_eq!(slice.validate(), []);
+
+        let slice = vcalendar.slice_trimmed(
+            "20210320T000000Z".parse::<crate::DateTime>()?,
+            "20300101T000000Z".parse()?,
+        );
+
+        let vevent = &slice.events[0];
+        assert_eq!(vevent.dtstart, paris("20210322T100000"));
+        assert!(vevent.exdate.is_empty());
+
+        let rrule = vevent.rrule.as_ref().unwrap();
+        assert_eq!(rrule.count, Some(7));
+        assert_eq!(rrule.until, None);
+        assert_eq!(slice.validate(), []);
+
+        let mut vcalendar = vcalendar;
+        vcalendar.events[1].dtstart = paris("20210324T100000");
+        vcalendar.events[1].dtend = Some(paris("20210324T110000"));
+
+        let slice = vcalendar.slice_trimmed(
+            "20210323T000000Z".parse::<crate::DateTime>()?,
+            "20210330T000000Z".parse()?,
+        );
+
+        let vevent = &slice.events[0];
+        assert_eq!(vevent.dtstart, paris("20210315T100000"));
+        assert_eq!(vevent.exdate, [paris("20210322T100000")]);
+        assert_eq!(slice.validate(), []);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+/*!
+ * Untyped component tree, shared by the alternative representations of iCalendar.
+ */
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Component {
+    pub name: String,
+    pub properties: Vec<crate::ContentLine>,
+    pub components: Vec<Component>,
+}
+
+impl Component {
+    /**
+     * Parses unfolded iCalendar data.
+     */
+    pub fn parse(ical: &str) -> crate::Result<Self> {
+        let mut stack = vec![Self::default()];
+
+        for line in ical.lines().filter(|x| !x.is_empty()) {
+            if let Some(name) = line.strip_prefix("BEGIN:") {
+                stack.push(Self {
+                    name: name.to_string(),
+                    ..Default::default()
+                });
+            } else if let Some(name) = line.strip_prefix("END:") {
+                let component = stack
+                    .pop()
+                    .filter(|x| x.name == name && !stack.is_empty())
+                    .ok_or_else(|| crate::Error::Parser(format!("Unexpected END:{name}")))?;
+
+                stack.last_mut().unwrap().components.push(component);
+            } else {
+                let (_, property) = crate::parser::content_line(&format!("{line}\r\n"))?;
+
+                stack.last_mut().unwrap().properties.push(property);
+            }
+        }
+
+        match stack.pop() {
+            Some(mut root) if stack.is_empty() && root.components.len() == 1 => {
+                Ok(root.components.remove(0))
+            }
+            _ => Err(crate::Error::Parser(
+                "Expected a single component".to_string(),
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Component {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BEGIN:{}\r\n", self.name)?;
+
+        for property in &self.properties {
+            f.write_str(&property.key)?;
+
+            for (k, v) in &property.params {
+                write!(f, ";{k}={}", crate::ser::quote(v))?;
+            }
+
+            write!(f, ":{}\r\n", property.value)?;
+        }
+
+        for component in &self.components {
+            component.fmt(f)?;
+        }
+
+        write!(f, "END:{}\r\n", self.name)
+    }
+}
+
+/**
+ * See [3.2.20. Value Data Types](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.20)
+ */
+pub(crate) fn default_type(key: &str) -> &'static str {
+    match key {
+        "ACKNOWLEDGED" | "COMPLETED" | "CREATED" | "DTEND" | "DTSTAMP" | "DTSTART" | "DUE"
+        | "EXDATE" | "LAST-MODIFIED" | "RDATE" | "RECURRENCE-ID" => "date-time",
+        "DURATION" | "REFRESH-INTERVAL" | "TRIGGER" => "duration",
+        "PERCENT-COMPLETE" | "PRIORITY" | "REPEAT" | "SEQUENCE" => "integer",
+        "GEO" => "float",
+        "RRULE" => "recur",
+        "FREEBUSY" => "period",
+        "TZOFFSETFROM" | "TZOFFSETTO" => "utc-offset",
+        "ATTACH" | "CONFERENCE" | "IMAGE" | "SOURCE" | "TZURL" | "URL" => "uri",
+        "ATTENDEE" | "ORGANIZER" => "cal-address",
+        "DEFAULT-ALARM" => "boolean",
+        "ACTION" | "CALSCALE" | "CATEGORIES" | "CLASS" | "COLOR" | "COMMENT" | "CONTACT"
+        | "DESCRIPTION" | "LOCATION" | "METHOD" | "NAME" | "PRODID" | "PROXIMITY"
+        | "RELATED-TO" | "REQUEST-STATUS" | "RESOURCES" | "STATUS" | "SUMMARY" | "TRANSP"
+        | "TZID" | "TZNAME" | "UID" | "VERSION" => "text",
+        _ => "unknown",
+    }
+}
+
+/**
+ * Returns the value type of a property, from its `VALUE` parameter or its default type.
+ */
+pub(crate) fn value_type(property: &crate::ContentLine) -> String {
+    if let Some(value) = property.params.get("VALUE") {
+        return value.to_lowercase();
+    }
+
+    match default_type(&property.key) {
+        "date-time" if !property.value.contains('T') => "date".to_string(),
+        ty => ty.to_string(),
+    }
+}
+
+/**
+ * Properties with a list of values.
+ */
+pub(crate) fn is_multiple(property: &crate::ContentLine) -> bool {
+    matches!(
+        property.key.as_str(),
+        "CATEGORIES" | "EXDATE" | "FREEBUSY" | "RDATE" | "RESOURCES"
+    )
+}
+
+/**
+ * Splits a value on unescaped separators.
+ */
+pub(crate) fn split(value: &str, separator: char) -> impl Iterator<Item = &str> {
+    let mut escaped = false;
+
+    value.split(move |c| {
+        let split = c == separator && !escaped;
+        escaped = c == '\\' && !escaped;
+
+        split
+    })
+}
+
+/**
+ * Converts a value to the ISO 8601 like form of jCal and xCal.
+ */
+pub(crate) fn to_iso(ty: &str, value: &str) -> String {
+    match ty {
+        "date" => date(value),
+        "date-time" => date_time(value),
+        "period" => value
+            .split('/')
+            .map(|x| {
+                if x.starts_with(['P', '+', '-']) {
+                    x.to_string()
+                } else {
+                    date_time(x)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/"),
+        "text" => crate::ser::unescape(value),
+        "utc-offset" => utc_offset(value),
+        _ => value.to_string(),
+    }
+}
+
+/**
+ * Reverts [`to_iso`].
+ */
+pub(crate) fn from_iso(ty: &str, value: &str) -> String {
+    match ty {
+        "date" | "date-time" | "period" => value.replace(['-', ':'], ""),
+        "text" => crate::ser::escape(value),
+        "utc-offset" => value.replace(':', ""),
+        _ => value.to_string(),
+    }
+}
+
+fn date(value: &str) -> String {
+    if value.len() < 8 || !value.is_char_boundary(4) || !value.is_char_boundary(6) {
+        return value.to_string();
+    }
+
+    format!("{}-{}-{}", &value[..4], &value[4..6], &value[6..8])
+}
+
+fn date_time(value: &str) -> String {
+    let Some((d, t)) = value.split_once('T') else {
+        return date(value);
+    };
+
+    if t.len() < 6 || !t.is_ascii() {
+        return value.to_string();
+    }
+
+    format!("{}T{}:{}:{}", date(d), &t[..2], &t[2..4], &t[4..])
+}
+
+fn utc_offset(value: &str) -> String {
+    if value.len() < 5 || !value.is_ascii() {
+        return value.to_string();
+    }
+
+    let mut offset = format!("{}:{}", &value[..3], &value[3..5]);
+
+    if value.len() > 5 {
+        offset.push(':');
+        offset.push_str(&value[5..]);
+    }
+
+    offset
+}
+
+/**
+ * Splits a recurrence rule in lowercase rule parts, UNTIL in its ISO form.
+ */
+pub(crate) fn recur(value: &str) -> Vec<(String, Vec<String>)> {
+    value
+        .split(';')
+        .filter_map(|part| part.split_once('='))
+        .map(|(k, v)| {
+            let k = k.to_lowercase();
+            let values = v
+                .split(',')
+                .map(|x| {
+                    if k == "until" {
+                        date_time(x)
+                    } else {
+                        x.to_string()
+                    }
+                })
+                .collect();
+
+            (k, values)
+        })
+        .collect()
+}
+
+/**
+ * Joins rule parts into a recurrence rule, FREQ first for compatibility with RFC 2445.
+ */
+pub(crate) fn join_recur(mut parts: Vec<(String, Vec<String>)>) -> String {
+    parts.sort_by_key(|(k, _)| k != "freq");
+
+    parts
+        .into_iter()
+        .map(|(k, values)| {
+            let values = values
+                .iter()
+                .map(|x| {
+                    if k == "until" {
+                        from_iso("date-time", x)
+                    } else {
+                        x.clone()
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            format!("{}={}", k.to_uppercase(), values.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
@@ -0,0 +1,1222 @@
+/*!
+ * Mapping of time zone identifiers.
+ *
+ * A [`crate::VTimezone::tzid`] is an opaque text: Exchange and Outlook use Windows zone names
+ * like `W. Europe Standard Time` or display strings like
+ * `(UTC+01:00) Amsterdam, Berlin, Bern, Rome, Stockholm, Vienna`. This module maps them, as
+ * well as legacy IANA aliases, to canonical [IANA](https://www.iana.org/time-zones)
+ * identifiers and back, from the built-in
+ * [CLDR `windowsZones.xml`](https://github.com/unicode-org/cldr/blob/main/common/supplemental/windowsZones.xml)
+ * table.
+ *
+ * ```
+ * assert_eq!(ikal::tzid::iana("W. Europe Standard Time"), Some("Europe/Berlin"));
+ * assert_eq!(ikal::tzid::iana("(UTC+01:00) Amsterdam, Berlin…"), Some("Europe/Berlin"));
+ * assert_eq!(ikal::tzid::iana("US/Eastern"), Some("America/New_York"));
+ * assert_eq!(ikal::tzid::windows("Europe/Amsterdam"), Some("W. Europe Standard Time"));
+ * ```
+ */
+
+/**
+ * Windows zone name, its display string and the IANA zones it covers, the first one being
+ * the CLDR territory `001`.
+ */
+type Zone = (&'static str, &'static str, &'static [&'static str]);
+
+const ZONES: &[Zone] = &[
+    (
+        "Dateline Standard Time",
+        "(UTC-12:00) International Date Line West",
+        &["Etc/GMT+12"],
+    ),
+    (
+        "UTC-11",
+        "(UTC-11:00) Coordinated Universal Time-11",
+        &[
+            "Etc/GMT+11",
+            "Pacific/Pago_Pago",
+            "Pacific/Niue",
+            "Pacific/Midway",
+        ],
+    ),
+    (
+        "Aleutian Standard Time",
+        "(UTC-10:00) Aleutian Islands",
+        &["America/Adak"],
+    ),
+    (
+        "Hawaiian Standard Time",
+        "(UTC-10:00) Hawaii",
+        &[
+            "Pacific/Honolulu",
+            "Pacific/Rarotonga",
+            "Pacific/Tahiti",
+            "Etc/GMT+10",
+        ],
+    ),
+    (
+        "Marquesas Standard Time",
+        "(UTC-09:30) Marquesas Islands",
+        &["Pacific/Marquesas"],
+    ),
+    (
+        "Alaskan Standard Time",
+        "(UTC-09:00) Alaska",
+        &[
+            "America/Anchorage",
+            "America/Juneau",
+            "America/Metlakatla",
+            "America/Nome",
+            "America/Sitka",
+            "America/Yakutat",
+        ],
+    ),
+    (
+        "UTC-09",
+        "(UTC-09:00) Coordinated Universal Time-09",
+        &["Etc/GMT+9", "Pacific/Gambier"],
+    ),
+    (
+        "Pacific Standard Time (Mexico)",
+        "(UTC-08:00) Baja California",
+        &["America/Tijuana"],
+    ),
+    (
+        "UTC-08",
+        "(UTC-08:00) Coordinated Universal Time-08",
+        &["Etc/GMT+8", "Pacific/Pitcairn"],
+    ),
+    (
+        "Pacific Standard Time",
+        "(UTC-08:00) Pacific Time (US & Canada)",
+        &["America/Los_Angeles", "America/Vancouver", "PST8PDT"],
+    ),
+    (
+        "US Mountain Standard Time",
+        "(UTC-07:00) Arizona",
+        &[
+            "America/Phoenix",
+            "America/Creston",
+            "America/Dawson_Creek",
+            "America/Fort_Nelson",
+            "America/Hermosillo",
+            "Etc/GMT+7",
+        ],
+    ),
+    (
+        "Mountain Standard Time (Mexico)",
+        "(UTC-07:00) La Paz, Mazatlan",
+        &["America/Mazatlan"],
+    ),
+    (
+        "Mountain Standard Time",
+        "(UTC-07:00) Mountain Time (US & Canada)",
+        &[
+            "America/Denver",
+            "America/Edmonton",
+            "America/Boise",
+            "America/Cambridge_Bay",
+            "America/Ciudad_Juarez",
+            "America/Inuvik",
+            "MST7MDT",
+        ],
+    ),
+    (
+        "Yukon Standard Time",
+        "(UTC-07:00) Yukon",
+        &["America/Whitehorse", "America/Dawson"],
+    ),
+    (
+        "Central America Standard Time",
+        "(UTC-06:00) Central America",
+        &[
+            "America/Guatemala",
+            "America/Belize",
+            "America/Costa_Rica",
+            "America/El_Salvador",
+            "America/Managua",
+            "America/Tegucigalpa",
+            "Pacific/Galapagos",
+            "Etc/GMT+6",
+        ],
+    ),
+    (
+        "Central Standard Time",
+        "(UTC-06:00) Central Time (US & Canada)",
+        &[
+            "America/Chicago",
+            "America/Winnipeg",
+            "America/Matamoros",
+            "America/Indiana/Knox",
+            "America/Indiana/Tell_City",
+            "America/Menominee",
+            "America/North_Dakota/Center",
+            "America/Rankin_Inlet",
+            "CST6CDT",
+        ],
+    ),
+    (
+        "Easter Island Standard Time",
+        "(UTC-06:00) Easter Island",
+        &["Pacific/Easter"],
+    ),
+    (
+        "Central Standard Time (Mexico)",
+        "(UTC-06:00) Guadalajara, Mexico City, Monterrey",
+        &[
+            "America/Mexico_City",
+            "America/Bahia_Banderas",
+            "America/Chihuahua",
+            "America/Merida",
+            "America/Monterrey",
+        ],
+    ),
+    (
+        "Canada Central Standard Time",
+        "(UTC-06:00) Saskatchewan",
+        &["America/Regina", "America/Swift_Current"],
+    ),
+    (
+        "SA Pacific Standard Time",
+        "(UTC-05:00) Bogota, Lima, Quito, Rio Branco",
+        &[
+            "America/Bogota",
+            "America/Lima",
+            "America/Guayaquil",
+            "America/Panama",
+            "America/Jamaica",
+            "America/Cayman",
+            "America/Rio_Branco",
+            "America/Eirunepe",
+            "Etc/GMT+5",
+        ],
+    ),
+    (
+        "Eastern Standard Time (Mexico)",
+        "(UTC-05:00) Chetumal",
+        &["America/Cancun"],
+    ),
+    (
+        "Eastern Standard Time",
+        "(UTC-05:00) Eastern Time (US & Canada)",
+        &[
+            "America/New_York",
+            "America/Toronto",
+            "America/Detroit",
+            "America/Nassau",
+            "America/Iqaluit",
+            "America/Kentucky/Louisville",
+            "America/Kentucky/Monticello",
+            "EST5EDT",
+        ],
+    ),
+    (
+        "Haiti Standard Time",
+        "(UTC-05:00) Haiti",
+        &["America/Port-au-Prince"],
+    ),
+    (
+        "Cuba Standard Time",
+        "(UTC-05:00) Havana",
+        &["America/Havana"],
+    ),
+    (
+        "US Eastern Standard Time",
+        "(UTC-05:00) Indiana (East)",
+        &[
+            "America/Indiana/Indianapolis",
+            "America/Indiana/Marengo",
+            "America/Indiana/Vevay",
+        ],
+    ),
+    (
+        "Turks And Caicos Standard Time",
+        "(UTC-05:00) Turks and Caicos",
+        &["America/Grand_Turk"],
+    ),
+    (
+        "Paraguay Standard Time",
+        "(UTC-04:00) Asuncion",
+        &["America/Asuncion"],
+    ),
+    (
+        "Atlantic Standard Time",
+        "(UTC-04:00) Atlantic Time (Canada)",
+        &[
+            "America/Halifax",
+            "Atlantic/Bermuda",
+            "America/Glace_Bay",
+            "America/Goose_Bay",
+            "America/Moncton",
+            "America/Thule",
+        ],
+    ),
+    (
+        "Venezuela Standard Time",
+        "(UTC-04:00) Caracas",
+        &["America/Caracas"],
+    ),
+    (
+        "Central Brazilian Standard Time",
+        "(UTC-04:00) Cuiaba",
+        &["America/Cuiaba", "America/Campo_Grande"],
+    ),
+    (
+        "SA Western Standard Time",
+        "(UTC-04:00) Georgetown, La Paz, Manaus, San Juan",
+        &[
+            "America/La_Paz",
+            "America/Manaus",
+            "America/Guyana",
+            "America/Puerto_Rico",
+            "America/Santo_Domingo",
+            "America/Barbados",
+            "America/Martinique",
+            "America/Port_of_Spain",
+            "America/Porto_Velho",
+            "America/Boa_Vista",
+            "Etc/GMT+4",
+        ],
+    ),
+    (
+        "Pacific SA Standard Time",
+        "(UTC-04:00) Santiago",
+        &["America/Santiago"],
+    ),
+    (
+        "Newfoundland Standard Time",
+        "(UTC-03:30) Newfoundland",
+        &["America/St_Johns"],
+    ),
+    (
+        "Tocantins Standard Time",
+        "(UTC-03:00) Araguaina",
+        &["America/Araguaina"],
+    ),
+    (
+        "E. South America Standard Time",
+        "(UTC-03:00) Brasilia",
+        &["America/Sao_Paulo"],
+    ),
+    (
+        "SA Eastern Standard Time",
+        "(UTC-03:00) Cayenne, Fortaleza",
+        &[
+            "America/Cayenne",
+            "America/Fortaleza",
+            "America/Belem",
+            "America/Maceio",
+            "America/Recife",
+            "America/Santarem",
+            "America/Paramaribo",
+            "Antarctica/Rothera",
+            "Atlantic/Stanley",
+            "Etc/GMT+3",
+        ],
+    ),
+    (
+        "Argentina Standard Time",
+        "(UTC-03:00) City of Buenos Aires",
+        &[
+            "America/Argentina/Buenos_Aires",
+            "America/Argentina/Catamarca",
+            "America/Argentina/Cordoba",
+            "America/Argentina/Jujuy",
+            "America/Argentina/La_Rioja",
+            "America/Argentina/Mendoza",
+            "America/Argentina/Rio_Gallegos",
+            "America/Argentina/Salta",
+            "America/Argentina/San_Juan",
+            "America/Argentina/San_Luis",
+            "America/Argentina/Tucuman",
+            "America/Argentina/Ushuaia",
+        ],
+    ),
+    (
+        "Greenland Standard Time",
+        "(UTC-03:00) Greenland",
+        &["America/Nuuk"],
+    ),
+    (
+        "Montevideo Standard Time",
+        "(UTC-03:00) Montevideo",
+        &["America/Montevideo"],
+    ),
+    (
+        "Magallanes Standard Time",
+        "(UTC-03:00) Punta Arenas",
+        &["America/Punta_Arenas"],
+    ),
+    (
+        "Saint Pierre Standard Time",
+        "(UTC-03:00) Saint Pierre and Miquelon",
+        &["America/Miquelon"],
+    ),
+    (
+        "Bahia Standard Time",
+        "(UTC-03:00) Salvador",
+        &["America/Bahia"],
+    ),
+    (
+        "UTC-02",
+        "(UTC-02:00) Coordinated Universal Time-02",
+        &["Etc/GMT+2", "America/Noronha", "Atlantic/South_Georgia"],
+    ),
+    (
+        "Azores Standard Time",
+        "(UTC-01:00) Azores",
+        &["Atlantic/Azores", "America/Scoresbysund"],
+    ),
+    (
+        "Cape Verde Standard Time",
+        "(UTC-01:00) Cabo Verde Is.",
+        &["Atlantic/Cape_Verde", "Etc/GMT+1"],
+    ),
+    (
+        "UTC",
+        "(UTC) Coordinated Universal Time",
+        &["Etc/UTC", "Etc/GMT"],
+    ),
+    (
+        "GMT Standard Time",
+        "(UTC+00:00) Dublin, Edinburgh, Lisbon, London",
+        &[
+            "Europe/London",
+            "Europe/Dublin",
+            "Europe/Lisbon",
+            "Europe/Guernsey",
+            "Europe/Isle_of_Man",
+            "Europe/Jersey",
+            "Atlantic/Canary",
+            "Atlantic/Faroe",
+            "Atlantic/Madeira",
+        ],
+    ),
+    (
+        "Greenwich Standard Time",
+        "(UTC+00:00) Monrovia, Reykjavik",
+        &[
+            "Atlantic/Reykjavik",
+            "Africa/Abidjan",
+            "Africa/Accra",
+            "Africa/Bamako",
+            "Africa/Banjul",
+            "Africa/Bissau",
+            "Africa/Conakry",
+            "Africa/Dakar",
+            "Africa/Freetown",
+            "Africa/Lome",
+            "Africa/Monrovia",
+            "Africa/Nouakchott",
+            "Africa/Ouagadougou",
+            "Atlantic/St_Helena",
+        ],
+    ),
+    (
+        "Sao Tome Standard Time",
+        "(UTC+00:00) Sao Tome",
+        &["Africa/Sao_Tome"],
+    ),
+    (
+        "Morocco Standard Time",
+        "(UTC+01:00) Casablanca",
+        &["Africa/Casablanca", "Africa/El_Aaiun"],
+    ),
+    (
+        "W. Europe Standard Time",
+        "(UTC+01:00) Amsterdam, Berlin, Bern, Rome, Stockholm, Vienna",
+        &[
+            "Europe/Berlin",
+            "Europe/Amsterdam",
+            "Europe/Andorra",
+            "Europe/Busingen",
+            "Europe/Gibraltar",
+            "Europe/Luxembourg",
+            "Europe/Malta",
+            "Europe/Monaco",
+            "Europe/Oslo",
+            "Europe/Rome",
+            "Europe/San_Marino",
+            "Europe/Stockholm",
+            "Europe/Vaduz",
+            "Europe/Vatican",
+            "Europe/Vienna",
+            "Europe/Zurich",
+            "Arctic/Longyearbyen",
+        ],
+    ),
+    (
+        "Central Europe Standard Time",
+        "(UTC+01:00) Belgrade, Bratislava, Budapest, Ljubljana, Prague",
+        &[
+            "Europe/Budapest",
+            "Europe/Belgrade",
+            "Europe/Bratislava",
+            "Europe/Ljubljana",
+            "Europe/Podgorica",
+            "Europe/Prague",
+            "Europe/Tirane",
+        ],
+    ),
+    (
+        "Romance Standard Time",
+        "(UTC+01:00) Brussels, Copenhagen, Madrid, Paris",
+        &[
+            "Europe/Paris",
+            "Europe/Brussels",
+            "Europe/Copenhagen",
+            "Europe/Madrid",
+            "Africa/Ceuta",
+        ],
+    ),
+    (
+        "Central European Standard Time",
+        "(UTC+01:00) Sarajevo, Skopje, Warsaw, Zagreb",
+        &[
+            "Europe/Warsaw",
+            "Europe/Sarajevo",
+            "Europe/Skopje",
+            "Europe/Zagreb",
+        ],
+    ),
+    (
+        "W. Central Africa Standard Time",
+        "(UTC+01:00) West Central Africa",
+        &[
+            "Africa/Lagos",
+            "Africa/Algiers",
+            "Africa/Bangui",
+            "Africa/Brazzaville",
+            "Africa/Douala",
+            "Africa/Kinshasa",
+            "Africa/Libreville",
+            "Africa/Luanda",
+            "Africa/Malabo",
+            "Africa/Ndjamena",
+            "Africa/Niamey",
+            "Africa/Porto-Novo",
+            "Africa/Tunis",
+            "Etc/GMT-1",
+        ],
+    ),
+    ("Jordan Standard Time", "(UTC+03:00) Amman", &["Asia/Amman"]),
+    (
+        "GTB Standard Time",
+        "(UTC+02:00) Athens, Bucharest",
+        &[
+            "Europe/Bucharest",
+            "Europe/Athens",
+            "Asia/Nicosia",
+            "Asia/Famagusta",
+        ],
+    ),
+    (
+        "Middle East Standard Time",
+        "(UTC+02:00) Beirut",
+        &["Asia/Beirut"],
+    ),
+    (
+        "Egypt Standard Time",
+        "(UTC+02:00) Cairo",
+        &["Africa/Cairo"],
+    ),
+    (
+        "E. Europe Standard Time",
+        "(UTC+02:00) Chisinau",
+        &["Europe/Chisinau"],
+    ),
+    (
+        "Syria Standard Time",
+        "(UTC+03:00) Damascus",
+        &["Asia/Damascus"],
+    ),
+    (
+        "West Bank Standard Time",
+        "(UTC+02:00) Gaza, Hebron",
+        &["Asia/Hebron", "Asia/Gaza"],
+    ),
+    (
+        "South Africa Standard Time",
+        "(UTC+02:00) Harare, Pretoria",
+        &[
+            "Africa/Johannesburg",
+            "Africa/Blantyre",
+            "Africa/Bujumbura",
+            "Africa/Gaborone",
+            "Africa/Harare",
+            "Africa/Kigali",
+            "Africa/Lubumbashi",
+            "Africa/Lusaka",
+            "Africa/Maputo",
+            "Africa/Maseru",
+            "Africa/Mbabane",
+            "Etc/GMT-2",
+        ],
+    ),
+    (
+        "FLE Standard Time",
+        "(UTC+02:00) Helsinki, Kyiv, Riga, Sofia, Tallinn, Vilnius",
+        &[
+            "Europe/Kyiv",
+            "Europe/Helsinki",
+            "Europe/Mariehamn",
+            "Europe/Riga",
+            "Europe/Sofia",
+            "Europe/Tallinn",
+            "Europe/Vilnius",
+        ],
+    ),
+    (
+        "Israel Standard Time",
+        "(UTC+02:00) Jerusalem",
+        &["Asia/Jerusalem"],
+    ),
+    (
+        "South Sudan Standard Time",
+        "(UTC+02:00) Juba",
+        &["Africa/Juba"],
+    ),
+    (
+        "Kaliningrad Standard Time",
+        "(UTC+02:00) Kaliningrad",
+        &["Europe/Kaliningrad"],
+    ),
+    (
+        "Sudan Standard Time",
+        "(UTC+02:00) Khartoum",
+        &["Africa/Khartoum"],
+    ),
+    (
+        "Libya Standard Time",
+        "(UTC+02:00) Tripoli",
+        &["Africa/Tripoli"],
+    ),
+    (
+        "Namibia Standard Time",
+        "(UTC+02:00) Windhoek",
+        &["Africa/Windhoek"],
+    ),
+    (
+        "Arabic Standard Time",
+        "(UTC+03:00) Baghdad",
+        &["Asia/Baghdad"],
+    ),
+    (
+        "Turkey Standard Time",
+        "(UTC+03:00) Istanbul",
+        &["Europe/Istanbul"],
+    ),
+    (
+        "Arab Standard Time",
+        "(UTC+03:00) Kuwait, Riyadh",
+        &[
+            "Asia/Riyadh",
+            "Asia/Aden",
+            "Asia/Bahrain",
+            "Asia/Kuwait",
+            "Asia/Qatar",
+        ],
+    ),
+    (
+        "Belarus Standard Time",
+        "(UTC+03:00) Minsk",
+        &["Europe/Minsk"],
+    ),
+    (
+        "Russian Standard Time",
+        "(UTC+03:00) Moscow, St. Petersburg",
+        &["Europe/Moscow", "Europe/Kirov", "Europe/Simferopol"],
+    ),
+    (
+        "E. Africa Standard Time",
+        "(UTC+03:00) Nairobi",
+        &[
+            "Africa/Nairobi",
+            "Africa/Addis_Ababa",
+            "Africa/Asmara",
+            "Africa/Dar_es_Salaam",
+            "Africa/Djibouti",
+            "Africa/Kampala",
+            "Africa/Mogadishu",
+            "Indian/Antananarivo",
+            "Indian/Comoro",
+            "Indian/Mayotte",
+            "Antarctica/Syowa",
+            "Etc/GMT-3",
+        ],
+    ),
+    (
+        "Volgograd Standard Time",
+        "(UTC+03:00) Volgograd",
+        &["Europe/Volgograd"],
+    ),
+    ("Iran Standard Time", "(UTC+03:30) Tehran", &["Asia/Tehran"]),
+    (
+        "Arabian Standard Time",
+        "(UTC+04:00) Abu Dhabi, Muscat",
+        &["Asia/Dubai", "Asia/Muscat", "Etc/GMT-4"],
+    ),
+    (
+        "Astrakhan Standard Time",
+        "(UTC+04:00) Astrakhan, Ulyanovsk",
+        &["Europe/Astrakhan", "Europe/Ulyanovsk"],
+    ),
+    (
+        "Azerbaijan Standard Time",
+        "(UTC+04:00) Baku",
+        &["Asia/Baku"],
+    ),
+    (
+        "Russia Time Zone 3",
+        "(UTC+04:00) Izhevsk, Samara",
+        &["Europe/Samara"],
+    ),
+    (
+        "Mauritius Standard Time",
+        "(UTC+04:00) Port Louis",
+        &["Indian/Mauritius", "Indian/Mahe", "Indian/Reunion"],
+    ),
+    (
+        "Saratov Standard Time",
+        "(UTC+04:00) Saratov",
+        &["Europe/Saratov"],
+    ),
+    (
+        "Georgian Standard Time",
+        "(UTC+04:00) Tbilisi",
+        &["Asia/Tbilisi"],
+    ),
+    (
+        "Caucasus Standard Time",
+        "(UTC+04:00) Yerevan",
+        &["Asia/Yerevan"],
+    ),
+    (
+        "Afghanistan Standard Time",
+        "(UTC+04:30) Kabul",
+        &["Asia/Kabul"],
+    ),
+    (
+        "West Asia Standard Time",
+        "(UTC+05:00) Ashgabat, Tashkent",
+        &[
+            "Asia/Tashkent",
+            "Asia/Aqtau",
+            "Asia/Aqtobe",
+            "Asia/Ashgabat",
+            "Asia/Atyrau",
+            "Asia/Dushanbe",
+            "Asia/Oral",
+            "Asia/Samarkand",
+            "Indian/Kerguelen",
+            "Indian/Maldives",
+            "Etc/GMT-5",
+        ],
+    ),
+    (
+        "Ekaterinburg Standard Time",
+        "(UTC+05:00) Ekaterinburg",
+        &["Asia/Yekaterinburg"],
+    ),
+    (
+        "Pakistan Standard Time",
+        "(UTC+05:00) Islamabad, Karachi",
+        &["Asia/Karachi"],
+    ),
+    (
+        "Qyzylorda Standard Time",
+        "(UTC+05:00) Qyzylorda",
+        &["Asia/Qyzylorda"],
+    ),
+    (
+        "India Standard Time",
+        "(UTC+05:30) Chennai, Kolkata, Mumbai, New Delhi",
+        &["Asia/Kolkata"],
+    ),
+    (
+        "Sri Lanka Standard Time",
+        "(UTC+05:30) Sri Jayawardenepura",
+        &["Asia/Colombo"],
+    ),
+    (
+        "Nepal Standard Time",
+        "(UTC+05:45) Kathmandu",
+        &["Asia/Kathmandu"],
+    ),
+    (
+        "Central Asia Standard Time",
+        "(UTC+06:00) Astana",
+        &[
+            "Asia/Almaty",
+            "Asia/Bishkek",
+            "Asia/Qostanay",
+            "Asia/Urumqi",
+            "Indian/Chagos",
+            "Etc/GMT-6",
+        ],
+    ),
+    (
+        "Bangladesh Standard Time",
+        "(UTC+06:00) Dhaka",
+        &["Asia/Dhaka", "Asia/Thimphu"],
+    ),
+    ("Omsk Standard Time", "(UTC+06:00) Omsk", &["Asia/Omsk"]),
+    (
+        "Myanmar Standard Time",
+        "(UTC+06:30) Yangon (Rangoon)",
+        &["Asia/Yangon", "Indian/Cocos"],
+    ),
+    (
+        "SE Asia Standard Time",
+        "(UTC+07:00) Bangkok, Hanoi, Jakarta",
+        &[
+            "Asia/Bangkok",
+            "Asia/Ho_Chi_Minh",
+            "Asia/Jakarta",
+            "Asia/Phnom_Penh",
+            "Asia/Pontianak",
+            "Asia/Vientiane",
+            "Indian/Christmas",
+            "Etc/GMT-7",
+        ],
+    ),
+    (
+        "Altai Standard Time",
+        "(UTC+07:00) Barnaul, Gorno-Altaysk",
+        &["Asia/Barnaul"],
+    ),
+    (
+        "W. Mongolia Standard Time",
+        "(UTC+07:00) Hovd",
+        &["Asia/Hovd"],
+    ),
+    (
+        "North Asia Standard Time",
+        "(UTC+07:00) Krasnoyarsk",
+        &["Asia/Krasnoyarsk", "Asia/Novokuznetsk"],
+    ),
+    (
+        "N. Central Asia Standard Time",
+        "(UTC+07:00) Novosibirsk",
+        &["Asia/Novosibirsk"],
+    ),
+    ("Tomsk Standard Time", "(UTC+07:00) Tomsk", &["Asia/Tomsk"]),
+    (
+        "China Standard Time",
+        "(UTC+08:00) Beijing, Chongqing, Hong Kong, Urumqi",
+        &["Asia/Shanghai", "Asia/Hong_Kong", "Asia/Macau"],
+    ),
+    (
+        "North Asia East Standard Time",
+        "(UTC+08:00) Irkutsk",
+        &["Asia/Irkutsk"],
+    ),
+    (
+        "Singapore Standard Time",
+        "(UTC+08:00) Kuala Lumpur, Singapore",
+        &[
+            "Asia/Singapore",
+            "Asia/Brunei",
+            "Asia/Kuala_Lumpur",
+            "Asia/Kuching",
+            "Asia/Makassar",
+            "Asia/Manila",
+            "Etc/GMT-8",
+        ],
+    ),
+    (
+        "W. Australia Standard Time",
+        "(UTC+08:00) Perth",
+        &["Australia/Perth"],
+    ),
+    (
+        "Taipei Standard Time",
+        "(UTC+08:00) Taipei",
+        &["Asia/Taipei"],
+    ),
+    (
+        "Ulaanbaatar Standard Time",
+        "(UTC+08:00) Ulaanbaatar",
+        &["Asia/Ulaanbaatar"],
+    ),
+    (
+        "Aus Central W. Standard Time",
+        "(UTC+08:45) Eucla",
+        &["Australia/Eucla"],
+    ),
+    (
+        "Transbaikal Standard Time",
+        "(UTC+09:00) Chita",
+        &["Asia/Chita"],
+    ),
+    (
+        "Tokyo Standard Time",
+        "(UTC+09:00) Osaka, Sapporo, Tokyo",
+        &[
+            "Asia/Tokyo",
+            "Asia/Dili",
+            "Asia/Jayapura",
+            "Pacific/Palau",
+            "Etc/GMT-9",
+        ],
+    ),
+    (
+        "North Korea Standard Time",
+        "(UTC+09:00) Pyongyang",
+        &["Asia/Pyongyang"],
+    ),
+    ("Korea Standard Time", "(UTC+09:00) Seoul", &["Asia/Seoul"]),
+    (
+        "Yakutsk Standard Time",
+        "(UTC+09:00) Yakutsk",
+        &["Asia/Yakutsk", "Asia/Khandyga"],
+    ),
+    (
+        "Cen. Australia Standard Time",
+        "(UTC+09:30) Adelaide",
+        &["Australia/Adelaide", "Australia/Broken_Hill"],
+    ),
+    (
+        "AUS Central Standard Time",
+        "(UTC+09:30) Darwin",
+        &["Australia/Darwin"],
+    ),
+    (
+        "E. Australia Standard Time",
+        "(UTC+10:00) Brisbane",
+        &["Australia/Brisbane", "Australia/Lindeman"],
+    ),
+    (
+        "AUS Eastern Standard Time",
+        "(UTC+10:00) Canberra, Melbourne, Sydney",
+        &["Australia/Sydney", "Australia/Melbourne"],
+    ),
+    (
+        "West Pacific Standard Time",
+        "(UTC+10:00) Guam, Port Moresby",
+        &[
+            "Pacific/Port_Moresby",
+            "Pacific/Chuuk",
+            "Pacific/Guam",
+            "Pacific/Saipan",
+            "Antarctica/DumontDUrville",
+            "Etc/GMT-10",
+        ],
+    ),
+    (
+        "Tasmania Standard Time",
+        "(UTC+10:00) Hobart",
+        &["Australia/Hobart", "Antarctica/Macquarie"],
+    ),
+    (
+        "Vladivostok Standard Time",
+        "(UTC+10:00) Vladivostok",
+        &["Asia/Vladivostok", "Asia/Ust-Nera"],
+    ),
+    (
+        "Lord Howe Standard Time",
+        "(UTC+10:30) Lord Howe Island",
+        &["Australia/Lord_Howe"],
+    ),
+    (
+        "Bougainville Standard Time",
+        "(UTC+11:00) Bougainville Island",
+        &["Pacific/Bougainville"],
+    ),
+    (
+        "Russia Time Zone 10",
+        "(UTC+11:00) Chokurdakh",
+        &["Asia/Srednekolymsk"],
+    ),
+    (
+        "Magadan Standard Time",
+        "(UTC+11:00) Magadan",
+        &["Asia/Magadan"],
+    ),
+    (
+        "Norfolk Standard Time",
+        "(UTC+11:00) Norfolk Island",
+        &["Pacific/Norfolk"],
+    ),
+    (
+        "Sakhalin Standard Time",
+        "(UTC+11:00) Sakhalin",
+        &["Asia/Sakhalin"],
+    ),
+    (
+        "Central Pacific Standard Time",
+        "(UTC+11:00) Solomon Is., New Caledonia",
+        &[
+            "Pacific/Guadalcanal",
+            "Pacific/Efate",
+            "Pacific/Kosrae",
+            "Pacific/Noumea",
+            "Pacific/Pohnpei",
+            "Antarctica/Casey",
+            "Etc/GMT-11",
+        ],
+    ),
+    (
+        "Russia Time Zone 11",
+        "(UTC+12:00) Anadyr, Petropavlovsk-Kamchatsky",
+        &["Asia/Kamchatka", "Asia/Anadyr"],
+    ),
+    (
+        "New Zealand Standard Time",
+        "(UTC+12:00) Auckland, Wellington",
+        &["Pacific/Auckland", "Antarctica/McMurdo"],
+    ),
+    (
+        "UTC+12",
+        "(UTC+12:00) Coordinated Universal Time+12",
+        &[
+            "Etc/GMT-12",
+            "Pacific/Funafuti",
+            "Pacific/Kwajalein",
+            "Pacific/Majuro",
+            "Pacific/Nauru",
+            "Pacific/Tarawa",
+            "Pacific/Wake",
+            "Pacific/Wallis",
+        ],
+    ),
+    ("Fiji Standard Time", "(UTC+12:00) Fiji", &["Pacific/Fiji"]),
+    (
+        "Chatham Islands Standard Time",
+        "(UTC+12:45) Chatham Islands",
+        &["Pacific/Chatham"],
+    ),
+    (
+        "UTC+13",
+        "(UTC+13:00) Coordinated Universal Time+13",
+        &["Etc/GMT-13", "Pacific/Fakaofo", "Pacific/Kanton"],
+    ),
+    (
+        "Tonga Standard Time",
+        "(UTC+13:00) Nuku'alofa",
+        &["Pacific/Tongatapu"],
+    ),
+    (
+        "Samoa Standard Time",
+        "(UTC+13:00) Samoa",
+        &["Pacific/Apia"],
+    ),
+    (
+        "Line Islands Standard Time",
+        "(UTC+14:00) Kiritimati Island",
+        &["Pacific/Kiritimati", "Etc/GMT-14"],
+    ),
+];
+
+/**
+ * Legacy identifiers, IANA links and retired Windows names, with their canonical IANA zone.
+ */
+const ALIASES: &[(&str, &str)] = &[
+    ("America/Buenos_Aires", "America/Argentina/Buenos_Aires"),
+    ("America/Godthab", "America/Nuuk"),
+    ("America/Indianapolis", "America/Indiana/Indianapolis"),
+    ("America/Montreal", "America/Toronto"),
+    ("Armenian Standard Time", "Asia/Yerevan"),
+    ("Asia/Calcutta", "Asia/Kolkata"),
+    ("Asia/Istanbul", "Europe/Istanbul"),
+    ("Asia/Katmandu", "Asia/Kathmandu"),
+    ("Asia/Rangoon", "Asia/Yangon"),
+    ("Asia/Saigon", "Asia/Ho_Chi_Minh"),
+    ("Australia/ACT", "Australia/Sydney"),
+    ("Australia/Canberra", "Australia/Sydney"),
+    ("Australia/NSW", "Australia/Sydney"),
+    ("Australia/Victoria", "Australia/Melbourne"),
+    ("Brazil/East", "America/Sao_Paulo"),
+    ("Canada/Atlantic", "America/Halifax"),
+    ("Canada/Central", "America/Winnipeg"),
+    ("Canada/Eastern", "America/Toronto"),
+    ("Canada/Mountain", "America/Edmonton"),
+    ("Canada/Pacific", "America/Vancouver"),
+    ("Egypt", "Africa/Cairo"),
+    ("Eire", "Europe/Dublin"),
+    ("Etc/Greenwich", "Etc/GMT"),
+    ("Etc/UCT", "Etc/UTC"),
+    ("Etc/Universal", "Etc/UTC"),
+    ("Etc/Zulu", "Etc/UTC"),
+    ("Europe/Kiev", "Europe/Kyiv"),
+    ("GB", "Europe/London"),
+    ("GMT", "Etc/GMT"),
+    ("Greenwich", "Etc/GMT"),
+    ("Hongkong", "Asia/Hong_Kong"),
+    ("Iran", "Asia/Tehran"),
+    ("Israel", "Asia/Jerusalem"),
+    ("Japan", "Asia/Tokyo"),
+    ("Kamchatka Standard Time", "Asia/Kamchatka"),
+    ("Mexico Standard Time", "America/Mexico_City"),
+    ("Mexico Standard Time 2", "America/Chihuahua"),
+    ("Mexico/General", "America/Mexico_City"),
+    ("Mid-Atlantic Standard Time", "Etc/GMT+2"),
+    ("NZ", "Pacific/Auckland"),
+    ("PRC", "Asia/Shanghai"),
+    ("Pacific/Enderbury", "Pacific/Kanton"),
+    ("Pacific/Samoa", "Pacific/Pago_Pago"),
+    ("Pacific/Truk", "Pacific/Chuuk"),
+    ("Pacific/Ponape", "Pacific/Pohnpei"),
+    ("ROC", "Asia/Taipei"),
+    ("ROK", "Asia/Seoul"),
+    ("Singapore", "Asia/Singapore"),
+    ("Turkey", "Europe/Istanbul"),
+    ("UCT", "Etc/UTC"),
+    ("US/Alaska", "America/Anchorage"),
+    ("US/Aleutian", "America/Adak"),
+    ("US/Arizona", "America/Phoenix"),
+    ("US/Central", "America/Chicago"),
+    ("US/East-Indiana", "America/Indiana/Indianapolis"),
+    ("US/Eastern", "America/New_York"),
+    ("US/Hawaii", "Pacific/Honolulu"),
+    ("US/Michigan", "America/Detroit"),
+    ("US/Mountain", "America/Denver"),
+    ("US/Pacific", "America/Los_Angeles"),
+    ("US/Samoa", "Pacific/Pago_Pago"),
+    ("Universal", "Etc/UTC"),
+    ("Zulu", "Etc/UTC"),
+];
+
+/**
+ * Canonical IANA identifier of a Windows zone name, an Outlook display string, a legacy
+ * alias or an IANA zone, `None` if the identifier is unknown.
+ *
+ * Display strings are matched without their `(UTC±hh:mm)` prefix and may be truncated.
+ */
+#[must_use]
+pub fn iana(tzid: &str) -> Option<&'static str> {
+    let tzid = tzid.trim();
+
+    if let Some((_, iana)) = ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(tzid))
+    {
+        return Some(iana);
+    }
+
+    ZONES
+        .iter()
+        .find_map(|(windows, _, zones)| {
+            if windows.eq_ignore_ascii_case(tzid) {
+                Some(zones[0])
+            } else {
+                zones
+                    .iter()
+                    .find(|zone| zone.eq_ignore_ascii_case(tzid))
+                    .copied()
+            }
+        })
+        .or_else(|| display(tzid))
+}
+
+/**
+ * Windows zone name covering an IANA zone or one of its aliases.
+ */
+#[must_use]
+pub fn windows(iana: &str) -> Option<&'static str> {
+    let iana = self::iana(iana)?;
+
+    ZONES
+        .iter()
+        .find(|(_, _, zones)| zones.contains(&iana))
+        .map(|(windows, _, _)| *windows)
+}
+
+fn display(tzid: &str) -> Option<&'static str> {
+    let tzid = cities(tzid);
+
+    if tzid.is_empty() {
+        return None;
+    }
+
+    // A truncated display string may be the prefix of several ones
+    let exact = ZONES
+        .iter()
+        .find(|(_, display, _)| cities(display).eq_ignore_ascii_case(tzid));
+    let prefix = || {
+        ZONES.iter().find(|(_, display, _)| {
+            cities(display)
+                .get(..tzid.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(tzid))
+        })
+    };
+
+    exact.or_else(prefix).map(|(_, _, zones)| zones[0])
+}
+
+/**
+ * Cities of a display string, without the offset nor the trailing ellipsis.
+ */
+fn cities(display: &str) -> &str {
+    let cities = match display.split_once(") ") {
+        Some((offset, cities)) if offset.starts_with('(') => cities,
+        _ => display,
+    };
+
+    cities
+        .trim_end_matches(['…', '.'])
+        .trim_end_matches([',', ' '])
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn iana() {
+        let tests = [
+            ("W. Europe Standard Time", Some("Europe/Berlin")),
+            ("romance standard time", Some("Europe/Paris")),
+            (
+                "(UTC+01:00) Brussels, Copenhagen, Madrid, Paris",
+                Some("Europe/Paris"),
+            ),
+            (
+                "(GMT+01.00) Amsterdam, Berlin, Bern, Rome, Stockholm, Vienna",
+                Some("Europe/Berlin"),
+            ),
+            ("(UTC+01:00) Amsterdam, Berlin…", Some("Europe/Berlin")),
+            ("(UTC) Dublin, Edinburgh...", Some("Europe/London")),
+            ("Europe/Amsterdam", Some("Europe/Amsterdam")),
+            ("Asia/Calcutta", Some("Asia/Kolkata")),
+            ("US/Pacific", Some("America/Los_Angeles")),
+            ("Mexico Standard Time", Some("America/Mexico_City")),
+            ("Customized Time Zone", None),
+            ("(UTC+01:00) ", None),
+        ];
+
+        for (tzid, expected) in tests {
+            assert_eq!(super::iana(tzid), expected, "{tzid}");
+        }
+    }
+
+    #[test]
+    fn windows() {
+        let tests = [
+            ("Europe/Berlin", Some("W. Europe Standard Time")),
+            ("Europe/Zurich", Some("W. Europe Standard Time")),
+            ("Europe/Kiev", Some("FLE Standard Time")),
+            ("US/Eastern", Some("Eastern Standard Time")),
+            ("Etc/UTC", Some("UTC")),
+            ("Mars/Olympus_Mons", None),
+        ];
+
+        for (iana, expected) in tests {
+            assert_eq!(super::windows(iana), expected, "{iana}");
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for (windows, display, zones) in super::ZONES {
+            assert_eq!(super::iana(windows), Some(zones[0]), "{windows}");
+            assert_eq!(super::iana(display), Some(zones[0]), "{display}");
+
+            for zone in *zones {
+                assert_eq!(super::windows(zone), Some(*windows), "{zone}");
+            }
+        }
+
+        for (alias, iana) in super::ALIASES {
+            assert!(super::windows(iana).is_some(), "{alias}");
+        }
+    }
+}
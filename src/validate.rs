@@ -0,0 +1,934 @@
+/*!
+ * Semantic validation of components.
+ *
+ * The parser only checks that required properties are present, [`crate::VCalendar::validate`]
+ * and the `validate` method of each component report the other constraints of
+ * [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545).
+ *
+ * A calendar also checks the references between its components: `TZID` parameters,
+ * `RECURRENCE-ID` overrides, `RELATED-TO` targets and the uniqueness of `UID` and time zones.
+ *
+ * ```
+ * let mut vevent = ikal::VEvent {
+ *     uid: "a8df6573-0474-496d-8496-033ad45d7fea".into(),
+ *     dtstart: "20200102T110000".parse()?,
+ *     dtend: Some("20200102T100000".parse()?),
+ *
+ *     ..Default::default()
+ * };
+ *
+ * let findings = vevent.validate();
+ * assert_eq!(findings[0].rule, ikal::validate::Rule::EndBeforeStart);
+ * assert_eq!(findings[0].severity, ikal::validate::Severity::Error);
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /** Allowed, but likely to be misinterpreted */
+    Warning,
+    /** Violates a MUST of the RFC */
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        };
+
+        f.write_str(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Rule {
+    /** Both `DTEND` and `DURATION` on an event */
+    DtendAndDuration,
+    /** Both `DUE` and `DURATION` on a to-do */
+    DueAndDuration,
+    /** `DURATION` on a to-do without `DTSTART` */
+    DurationWithoutStart,
+    /** `DTEND` or `DUE` before `DTSTART` */
+    EndBeforeStart,
+    /** `DTEND` or `DUE` with another value type than `DTSTART` */
+    EndValueType,
+    /** `BYSECOND`, `BYMINUTE` or `BYHOUR` with a `DATE` start */
+    TimePartsWithDate,
+    /** `UNTIL` with another value type than `DTSTART` */
+    UntilValueType,
+    /** Floating `UNTIL` while `DTSTART` is in UTC or has a `TZID` */
+    UntilNotUtc,
+    /** Both `COUNT` and `UNTIL` in a recurrence rule */
+    CountAndUntil,
+    /** `DURATION` without `REPEAT` on an alarm, or the opposite */
+    RepeatWithoutDuration,
+    /** A property required by the alarm action is missing */
+    MissingAlarmProperty,
+    /** A time zone without `STANDARD` nor `DAYLIGHT` */
+    EmptyTimezone,
+    /** A `TZID` parameter without the matching `VTIMEZONE` */
+    UnknownTimezone,
+    /** Two `VTIMEZONE` with the same `TZID` */
+    DuplicateTimezone,
+    /** Two components with the same `UID` which aren’t overrides of the same master */
+    DuplicateUid,
+    /** A `RECURRENCE-ID` which isn’t an instance of its master */
+    UnmatchedRecurrenceId,
+    /** A `RELATED-TO` target missing from the calendar */
+    UnknownRelatedTo,
+}
+
+impl Rule {
+    #[must_use]
+    pub fn severity(&self) -> Severity {
+        match self {
+            // The target may be stored in another calendar object
+            Self::UnknownRelatedTo => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /**
+     * Section of RFC 5545 defining the rule.
+     */
+    #[must_use]
+    pub fn section(&self) -> &'static str {
+        match self {
+            Self::DtendAndDuration => "3.6.1",
+            Self::DueAndDuration | Self::DurationWithoutStart => "3.6.2",
+            Self::EndBeforeStart | Self::EndValueType => "3.8.2.2",
+            Self::TimePartsWithDate
+            | Self::UntilValueType
+            | Self::UntilNotUtc
+            | Self::CountAndUntil => "3.3.10",
+            Self::RepeatWithoutDuration | Self::MissingAlarmProperty => "3.6.6",
+            Self::EmptyTimezone | Self::DuplicateTimezone => "3.6.5",
+            Self::UnknownTimezone => "3.2.19",
+            Self::DuplicateUid => "3.8.4.7",
+            Self::UnmatchedRecurrenceId => "3.8.4.4",
+            Self::UnknownRelatedTo => "3.8.4.5",
+        }
+    }
+
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!(
+            "https://datatracker.ietf.org/doc/html/rfc5545#section-{}",
+            self.section()
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Finding {
+    pub rule: Rule,
+    pub severity: Severity,
+    pub component: crate::Components,
+    /**
+     * UID of the component, of the enclosing one for an alarm, or the TZID of a time zone.
+     */
+    pub id: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} {}: {} (RFC 5545 section {})",
+            self.severity,
+            self.component,
+            self.id,
+            self.message,
+            self.rule.section()
+        )
+    }
+}
+
+/**
+ * Collects the findings of a component.
+ */
+struct Findings<'a> {
+    component: crate::Components,
+    id: &'a str,
+    findings: Vec<Finding>,
+}
+
+impl<'a> Findings<'a> {
+    fn new(component: crate::Components, id: &'a str) -> Self {
+        Self {
+            component,
+            id,
+            findings: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, rule: Rule, message: impl Into<String>) {
+        self.findings.push(Finding {
+            rule,
+            severity: rule.severity(),
+            component: self.component,
+            id: self.id.to_string(),
+            message: message.into(),
+        });
+    }
+
+    fn end(&mut self, name: &str, dtstart: Option<&crate::Date>, end: Option<&crate::Date>) {
+        let (Some(dtstart), Some(end)) = (dtstart, end) else {
+            return;
+        };
+
+        if dtstart.has_time() != end.has_time() {
+            self.push(
+                Rule::EndValueType,
+                format!("{name} and DTSTART must have the same value type"),
+            );
+        } else if end < dtstart {
+            self.push(
+                Rule::EndBeforeStart,
+                format!("{name} {end} is before DTSTART {dtstart}"),
+            );
+        }
+    }
+
+    fn rrule(&mut self, dtstart: Option<&crate::Date>, rrule: Option<&crate::Recur>) {
+        let Some(rrule) = rrule else {
+            return;
+        };
+
+        if rrule.count.is_some() && rrule.until.is_some() {
+            self.push(
+                Rule::CountAndUntil,
+                "COUNT and UNTIL must not occur in the same RRULE",
+            );
+        }
+
+        let Some(dtstart) = dtstart else {
+            return;
+        };
+
+        let time_parts =
+            !(rrule.by_second.is_empty() && rrule.by_minute.is_empty() && rrule.by_hour.is_empty());
+
+        if !dtstart.has_time() && time_parts {
+            self.push(
+                Rule::TimePartsWithDate,
+                "BYSECOND, BYMINUTE and BYHOUR must not be used with a DATE DTSTART",
+            );
+        }
+
+        match (dtstart, &rrule.until) {
+            (crate::Date::Date(_), Some(crate::Date::DateTime(_)))
+            | (crate::Date::DateTime(_), Some(crate::Date::Date(_))) => {
+                self.push(
+                    Rule::UntilValueType,
+                    "UNTIL must have the same value type as DTSTART",
+                );
+            }
+            (
+                crate::Date::DateTime(crate::DateTime::Local(_) | crate::DateTime::Zoned(..)),
+                Some(crate::Date::DateTime(crate::DateTime::Naive(_))),
+            ) => {
+                self.push(
+                    Rule::UntilNotUtc,
+                    "UNTIL must be in UTC when DTSTART is not floating",
+                );
+            }
+            _ => (),
+        }
+    }
+
+    fn alarms(&mut self, alarms: &[crate::VAlarm]) {
+        for alarm in alarms {
+            self.findings.extend(valarm(alarm, self.id));
+        }
+    }
+}
+
+pub(crate) fn vcalendar(vcalendar: &crate::VCalendar) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    findings.extend(vcalendar.events.iter().flat_map(vevent));
+    findings.extend(vcalendar.todo.iter().flat_map(vtodo));
+    findings.extend(vcalendar.journals.iter().flat_map(vjournal));
+    findings.extend(vcalendar.freebusy.iter().flat_map(vfreebusy));
+    findings.extend(vcalendar.timezones.iter().flat_map(vtimezone));
+    findings.extend(vcalendar.alarms.iter().flat_map(|x| valarm(x, "")));
+    findings.extend(references(vcalendar));
+
+    findings
+}
+
+/**
+ * Properties of a component referring to other components.
+ */
+struct Entry<'a> {
+    component: crate::Components,
+    uid: &'a str,
+    recurid: Option<&'a crate::Date>,
+    related_to: Vec<&'a crate::Text>,
+    dates: Vec<&'a crate::DateTime>,
+}
+
+impl<'a> Entry<'a> {
+    fn new(component: crate::Components, uid: &'a str) -> Self {
+        Self {
+            component,
+            uid,
+            recurid: None,
+            related_to: Vec::new(),
+            dates: Vec::new(),
+        }
+    }
+
+    fn date(mut self, date: Option<&'a crate::Date>) -> Self {
+        if let Some(crate::Date::DateTime(date_time)) = date {
+            self.dates.push(date_time);
+        }
+
+        self
+    }
+
+    fn recurrence(
+        mut self,
+        recurid: Option<&'a crate::Date>,
+        exdate: &'a [crate::Date],
+        rdate: &'a [crate::RDate],
+    ) -> Self {
+        self.recurid = recurid;
+        self = self.date(recurid);
+
+        for date in exdate {
+            self = self.date(Some(date));
+        }
+
+        for rdate in rdate {
+            match rdate {
+                crate::RDate::Date(dates) => {
+                    for date in dates {
+                        self = self.date(Some(date));
+                    }
+                }
+                crate::RDate::Period(periods) => {
+                    self.dates.extend(periods.iter().map(|x| match x {
+                        crate::Period::StartEnd(x) => &x.start,
+                        crate::Period::StartDur(x) => &x.start,
+                    }));
+                }
+            }
+        }
+
+        self
+    }
+
+    fn related_to(mut self, related_to: &'a [crate::Text], alarms: &'a [crate::VAlarm]) -> Self {
+        self.related_to.extend(related_to);
+        self.related_to
+            .extend(alarms.iter().flat_map(crate::VAlarm::related_to));
+
+        self
+    }
+}
+
+fn entries(vcalendar: &crate::VCalendar) -> Vec<Entry<'_>> {
+    let mut entries = Vec::new();
+
+    for vevent in &vcalendar.events {
+        entries.push(
+            Entry::new(crate::Components::Event, &vevent.uid)
+                .date(Some(&vevent.dtstart))
+                .date(vevent.dtend.as_ref())
+                .recurrence(vevent.recurid.as_ref(), &vevent.exdate, &vevent.rdate)
+                .related_to(&vevent.related_to, &vevent.alarms),
+        );
+    }
+
+    for vtodo in &vcalendar.todo {
+        entries.push(
+            Entry::new(crate::Components::Todo, &vtodo.uid)
+                .date(vtodo.dtstart.as_ref())
+                .date(vtodo.due.as_ref())
+                .recurrence(vtodo.recurid.as_ref(), &vtodo.exdate, &vtodo.rdate)
+                .related_to(&vtodo.related_to, &vtodo.alarms),
+        );
+    }
+
+    for vjournal in &vcalendar.journals {
+        entries.push(
+            Entry::new(crate::Components::Journal, &vjournal.uid)
+                .date(Some(&vjournal.dtstart))
+                .recurrence(vjournal.recurid.as_ref(), &vjournal.exdate, &vjournal.rdate)
+                .related_to(&vjournal.related_to, &[]),
+        );
+    }
+
+    for vfreebusy in &vcalendar.freebusy {
+        entries.push(
+            Entry::new(crate::Components::Freebusy, &vfreebusy.uid)
+                .date(vfreebusy.dtstart.as_ref())
+                .date(vfreebusy.dtend.as_ref()),
+        );
+    }
+
+    entries
+}
+
+fn references(vcalendar: &crate::VCalendar) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut tzids = std::collections::BTreeSet::new();
+
+    for vtimezone in &vcalendar.timezones {
+        if !tzids.insert(vtimezone.tzid.text.as_str()) {
+            let mut timezone = Findings::new(crate::Components::Timezone, &vtimezone.tzid);
+            timezone.push(
+                Rule::DuplicateTimezone,
+                "another VTIMEZONE has the same TZID",
+            );
+            findings.extend(timezone.findings);
+        }
+    }
+
+    let entries = entries(vcalendar);
+
+    let uids = entries
+        .iter()
+        .map(|x| x.uid)
+        .chain(
+            vcalendar
+                .events
+                .iter()
+                .flat_map(|x| &x.alarms)
+                .chain(vcalendar.todo.iter().flat_map(|x| &x.alarms))
+                .filter_map(|x| x.uid().map(|x| x.text.as_str())),
+        )
+        .collect::<std::collections::BTreeSet<_>>();
+
+    for (n, entry) in entries.iter().enumerate() {
+        let mut component = Findings::new(entry.component, entry.uid);
+
+        let mut unknown = entry
+            .dates
+            .iter()
+            .filter_map(|x| x.tzid())
+            .filter(|x| !tzids.contains(x))
+            .collect::<Vec<_>>();
+        unknown.sort_unstable();
+        unknown.dedup();
+
+        for tzid in unknown {
+            component.push(
+                Rule::UnknownTimezone,
+                format!("no VTIMEZONE defines the TZID {tzid}"),
+            );
+        }
+
+        for related_to in &entry.related_to {
+            if !uids.contains(related_to.text.as_str()) {
+                component.push(
+                    Rule::UnknownRelatedTo,
+                    format!("RELATED-TO {} isn’t in the calendar", related_to.text),
+                );
+            }
+        }
+
+        let duplicate = entries[..n].iter().any(|x| {
+            x.uid == entry.uid && (x.component != entry.component || x.recurid == entry.recurid)
+        });
+
+        if duplicate {
+            let message = match entry.recurid {
+                Some(recurid) => format!("another component overrides the instance {recurid}"),
+                None => "another component has the same UID".to_string(),
+            };
+            component.push(Rule::DuplicateUid, message);
+        }
+
+        findings.extend(component.findings);
+    }
+
+    findings.extend(overrides(&vcalendar.events, crate::Components::Event));
+    findings.extend(overrides(&vcalendar.todo, crate::Components::Todo));
+    findings.extend(overrides(&vcalendar.journals, crate::Components::Journal));
+
+    findings
+}
+
+/**
+ * Checks the `RECURRENCE-ID` of overrides against the instances of their master.
+ */
+fn overrides<T: crate::iter::Override>(components: &[T], kind: crate::Components) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for component in components {
+        let Some(recurid) = component.recurid() else {
+            continue;
+        };
+
+        // A lone override is valid, like an iTIP message about a single instance
+        let Some(master) = components
+            .iter()
+            .find(|x| x.uid() == component.uid() && x.recurid().is_none())
+        else {
+            continue;
+        };
+
+        if generates(master, recurid) == Some(false) {
+            let mut finding = Findings::new(kind, component.uid());
+            finding.push(
+                Rule::UnmatchedRecurrenceId,
+                format!("RECURRENCE-ID {recurid} isn’t an instance of the master"),
+            );
+            findings.extend(finding.findings);
+        }
+    }
+
+    findings
+}
+
+/**
+ * Whether `master` has an instance at `recurid`, `None` if it can’t be determined.
+ */
+pub(crate) fn generates<T: crate::iter::Override>(
+    master: &T,
+    recurid: &crate::Date,
+) -> Option<bool> {
+    let dtstart = master.dtstart()?;
+
+    let mut instances = vec![dtstart.clone()];
+
+    for rdate in master.rdate() {
+        match rdate {
+            crate::RDate::Date(dates) => instances.extend(dates.iter().cloned()),
+            crate::RDate::Period(periods) => {
+                instances.extend(periods.iter().map(|x| match x {
+                    crate::Period::StartEnd(x) => x.start.clone().into(),
+                    crate::Period::StartDur(x) => x.start.clone().into(),
+                }));
+            }
+        }
+    }
+
+    if instances
+        .iter()
+        .any(|x| same_instant(x, recurid) == Some(true))
+    {
+        return Some(true);
+    }
+
+    if let Some(rrule) = master.rrule() {
+        // The BYxxx parts aren’t expanded by the iterator
+        let expanded = !(rrule.by_second.is_empty()
+            && rrule.by_minute.is_empty()
+            && rrule.by_hour.is_empty()
+            && rrule.by_day.is_empty()
+            && rrule.by_monthday.is_empty()
+            && rrule.by_yearday.is_empty()
+            && rrule.by_weekno.is_empty()
+            && rrule.by_month.is_empty()
+            && rrule.by_setpos.is_empty());
+
+        if expanded {
+            return None;
+        }
+
+        let found = crate::iter::Recur::from(master)
+            .map(|x| x.dtstart().cloned())
+            .take_while(|x| x.as_ref().is_some_and(|x| x <= recurid))
+            .any(|x| x.is_some_and(|x| same_instant(&x, recurid) == Some(true)));
+
+        if found {
+            return Some(true);
+        }
+    }
+
+    let comparable = instances.iter().all(|x| same_instant(x, recurid).is_some());
+
+    comparable.then_some(false)
+}
+
+/**
+ * Compares two dates, `None` when they are in different time zones.
+ */
+fn same_instant(a: &crate::Date, b: &crate::Date) -> Option<bool> {
+    use crate::DateTime::{Local, Naive, Zoned};
+
+    match (a, b) {
+        (crate::Date::Date(a), crate::Date::Date(b)) => Some(a == b),
+        (crate::Date::DateTime(a), crate::Date::DateTime(b)) => match (a, b) {
+            (Local(a), Local(b)) => Some(a == b),
+            (Naive(a), Naive(b)) => Some(a == b),
+            (Zoned(a, a_tzid), Zoned(b, b_tzid)) if a_tzid == b_tzid => Some(a == b),
+            _ => None,
+        },
+        _ => Some(false),
+    }
+}
+
+pub(crate) fn vevent(vevent: &crate::VEvent) -> Vec<Finding> {
+    let mut findings = Findings::new(crate::Components::Event, &vevent.uid);
+
+    if vevent.dtend.is_some() && vevent.duration.is_some() {
+        findings.push(
+            Rule::DtendAndDuration,
+            "DTEND and DURATION must not occur in the same event",
+        );
+    }
+
+    findings.end("DTEND", Some(&vevent.dtstart), vevent.dtend.as_ref());
+    findings.rrule(Some(&vevent.dtstart), vevent.rrule.as_ref());
+    findings.alarms(&vevent.alarms);
+
+    findings.findings
+}
+
+pub(crate) fn vtodo(vtodo: &crate::VTodo) -> Vec<Finding> {
+    let mut findings = Findings::new(crate::Components::Todo, &vtodo.uid);
+
+    if vtodo.due.is_some() && vtodo.duration.is_some() {
+        findings.push(
+            Rule::DueAndDuration,
+            "DUE and DURATION must not occur in the same to-do",
+        );
+    }
+
+    if vtodo.dtstart.is_none() && vtodo.duration.is_some() {
+        findings.push(Rule::DurationWithoutStart, "DURATION requires DTSTART");
+    }
+
+    findings.end("DUE", vtodo.dtstart.as_ref(), vtodo.due.as_ref());
+    findings.rrule(vtodo.dtstart.as_ref(), vtodo.rrule.as_ref());
+    findings.alarms(&vtodo.alarms);
+
+    findings.findings
+}
+
+pub(crate) fn vjournal(vjournal: &crate::VJournal) -> Vec<Finding> {
+    let mut findings = Findings::new(crate::Components::Journal, &vjournal.uid);
+
+    findings.rrule(Some(&vjournal.dtstart), vjournal.rrule.as_ref());
+
+    findings.findings
+}
+
+pub(crate) fn vfreebusy(vfreebusy: &crate::VFreebusy) -> Vec<Finding> {
+    let mut findings = Findings::new(crate::Components::Freebusy, &vfreebusy.uid);
+
+    findings.end(
+        "DTEND",
+        vfreebusy.dtstart.as_ref(),
+        vfreebusy.dtend.as_ref(),
+    );
+
+    findings.findings
+}
+
+pub(crate) fn vtimezone(vtimezone: &crate::VTimezone) -> Vec<Finding> {
+    let mut findings = Findings::new(crate::Components::Timezone, &vtimezone.tzid);
+
+    if vtimezone.standard.is_empty() && vtimezone.daylight.is_empty() {
+        findings.push(
+            Rule::EmptyTimezone,
+            "STANDARD or DAYLIGHT must occur at least once",
+        );
+    }
+
+    for standard in &vtimezone.standard {
+        findings.rrule(Some(&standard.dtstart), standard.rrule.as_ref());
+    }
+
+    for daylight in &vtimezone.daylight {
+        findings.rrule(Some(&daylight.dtstart), daylight.rrule.as_ref());
+    }
+
+    findings.findings
+}
+
+/**
+ * `id` is the UID of the component owning the alarm.
+ */
+pub(crate) fn valarm(valarm: &crate::VAlarm, id: &str) -> Vec<Finding> {
+    let mut findings = Findings::new(crate::Components::Alarm, id);
+
+    if valarm.duration().is_some() != valarm.repeat().is_some() {
+        findings.push(
+            Rule::RepeatWithoutDuration,
+            "DURATION and REPEAT must both occur or neither",
+        );
+    }
+
+    let mut missing = Vec::new();
+
+    match valarm {
+        crate::VAlarm::Display(display) => {
+            if display.description.is_empty() {
+                missing.push("DESCRIPTION");
+            }
+        }
+        crate::VAlarm::Email(email) => {
+            if email.description.is_empty() {
+                missing.push("DESCRIPTION");
+            }
+            if email.summary.is_empty() {
+                missing.push("SUMMARY");
+            }
+            if email.attendee.is_empty() {
+                missing.push("ATTENDEE");
+            }
+        }
+        crate::VAlarm::Audio(_) | crate::VAlarm::NoAction(_) | crate::VAlarm::Custom(_) => (),
+    }
+
+    for property in missing {
+        findings.push(
+            Rule::MissingAlarmProperty,
+            format!("{property} is required by the alarm action"),
+        );
+    }
+
+    findings.findings
+}
+
+#[cfg(test)]
+mod test {
+    use crate::validate::Rule;
+
+    fn rules(findings: Vec<crate::validate::Finding>) -> Vec<Rule> {
+        findings.into_iter().map(|x| x.rule).collect()
+    }
+
+    #[test]
+    fn vevent() -> crate::Result {
+        let mut vevent = crate::vevent! {
+            uid: "19970901T130000Z-123401@example.com",
+            dtstamp: "19970901T130000Z",
+            dtstart: "19970903",
+            dtend: "19970902",
+            duration: "P1D",
+        }?;
+        vevent.rrule = Some("FREQ=DAILY;COUNT=4;UNTIL=19970910T000000;BYHOUR=10".parse()?);
+
+        assert_eq!(
+            rules(vevent.validate()),
+            [
+                Rule::DtendAndDuration,
+                Rule::EndBeforeStart,
+                Rule::CountAndUntil,
+                Rule::TimePartsWithDate,
+                Rule::UntilValueType,
+            ]
+        );
+
+        vevent.dtend = Some("19970904".parse()?);
+        vevent.duration = None;
+        vevent.rrule = Some("FREQ=DAILY;UNTIL=19970910".parse()?);
+        assert!(vevent.validate().is_empty());
+
+        vevent.dtend = Some("19970904T100000".parse()?);
+        assert_eq!(rules(vevent.validate()), [Rule::EndValueType]);
+
+        vevent.dtstart = "19970903T100000Z".parse()?;
+        vevent.dtend = None;
+        vevent.rrule = Some("FREQ=DAILY;UNTIL=19970910T000000".parse()?);
+        let findings = vevent.validate();
+        assert_eq!(rules(findings.clone()), [Rule::UntilNotUtc]);
+        assert_eq!(findings[0].severity, crate::validate::Severity::Error);
+
+        vevent.dtstart = "19970903T100000"
+            .parse::<crate::Date>()?
+            .with_tzid("America/New_York");
+        assert_eq!(rules(vevent.validate()), [Rule::UntilNotUtc]);
+
+        vevent.rrule = Some("FREQ=DAILY;UNTIL=19970910T000000Z".parse()?);
+        assert!(vevent.validate().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn vtodo() -> crate::Result {
+        let vtodo = crate::vtodo! {
+            uid: "20070313T123432Z-456553@example.com",
+            dtstamp: "20070313T123432Z",
+            due: "20070501",
+            duration: "PT1H",
+        }?;
+
+        assert_eq!(
+            rules(vtodo.validate()),
+            [Rule::DueAndDuration, Rule::DurationWithoutStart]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn valarm() -> crate::Result {
+        let mut vevent = crate::vevent! {
+            uid: "19970901T130000Z-123401@example.com",
+            dtstamp: "19970901T130000Z",
+            dtstart: "19970903T163000Z",
+        }?;
+        vevent.alarms.push(crate::valarm! {
+            @display,
+            trigger: "-PT15M",
+            repeat: 2,
+        }?);
+
+        let findings = vevent.validate();
+
+        assert_eq!(
+            rules(findings.clone()),
+            [Rule::RepeatWithoutDuration, Rule::MissingAlarmProperty]
+        );
+        assert_eq!(findings[0].component, crate::Components::Alarm);
+        assert_eq!(findings[0].id, "19970901T130000Z-123401@example.com");
+        assert_eq!(
+            findings[1].to_string(),
+            "error: VALARM 19970901T130000Z-123401@example.com: DESCRIPTION is required by the alarm action (RFC 5545 section 3.6.6)"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn references() -> crate::Result {
+        let input =
+            std::fs::read_to_string("tests/calendars/issue_466_respect_unique_timezone.ics")
+                .unwrap();
+        let mut vcalendar = crate::VCalendar::try_from(input)?;
+
+        assert!(vcalendar.validate().is_empty());
+
+        vcalendar.timezones.push(vcalendar.timezones[0].clone());
+        let master = vcalendar.events[0].clone();
+
+        let mut instance = master.clone();
+        instance.rrule = None;
+        instance.recurid = Some(master.dtstart.clone() + crate::Duration::weeks(1));
+        vcalendar.events.push(instance.clone());
+
+        instance.recurid = Some(master.dtstart.clone() + crate::Duration::days(1));
+        instance.dtstart = "20221022T200000"
+            .parse::<crate::Date>()?
+            .with_tzid("Europe/Paris");
+        instance.dtend = None;
+        instance.related_to = vec!["missing@example.com".into()];
+        vcalendar.events.push(instance.clone());
+        vcalendar.events.push(instance);
+
+        let findings = vcalendar.validate();
+
+        assert_eq!(
+            rules(findings.clone()),
+            [
+                Rule::DuplicateTimezone,
+                Rule::UnknownTimezone,
+                Rule::UnknownRelatedTo,
+                Rule::UnknownTimezone,
+                Rule::UnknownRelatedTo,
+                Rule::DuplicateUid,
+            ]
+        );
+        assert_eq!(findings[2].severity, crate::validate::Severity::Warning);
+
+        // FREQ=WEEKLY;BYDAY=FR isn’t expanded, the overrides can’t be checked
+        let mut master = vcalendar.events.remove(0);
+        master.rrule = Some("FREQ=WEEKLY".parse()?);
+        vcalendar.events.insert(0, master);
+
+        assert_eq!(
+            rules(vcalendar.validate())
+                .into_iter()
+                .filter(|x| *x == Rule::UnmatchedRecurrenceId)
+                .count(),
+            2
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn files() {
+        let tests = std::path::Path::new("tests/calendars");
+        let mut errors = Vec::new();
+
+        for file in tests.read_dir().unwrap() {
+            let file = file.unwrap().path();
+
+            if file.extension() != Some(std::ffi::OsStr::new("ics")) {
+                continue;
+            }
+
+            let input = std::fs::read_to_string(&file).unwrap();
+            let Ok(calendar) = crate::VCalendar::try_from(input) else {
+                continue;
+            };
+
+            let name = file.file_name().unwrap().to_string_lossy().to_string();
+
+            errors.extend(
+                calendar
+                    .validate()
+                    .into_iter()
+                    .filter(|x| x.severity == crate::validate::Severity::Error)
+                    .map(|x| (name.clone(), x.rule)),
+            );
+        }
+
+        errors.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let expected = [
+            // DTSTART is a DATE-TIME and DTEND a DATE
+            ("1.ics", Rule::EndValueType),
+            ("1.ics", Rule::UnknownTimezone),
+            (
+                "created_calendar_with_unicode_fields.ics",
+                Rule::DuplicateUid,
+            ),
+            (
+                "created_calendar_with_unicode_fields.ics",
+                Rule::DuplicateUid,
+            ),
+            (
+                "issue_156_RDATE_with_PERIOD_TZID_khal.ics",
+                Rule::UnknownTimezone,
+            ),
+            (
+                "issue_156_RDATE_with_PERIOD_TZID_khal.ics",
+                Rule::UnknownTimezone,
+            ),
+            (
+                "issue_466_convert_tzid_with_slash.ics",
+                Rule::UnknownTimezone,
+            ),
+            (
+                "issue_526_calendar_with_different_events.ics",
+                Rule::UnknownTimezone,
+            ),
+            (
+                "issue_526_calendar_with_different_events.ics",
+                Rule::UnknownTimezone,
+            ),
+            (
+                "issue_526_calendar_with_event_subset.ics",
+                Rule::UnknownTimezone,
+            ),
+            ("issue_526_calendar_with_events.ics", Rule::UnknownTimezone),
+            ("issue_526_calendar_with_events.ics", Rule::UnknownTimezone),
+            (
+                "issue_526_calendar_with_shuffeled_events.ics",
+                Rule::UnknownTimezone,
+            ),
+            (
+                "issue_526_calendar_with_shuffeled_events.ics",
+                Rule::UnknownTimezone,
+            ),
+        ]
+        .map(|(file, rule)| (file.to_string(), rule));
+
+        assert_eq!(errors, expected);
+    }
+}
@@ -0,0 +1,444 @@
+/*!
+ * Storage of a calendar collection as a [vdir](https://vdirsyncer.pimutils.org/en/stable/vdir.html),
+ * the directory format of khal and vdirsyncer.
+ *
+ * Each item is a `.ics` file, named after its UID when it only contains safe characters. The
+ * collection metadata are the `displayname` and `color` files. The ETags are computed from the
+ * file content.
+ *
+ * ```
+ * # let path = std::env::temp_dir().join(format!("ikal-vdir-doc-{}", std::process::id()));
+ * let vdir = ikal::vdir::Vdir::init(&path)?;
+ * vdir.set_displayname("Work")?;
+ *
+ * let vcalendar: ikal::VCalendar = std::fs::read_to_string("tests/calendars/1.ics")?.parse()?;
+ * let (href, etag) = vdir.upload(&vcalendar)?;
+ *
+ * for (href, item) in vdir.iter()? {
+ *     println!("{href}: {:?}", item.map(|x| x.etag));
+ * }
+ *
+ * vdir.delete(&href, &etag)?;
+ * # std::fs::remove_dir_all(path)?;
+ * # Ok::<(), Box<dyn std::error::Error>>(())
+ * ```
+ */
+
+const EXTENSION: &str = ".ics";
+
+/**
+ * An item of the collection.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct Item {
+    pub href: String,
+    pub etag: String,
+    pub vcalendar: crate::VCalendar,
+}
+
+/**
+ * A vdir collection.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Vdir {
+    path: std::path::PathBuf,
+}
+
+impl Vdir {
+    /**
+     * Opens an existing collection.
+     */
+    pub fn open<P: Into<std::path::PathBuf>>(path: P) -> crate::Result<Self> {
+        let path = path.into();
+
+        if !path.is_dir() {
+            return Err(crate::Error::NotFound(path.display().to_string()));
+        }
+
+        Ok(Self { path })
+    }
+
+    /**
+     * Opens a collection, creating its directory if needed.
+     */
+    pub fn init<P: Into<std::path::PathBuf>>(path: P) -> crate::Result<Self> {
+        let path = path.into();
+        std::fs::create_dir_all(&path)?;
+
+        Ok(Self { path })
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    pub fn displayname(&self) -> crate::Result<Option<String>> {
+        self.metadata("displayname")
+    }
+
+    pub fn set_displayname(&self, displayname: &str) -> crate::Result {
+        self.set_metadata("displayname", displayname)
+    }
+
+    pub fn color(&self) -> crate::Result<Option<String>> {
+        self.metadata("color")
+    }
+
+    pub fn set_color(&self, color: &str) -> crate::Result {
+        self.set_metadata("color", color)
+    }
+
+    fn metadata(&self, name: &str) -> crate::Result<Option<String>> {
+        match std::fs::read_to_string(self.path.join(name)) {
+            Ok(value) => Ok(Some(value.trim().to_string())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn set_metadata(&self, name: &str, value: &str) -> crate::Result {
+        let tmp = self.write_tmp(name, value)?;
+        std::fs::rename(tmp, self.path.join(name))?;
+
+        Ok(())
+    }
+
+    /**
+     * The href and ETag of every item, sorted by href.
+     */
+    pub fn list(&self) -> crate::Result<Vec<(String, String)>> {
+        self.hrefs()?
+            .into_iter()
+            .map(|href| {
+                let data = std::fs::read_to_string(self.path.join(&href))?;
+                let etag = etag(&data);
+
+                Ok((href, etag))
+            })
+            .collect()
+    }
+
+    pub fn get(&self, href: &str) -> crate::Result<Item> {
+        let data = self.read(href)?;
+        let vcalendar = data.parse()?;
+
+        Ok(Item {
+            href: href.to_string(),
+            etag: etag(&data),
+            vcalendar,
+        })
+    }
+
+    /**
+     * Parses every item, an error doesn’t stop the iteration.
+     */
+    pub fn iter(&self) -> crate::Result<impl Iterator<Item = (String, crate::Result<Item>)> + '_> {
+        let items = self.hrefs()?.into_iter().map(|href| {
+            let item = self.get(&href);
+            (href, item)
+        });
+
+        Ok(items)
+    }
+
+    /**
+     * Href of the item with `uid`, looked up in the files when it isn’t named after it.
+     */
+    pub fn find(&self, uid: &str) -> crate::Result<Option<String>> {
+        let href = href(uid);
+
+        if self.path.join(&href).is_file() {
+            return Ok(Some(href));
+        }
+
+        for (href, item) in self.iter()? {
+            if item.is_ok_and(|x| self::uid(&x.vcalendar) == Some(uid)) {
+                return Ok(Some(href));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /**
+     * Stores a new item, fails if its href is already taken.
+     */
+    pub fn upload(&self, vcalendar: &crate::VCalendar) -> crate::Result<(String, String)> {
+        let data = crate::ser::ical(vcalendar);
+        let href = new_href(vcalendar, &data);
+
+        let tmp = self.write_tmp(&href, &data)?;
+        // A hard link doesn’t replace an existing file, unlike a rename
+        let linked = std::fs::hard_link(&tmp, self.path.join(&href));
+        std::fs::remove_file(tmp)?;
+
+        match linked {
+            Ok(()) => Ok((href, etag(&data))),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                Err(crate::Error::Exists(href))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /**
+     * Replaces an item, if it wasn’t modified since `etag`.
+     */
+    pub fn update(
+        &self,
+        href: &str,
+        vcalendar: &crate::VCalendar,
+        etag: &str,
+    ) -> crate::Result<String> {
+        self.check(href, etag)?;
+
+        let data = crate::ser::ical(vcalendar);
+        let tmp = self.write_tmp(href, &data)?;
+        std::fs::rename(tmp, self.path.join(href))?;
+
+        Ok(self::etag(&data))
+    }
+
+    /**
+     * Deletes an item, if it wasn’t modified since `etag`.
+     */
+    pub fn delete(&self, href: &str, etag: &str) -> crate::Result {
+        self.check(href, etag)?;
+        std::fs::remove_file(self.path.join(href))?;
+
+        Ok(())
+    }
+
+    fn check(&self, href: &str, etag: &str) -> crate::Result {
+        if self::etag(&self.read(href)?) == etag {
+            Ok(())
+        } else {
+            Err(crate::Error::Conflict(href.to_string()))
+        }
+    }
+
+    fn read(&self, href: &str) -> crate::Result<String> {
+        if !href.ends_with(EXTENSION) || href.contains(['/', '\\']) {
+            return Err(crate::Error::NotFound(href.to_string()));
+        }
+
+        match std::fs::read_to_string(self.path.join(href)) {
+            Ok(data) => Ok(data),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                Err(crate::Error::NotFound(href.to_string()))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn hrefs(&self) -> crate::Result<Vec<String>> {
+        let mut hrefs = Vec::new();
+
+        for entry in std::fs::read_dir(&self.path)? {
+            let entry = entry?;
+            let href = entry.file_name().to_string_lossy().to_string();
+
+            if href.ends_with(EXTENSION) && !href.starts_with('.') && entry.path().is_file() {
+                hrefs.push(href);
+            }
+        }
+
+        hrefs.sort();
+
+        Ok(hrefs)
+    }
+
+    /**
+     * Writes a hidden file next to `name`, to be moved in place.
+     */
+    fn write_tmp(&self, name: &str, data: &str) -> crate::Result<std::path::PathBuf> {
+        let tmp = self
+            .path
+            .join(format!(".{name}.{}.tmp", std::process::id()));
+        std::fs::write(&tmp, data)?;
+
+        Ok(tmp)
+    }
+}
+
+/**
+ * File name of an item: its UID when only made of safe characters, a hash otherwise.
+ */
+#[must_use]
+pub fn href(uid: &str) -> String {
+    let safe = !uid.is_empty()
+        && !uid.starts_with('.')
+        && uid
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_.-+@".contains(c));
+
+    if safe {
+        format!("{uid}{EXTENSION}")
+    } else {
+        format!("{}{EXTENSION}", etag(uid))
+    }
+}
+
+/**
+ * File name of a new item, from its UID or its content when it has none.
+ */
+pub(crate) fn new_href(vcalendar: &crate::VCalendar, data: &str) -> String {
+    match uid(vcalendar) {
+        Some(uid) => href(uid),
+        None => format!("{}{EXTENSION}", etag(data)),
+    }
+}
+
+/**
+ * Content-based ETag, the 64-bit FNV-1a hash of the data.
+ *
+ * The hash doesn’t depend on the Rust release, the ETags stay valid once stored.
+ */
+#[must_use]
+pub fn etag(data: &str) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let hash = data.bytes().fold(OFFSET, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    });
+
+    format!("{hash:016x}")
+}
+
+/**
+ * UID of the components of a calendar object.
+ */
+pub(crate) fn uid(vcalendar: &crate::VCalendar) -> Option<&str> {
+    let uid = vcalendar
+        .events
+        .iter()
+        .map(|x| &x.uid)
+        .chain(vcalendar.todo.iter().map(|x| &x.uid))
+        .chain(vcalendar.journals.iter().map(|x| &x.uid))
+        .chain(vcalendar.freebusy.iter().map(|x| &x.uid))
+        .next()?;
+
+    Some(uid.text.as_str())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::vcalendar;
+
+    #[test]
+    fn href() {
+        assert_eq!(
+            super::href("abc-123@example.com"),
+            "abc-123@example.com.ics"
+        );
+        assert_ne!(super::href("a/b"), "a/b.ics");
+        assert!(!super::href("../etc").contains('/'));
+        assert_eq!(super::href("a b"), super::href("a b"));
+    }
+
+    #[test]
+    fn etag() {
+        assert_eq!(super::etag(""), "cbf29ce484222325");
+        assert_eq!(super::etag("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn metadata() -> crate::Result {
+        let tmp = crate::test::TempDir::new("vdir-metadata");
+        let vdir = super::Vdir::init(tmp.path())?;
+
+        assert_eq!(vdir.displayname()?, None);
+        vdir.set_displayname("Work")?;
+        vdir.set_color("#ff0000")?;
+        assert_eq!(vdir.displayname()?, Some("Work".to_string()));
+        assert_eq!(vdir.color()?, Some("#ff0000".to_string()));
+        assert!(vdir.list()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn items() -> crate::Result {
+        let tmp = crate::test::TempDir::new("vdir-items");
+        let vdir = super::Vdir::init(tmp.path())?;
+
+        let (href, etag) = vdir.upload(&vcalendar("1", "First"))?;
+        assert_eq!(href, "1.ics");
+        assert_eq!(
+            vdir.upload(&vcalendar("1", "Again")),
+            Err(crate::Error::Exists(href.clone()))
+        );
+        assert_eq!(vdir.list()?, [(href.clone(), etag.clone())]);
+
+        let item = vdir.get(&href)?;
+        assert_eq!(item.etag, etag);
+        assert_eq!(item.vcalendar, vcalendar("1", "First"));
+
+        let updated = vdir.update(&href, &vcalendar("1", "Updated"), &etag)?;
+        assert_ne!(updated, etag);
+        assert_eq!(
+            vdir.update(&href, &vcalendar("1", "Lost"), &etag),
+            Err(crate::Error::Conflict(href.clone()))
+        );
+        assert_eq!(
+            vdir.delete(&href, &etag),
+            Err(crate::Error::Conflict(href.clone()))
+        );
+
+        vdir.delete(&href, &updated)?;
+        assert_eq!(vdir.get(&href), Err(crate::Error::NotFound(href.clone())));
+        assert_eq!(
+            vdir.get("../1.ics"),
+            Err(crate::Error::NotFound("../1.ics".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find() -> crate::Result {
+        let tmp = crate::test::TempDir::new("vdir-find");
+        let vdir = super::Vdir::init(tmp.path())?;
+
+        let (href, _) = vdir.upload(&vcalendar("a b", "Unsafe"))?;
+        std::fs::write(
+            vdir.path().join("renamed.ics"),
+            crate::ser::ical(&vcalendar("2", "")),
+        )
+        .unwrap();
+
+        assert_eq!(vdir.find("a b")?, Some(href));
+        assert_eq!(vdir.find("2")?, Some("renamed.ics".to_string()));
+        assert_eq!(vdir.find("3")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn iter() -> crate::Result {
+        let tmp = crate::test::TempDir::new("vdir-iter");
+        let vdir = super::Vdir::init(tmp.path())?;
+
+        for name in ["1.ics", "broken_ical.ics"] {
+            std::fs::copy(
+                std::path::Path::new("tests/calendars").join(name),
+                vdir.path().join(name),
+            )
+            .unwrap();
+        }
+        std::fs::write(vdir.path().join("displayname"), "Fixtures\n").unwrap();
+
+        let items = vdir.iter()?.collect::<Vec<_>>();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].0, "1.ics");
+        assert!(items[0].1.is_ok());
+        assert_eq!(items[1].0, "broken_ical.ics");
+        assert!(items[1].1.is_err());
+        assert_eq!(vdir.displayname()?, Some("Fixtures".to_string()));
+
+        Ok(())
+    }
+}
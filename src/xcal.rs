@@ -0,0 +1,425 @@
+/*!
+ * See [RFC 6321](https://datatracker.ietf.org/doc/html/rfc6321)
+ *
+ * ```
+ * let calendar: ikal::VCalendar = std::fs::read_to_string("tests/calendars/1.ics")?.try_into()?;
+ *
+ * let xcal = ikal::xcal::to_string(&calendar)?;
+ * let parsed: ikal::VCalendar = ikal::xcal::from_str(&xcal)?;
+ *
+ * assert_eq!(parsed, calendar);
+ * # Ok::<(), Box<dyn std::error::Error>>(())
+ * ```
+ */
+
+use crate::xml::Element;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+
+const NAMESPACE: &str = "urn:ietf:params:xml:ns:icalendar-2.0";
+
+type Writer = quick_xml::Writer<Vec<u8>>;
+
+/**
+ * Converts a component to an xCal document.
+ */
+pub fn to_string<T: crate::ser::Serialize>(component: &T) -> crate::Result<String> {
+    let ical = crate::ser::ical(component).replace("\r\n ", "");
+    let tree = crate::tree::Component::parse(&ical)?;
+
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    write_document(&mut writer, &tree).map_err(error)?;
+
+    String::from_utf8(writer.into_inner()).map_err(error)
+}
+
+/**
+ * Parses a component from an xCal document.
+ */
+pub fn from_str<T>(s: &str) -> crate::Result<T>
+where
+    T: std::str::FromStr<Err = crate::Error>,
+{
+    let root = Element::parse(s).map_err(crate::Error::Xcal)?;
+
+    if root.name != "icalendar" {
+        return Err(crate::Error::Xcal(format!(
+            "expected icalendar root, found {}",
+            root.name
+        )));
+    }
+
+    let [component] = root.children.as_slice() else {
+        return Err(crate::Error::Xcal(
+            "expected a single component".to_string(),
+        ));
+    };
+
+    component_from_element(component)?.to_string().parse()
+}
+
+fn write_document(writer: &mut Writer, component: &crate::tree::Component) -> std::io::Result<()> {
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
+    writer.write_event(Event::Start(
+        BytesStart::new("icalendar").with_attributes([("xmlns", NAMESPACE)]),
+    ))?;
+    write_component(writer, component)?;
+    writer.write_event(Event::End(BytesEnd::new("icalendar")))
+}
+
+fn write_component(writer: &mut Writer, component: &crate::tree::Component) -> std::io::Result<()> {
+    let name = component.name.to_lowercase();
+
+    start(writer, &name)?;
+
+    start(writer, "properties")?;
+    for property in &component.properties {
+        write_property(writer, property)?;
+    }
+    end(writer, "properties")?;
+
+    if !component.components.is_empty() {
+        start(writer, "components")?;
+        for component in &component.components {
+            write_component(writer, component)?;
+        }
+        end(writer, "components")?;
+    }
+
+    end(writer, &name)
+}
+
+fn write_property(writer: &mut Writer, property: &crate::ContentLine) -> std::io::Result<()> {
+    let name = property.key.to_lowercase();
+    let ty = crate::tree::value_type(property);
+    let ty = ty.as_str();
+
+    start(writer, &name)?;
+
+    // A redundant VALUE is kept as parameter, the type alone would drop it on import
+    let params = property
+        .params
+        .iter()
+        .filter(|(k, _)| k.as_str() != "VALUE" || ty == crate::tree::default_type(&property.key))
+        .collect::<Vec<_>>();
+
+    if !params.is_empty() {
+        start(writer, "parameters")?;
+        for (k, v) in params {
+            let k = k.to_lowercase();
+
+            start(writer, &k)?;
+            element(writer, param_type(&k), v)?;
+            end(writer, &k)?;
+        }
+        end(writer, "parameters")?;
+    }
+
+    if ty == "unknown" {
+        element(writer, ty, &property.value)?;
+    } else if ty == "recur" {
+        start(writer, ty)?;
+        for (k, values) in crate::tree::recur(&property.value) {
+            for value in values {
+                element(writer, &k, &value)?;
+            }
+        }
+        end(writer, ty)?;
+    } else if ty == "text" && property.key == "REQUEST-STATUS" {
+        let status = crate::tree::split(&property.value, ';');
+        for (name, value) in ["code", "description", "data"].into_iter().zip(status) {
+            element(writer, name, &crate::ser::unescape(value))?;
+        }
+    } else if ty == "float" && property.key == "GEO" {
+        let geo = property.value.split(';');
+        for (name, value) in ["latitude", "longitude"].into_iter().zip(geo) {
+            element(writer, name, value)?;
+        }
+    } else if ty == "period" {
+        for value in property.value.split(',') {
+            write_period(writer, value)?;
+        }
+    } else if crate::tree::is_multiple(property) {
+        for value in crate::tree::split(&property.value, ',') {
+            element(writer, ty, &scalar(ty, value))?;
+        }
+    } else {
+        element(writer, ty, &scalar(ty, &property.value))?;
+    }
+
+    end(writer, &name)
+}
+
+fn write_period(writer: &mut Writer, value: &str) -> std::io::Result<()> {
+    let period = crate::tree::to_iso("period", value);
+    let (start_date, end_date) = period.split_once('/').unwrap_or((&period, ""));
+
+    start(writer, "period")?;
+    element(writer, "start", start_date)?;
+    if end_date.starts_with(['P', '+', '-']) {
+        element(writer, "duration", end_date)?;
+    } else {
+        element(writer, "end", end_date)?;
+    }
+    end(writer, "period")
+}
+
+fn scalar(ty: &str, value: &str) -> String {
+    if ty == "boolean" {
+        value.to_lowercase()
+    } else {
+        crate::tree::to_iso(ty, value)
+    }
+}
+
+/**
+ * See [3.5. Parameters](https://datatracker.ietf.org/doc/html/rfc6321#section-3.5)
+ */
+fn param_type(name: &str) -> &'static str {
+    match name {
+        "altrep" | "dir" => "uri",
+        "delegated-from" | "delegated-to" | "member" | "sent-by" => "cal-address",
+        _ => "text",
+    }
+}
+
+fn start(writer: &mut Writer, name: &str) -> std::io::Result<()> {
+    writer.write_event(Event::Start(BytesStart::new(name)))
+}
+
+fn end(writer: &mut Writer, name: &str) -> std::io::Result<()> {
+    writer.write_event(Event::End(BytesEnd::new(name)))
+}
+
+fn element(writer: &mut Writer, name: &str, text: &str) -> std::io::Result<()> {
+    writer
+        .create_element(name)
+        .write_text_content(BytesText::new(text))
+        .map(|_| ())
+}
+
+fn component_from_element(element: &Element) -> crate::Result<crate::tree::Component> {
+    let properties = element
+        .child("properties")
+        .map(|x| x.children.iter().map(property_from_element).collect())
+        .transpose()?
+        .unwrap_or_default();
+
+    let components = element
+        .child("components")
+        .map(|x| x.children.iter().map(component_from_element).collect())
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(crate::tree::Component {
+        name: element.name.to_uppercase(),
+        properties,
+        components,
+    })
+}
+
+fn property_from_element(element: &Element) -> crate::Result<crate::ContentLine> {
+    let key = element.name.to_uppercase();
+
+    let mut params = element
+        .child("parameters")
+        .map(|x| x.children.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .map(|param| {
+            let values = param
+                .children
+                .iter()
+                .map(|x| x.text.as_str())
+                .collect::<Vec<_>>();
+
+            (param.name.to_uppercase(), values.join(","))
+        })
+        .collect::<std::collections::BTreeMap<_, _>>();
+
+    let values = element
+        .children
+        .iter()
+        .filter(|x| x.name != "parameters")
+        .collect::<Vec<_>>();
+
+    let Some(first) = values.first() else {
+        return Err(crate::Error::Xcal(format!("missing value for {key}")));
+    };
+
+    let (ty, value) = match first.name.as_str() {
+        // Structured values of GEO and REQUEST-STATUS
+        "latitude" | "code" => {
+            let ty = crate::tree::default_type(&key);
+            let value = values
+                .iter()
+                .map(|x| crate::tree::from_iso(ty, &x.text))
+                .collect::<Vec<_>>()
+                .join(";");
+
+            (ty.to_string(), value)
+        }
+        "period" => {
+            let periods = values
+                .iter()
+                .map(|x| {
+                    let period = x
+                        .children
+                        .iter()
+                        .map(|x| x.text.as_str())
+                        .collect::<Vec<_>>()
+                        .join("/");
+
+                    crate::tree::from_iso("period", &period)
+                })
+                .collect::<Vec<_>>();
+
+            ("period".to_string(), periods.join(","))
+        }
+        "recur" => {
+            let mut parts = Vec::<(String, Vec<String>)>::new();
+
+            for part in &first.children {
+                match parts.iter_mut().find(|(k, _)| *k == part.name) {
+                    Some((_, values)) => values.push(part.text.clone()),
+                    None => parts.push((part.name.clone(), vec![part.text.clone()])),
+                }
+            }
+
+            ("recur".to_string(), crate::tree::join_recur(parts))
+        }
+        "unknown" => ("unknown".to_string(), first.text.clone()),
+        ty => {
+            let value = values
+                .iter()
+                .map(|x| {
+                    if ty == "boolean" {
+                        x.text.to_uppercase()
+                    } else {
+                        crate::tree::from_iso(ty, &x.text)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            (ty.to_string(), value)
+        }
+    };
+
+    if ty != "unknown" && ty != crate::tree::default_type(&key) {
+        params.insert("VALUE".to_string(), ty.to_uppercase());
+    }
+
+    Ok(crate::ContentLine { key, params, value })
+}
+
+fn error(err: impl ToString) -> crate::Error {
+    crate::Error::Xcal(err.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn to_string() -> crate::Result {
+        let vcalendar = crate::vcalendar! {
+            version: "2.0",
+            prodid: "-//Example Inc.//Example Calendar//EN",
+            events: [
+                {
+                    uid: "4088E990AD89CB3DBB484909",
+                    dtstamp: "20080205T191224",
+                    dtstart: "20081006",
+                    summary: "Planning meeting; room 2",
+                    categories: ["Work", "Meeting"],
+                    rrule: {
+                        freq: Weekly,
+                        count: 4,
+                    },
+                }
+            ],
+        }?;
+
+        let xcal = crate::xcal::to_string(&vcalendar)?;
+
+        similar_asserts::assert_eq!(
+            xcal,
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<icalendar xmlns="urn:ietf:params:xml:ns:icalendar-2.0">
+  <vcalendar>
+    <properties>
+      <prodid>
+        <text>-//Example Inc.//Example Calendar//EN</text>
+      </prodid>
+      <version>
+        <text>2.0</text>
+      </version>
+    </properties>
+    <components>
+      <vevent>
+        <properties>
+          <dtstamp>
+            <date-time>2008-02-05T19:12:24</date-time>
+          </dtstamp>
+          <uid>
+            <text>4088E990AD89CB3DBB484909</text>
+          </uid>
+          <dtstart>
+            <date>2008-10-06</date>
+          </dtstart>
+          <summary>
+            <text>Planning meeting; room 2</text>
+          </summary>
+          <rrule>
+            <recur>
+              <freq>WEEKLY</freq>
+              <count>4</count>
+            </recur>
+          </rrule>
+          <categories>
+            <text>Work</text>
+            <text>Meeting</text>
+          </categories>
+        </properties>
+      </vevent>
+    </components>
+  </vcalendar>
+</icalendar>"#
+        );
+
+        assert_eq!(crate::xcal::from_str(&xcal), Ok(vcalendar));
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() {
+        let tests = std::path::Path::new("tests");
+
+        for dir in tests.read_dir().unwrap() {
+            for file in dir.unwrap().path().read_dir().unwrap() {
+                let file = file.unwrap().path();
+
+                if file.extension() != Some(std::ffi::OsStr::new("ics")) {
+                    continue;
+                }
+
+                let input = std::fs::read_to_string(&file).unwrap();
+                let Ok(calendar) = crate::VCalendar::try_from(input) else {
+                    continue;
+                };
+
+                let xcal = crate::xcal::to_string(&calendar).unwrap();
+                let parsed = crate::xcal::from_str::<crate::VCalendar>(&xcal);
+
+                similar_asserts::assert_eq!(parsed, Ok(calendar), "{file:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            crate::xcal::from_str::<crate::VCalendar>("<vcalendar/>"),
+            Err(crate::Error::Xcal(_))
+        ));
+    }
+}
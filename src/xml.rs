@@ -0,0 +1,89 @@
+use quick_xml::events::{BytesStart, Event};
+
+/**
+ * Minimal XML tree, namespaces are ignored.
+ */
+#[derive(Debug, Default)]
+pub(crate) struct Element {
+    pub name: String,
+    pub attributes: std::collections::BTreeMap<String, String>,
+    pub children: Vec<Element>,
+    pub text: String,
+}
+
+impl Element {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut reader = quick_xml::Reader::from_str(s);
+        let mut stack = vec![Self::default()];
+
+        loop {
+            match reader.read_event().map_err(error)? {
+                Event::Start(start) => stack.push(Self::new(&start)?),
+                Event::Empty(start) => {
+                    let element = Self::new(&start)?;
+                    stack.last_mut().unwrap().children.push(element);
+                }
+                Event::End(_) => {
+                    let element = stack.pop().unwrap();
+                    let Some(parent) = stack.last_mut() else {
+                        return Err("unexpected end tag".to_string());
+                    };
+                    parent.children.push(element);
+                }
+                Event::Text(text) => {
+                    let text = text.unescape().map_err(error)?;
+                    stack.last_mut().unwrap().text.push_str(&text);
+                }
+                Event::CData(cdata) => {
+                    let text = cdata.decode().map_err(error)?;
+                    stack.last_mut().unwrap().text.push_str(&text);
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+        }
+
+        match stack.pop() {
+            Some(mut document) if stack.is_empty() && document.children.len() == 1 => {
+                Ok(document.children.remove(0))
+            }
+            _ => Err("expected a single root".to_string()),
+        }
+    }
+
+    fn new(start: &BytesStart) -> Result<Self, String> {
+        Ok(Self {
+            name: String::from_utf8_lossy(start.local_name().as_ref()).to_string(),
+            attributes: attributes(start)?,
+            ..Default::default()
+        })
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Self> {
+        self.children.iter().find(|x| x.name == name)
+    }
+
+    // Only the CalDAV requests read attributes, xCal has none
+    #[cfg_attr(not(feature = "caldav"), allow(dead_code))]
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(String::as_str)
+    }
+}
+
+fn attributes(start: &BytesStart) -> Result<std::collections::BTreeMap<String, String>, String> {
+    let mut attributes = std::collections::BTreeMap::new();
+
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(error)?;
+        let name = String::from_utf8_lossy(attribute.key.local_name().as_ref()).to_string();
+        let value = attribute.unescape_value().map_err(error)?;
+
+        attributes.insert(name, value.to_string());
+    }
+
+    Ok(attributes)
+}
+
+fn error(err: impl ToString) -> String {
+    err.to_string()
+}